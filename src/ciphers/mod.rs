@@ -3,6 +3,7 @@ mod aes;
 mod another_rot13;
 mod caesar;
 mod morse_code;
+mod otp;
 mod polybius;
 mod rot13;
 mod sha256;
@@ -15,10 +16,11 @@ pub use self::aes::{aes_decrypt, aes_encrypt, AesKey};
 pub use self::another_rot13::another_rot13;
 pub use self::caesar::caesar;
 pub use self::morse_code::{decode, encode};
+pub use self::otp::otp_xor;
 pub use self::polybius::{decode_ascii, encode_ascii};
-pub use self::rot13::rot13;
+pub use self::rot13::{rot13, rot13_bytes};
 pub use self::sha256::sha256;
 pub use self::tea::{tea_decrypt, tea_encrypt};
 pub use self::transposition::transposition;
-pub use self::vigenere::vigenere;
+pub use self::vigenere::{estimate_key_length, vigenere};
 pub use self::xor::xor;
@@ -0,0 +1,77 @@
+/// One-time pad cipher
+///
+/// XORs every byte of `data` with the byte of `key` at the same position. XOR is
+/// its own inverse, so the same function both encrypts and decrypts.
+///
+/// The pad is only secure if the key is truly random, at least as long as the
+/// data, and never reused. A key shorter than the data is rejected instead of
+/// being repeated, since repeating it would amount to reusing the pad.
+///
+/// # Arguments
+///
+/// * `data` - The bytes to be ciphered or deciphered.
+/// * `key` - The pad, which must be at least as long as `data`.
+///
+/// # Returns
+///
+/// * The ciphered bytes, or an error if `key` is shorter than `data`.
+///
+/// # Example
+///
+/// ```rust
+/// use rust_algorithms::ciphers::otp_xor;
+///
+/// let message = b"attack at dawn";
+/// let key = b"\x12\x9a\x4f\x03\xe8\x71\x2c\xb5\x66\x0d\xf2\x39\x84\x5e";
+///
+/// let ciphered = otp_xor(message, key).unwrap();
+/// assert_eq!(otp_xor(&ciphered, key).unwrap(), message);
+/// ```
+pub fn otp_xor(data: &[u8], key: &[u8]) -> Result<Vec<u8>, &'static str> {
+    if key.len() < data.len() {
+        return Err("key must be at least as long as the data");
+    }
+    Ok(data.iter().zip(key).map(|(d, k)| d ^ k).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"\x8f\x1c\x55\xd0\x27\xaa\x03\x9e\x61\xfb\x44\x70\x1d\xc2\x38\xe9";
+
+    #[test]
+    fn round_trip() {
+        let message = b"hello, world";
+        let ciphered = otp_xor(message, KEY).unwrap();
+        assert_ne!(ciphered, message);
+        assert_eq!(otp_xor(&ciphered, KEY).unwrap(), message);
+    }
+
+    #[test]
+    fn key_longer_than_data() {
+        let ciphered = otp_xor(b"abc", KEY).unwrap();
+        assert_eq!(ciphered.len(), 3);
+        assert_eq!(ciphered, vec![b'a' ^ 0x8f, b'b' ^ 0x1c, b'c' ^ 0x55]);
+    }
+
+    #[test]
+    fn key_too_short() {
+        let message = [0u8; 17];
+        assert_eq!(
+            otp_xor(&message, KEY),
+            Err("key must be at least as long as the data")
+        );
+    }
+
+    #[test]
+    fn same_plaintext_same_key() {
+        let message = b"identical";
+        assert_eq!(otp_xor(message, KEY), otp_xor(message, KEY));
+    }
+
+    #[test]
+    fn empty_data() {
+        assert_eq!(otp_xor(&[], &[]), Ok(vec![]));
+    }
+}
@@ -36,9 +36,40 @@ pub fn rot13(text: &str) -> String {
         .collect()
 }
 
+/// Applies ROT13 to raw bytes, so it can process data that is not valid UTF-8.
+///
+/// Only ASCII letters are rotated, keeping their case; every other byte is
+/// passed through untouched. Unlike [`rot13`], the input is not uppercased.
+///
+/// # Arguments
+///
+/// * `data` - Bytes to transform.
+///
+/// # Returns
+///
+/// An owned [`Vec<u8>`] with the transformed bytes.
+///
+/// # Examples
+/// ```rust
+/// use rust_algorithms::ciphers::rot13_bytes;
+///
+/// let encoded = rot13_bytes(b"Hello\xff world");
+///
+/// assert_eq!(encoded, b"Uryyb\xff jbeyq");
+/// ```
+pub fn rot13_bytes(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .map(|&b| match b {
+            b'A'..=b'M' | b'a'..=b'm' => b + 13,
+            b'N'..=b'Z' | b'n'..=b'z' => b - 13,
+            _ => b,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
-    use super::rot13;
+    use super::{rot13, rot13_bytes};
 
     #[test]
     fn test_single_letter() {
@@ -59,4 +90,30 @@ mod test {
     fn test_twice() {
         assert_eq!("ABCD", rot13(&rot13("ABCD")));
     }
+
+    #[test]
+    fn test_bytes_matches_rot13_for_ascii() {
+        for text in ["A", "ABC", "HELLO WORLD", "the quick brown fox, 42!"].iter() {
+            let uppercase = text.to_uppercase();
+            assert_eq!(rot13_bytes(uppercase.as_bytes()), rot13(text).as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_bytes_keeps_case() {
+        assert_eq!(rot13_bytes(b"abcXYZ"), b"nopKLM");
+    }
+
+    #[test]
+    fn test_bytes_non_letters_unchanged() {
+        let data: Vec<u8> = (0..=255u8).filter(|b| !b.is_ascii_alphabetic()).collect();
+        assert_eq!(rot13_bytes(&data), data);
+    }
+
+    #[test]
+    fn test_bytes_invalid_utf8() {
+        let data = [0xff, b'a', 0xfe, 0x00, b'Z', 0x80];
+        assert_eq!(rot13_bytes(&data), [0xff, b'n', 0xfe, 0x00, b'M', 0x80]);
+        assert_eq!(rot13_bytes(&rot13_bytes(&data)), data);
+    }
 }
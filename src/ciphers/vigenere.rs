@@ -60,6 +60,98 @@ pub fn vigenere(plain_text: &str, key: &str) -> String {
         .collect()
 }
 
+/// Estimates the length of the key that was used to encrypt a Vigenère cipher text.
+///
+/// # Algorithm
+///
+/// For every candidate length `n`, the letters of the cipher text are split into `n`
+/// columns, so that every column was shifted by the same key letter. The index of
+/// coincidence of a column, the probability that two letters drawn from it are equal,
+/// stays close to that of English (about 0.066) when `n` is a multiple of the key length,
+/// and drops towards that of uniformly random text (about 0.038) otherwise.
+///
+/// The average index of coincidence is computed for every length up to `max_len`, and the
+/// smallest length whose average comes within 10% of the best one is returned, since
+/// every multiple of the key length scores about as high as the key length itself.
+/// Only ascii letters are taken into account, ignoring case.
+///
+/// # Reference
+///
+/// [Index of coincidence](https://en.wikipedia.org/wiki/Index_of_coincidence).
+///
+/// # Arguments
+///
+/// * `cipher` - The encrypted text. It should be long, a few hundred letters at least.
+/// * `max_len` - The largest key length to consider.
+///
+/// # Returns
+///
+/// The estimated key length, between 1 and `max_len`, or 0 if `max_len` is 0.
+///
+/// # Example
+///
+/// ```rust
+/// use rust_algorithms::ciphers::{estimate_key_length, vigenere};
+///
+/// let plain_text = "it was the best of times it was the worst of times it was the age of \
+///     wisdom it was the age of foolishness it was the epoch of belief it was the epoch of \
+///     incredulity it was the season of light it was the season of darkness it was the \
+///     spring of hope it was the winter of despair";
+///
+/// let encrypted = vigenere(plain_text, "key");
+///
+/// assert_eq!(estimate_key_length(&encrypted, 10), 3);
+/// ```
+pub fn estimate_key_length(cipher: &str, max_len: usize) -> usize {
+    let letters: Vec<usize> = cipher
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|c| (c.to_ascii_lowercase() - b'a') as usize)
+        .collect();
+
+    let scores: Vec<f64> = (1..=max_len)
+        .map(|len| average_index_of_coincidence(&letters, len))
+        .collect();
+    let best = scores.iter().cloned().fold(0.0, f64::max);
+
+    scores
+        .iter()
+        .position(|&score| score >= 0.9 * best)
+        .map_or(0, |index| index + 1)
+}
+
+/// The index of coincidence of the `len` columns of `letters`, averaged over the columns
+/// that hold at least two letters.
+fn average_index_of_coincidence(letters: &[usize], len: usize) -> f64 {
+    let mut total = 0.0;
+    let mut columns = 0;
+
+    for column in 0..len {
+        let mut counts = [0usize; 26];
+        let mut size = 0;
+        for &letter in letters.iter().skip(column).step_by(len) {
+            counts[letter] += 1;
+            size += 1;
+        }
+        if size < 2 {
+            continue;
+        }
+
+        let pairs: usize = counts
+            .iter()
+            .map(|&count| count * count.saturating_sub(1))
+            .sum();
+        total += pairs as f64 / (size * (size - 1)) as f64;
+        columns += 1;
+    }
+
+    if columns == 0 {
+        0.0
+    } else {
+        total / columns as f64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +192,66 @@ mod tests {
     fn vigenere_empty_key() {
         assert_eq!(vigenere("Lorem ipsum", ""), "Lorem ipsum");
     }
+
+    const ENGLISH_TEXT: &str = "It is a truth universally acknowledged, that a single man in \
+        possession of a good fortune, must be in want of a wife. However little known the \
+        feelings or views of such a man may be on his first entering a neighbourhood, this \
+        truth is so well fixed in the minds of the surrounding families, that he is considered \
+        the rightful property of some one or other of their daughters. My dear Mr. Bennet, said \
+        his lady to him one day, have you heard that Netherfield Park is let at last? Mr. Bennet \
+        replied that he had not. But it is, returned she; for Mrs. Long has just been here, and \
+        she told me all about it. Mr. Bennet made no answer. Do you not want to know who has \
+        taken it? cried his wife impatiently. You want to tell me, and I have no objection to \
+        hearing it. This was invitation enough. Why, my dear, you must know, Mrs. Long says \
+        that Netherfield is taken by a young man of large fortune from the north of England; \
+        that he came down on Monday in a chaise and four to see the place, and was so much \
+        delighted with it, that he agreed with Mr. Morris immediately; that he is to take \
+        possession before Michaelmas, and some of his servants are to be in the house by the \
+        end of next week.";
+
+    #[test]
+    fn estimate_known_key_lengths() {
+        for key in ["lemon", "bennet", "cryptic", "ab"].iter() {
+            let encrypted = vigenere(ENGLISH_TEXT, key);
+            let estimate = estimate_key_length(&encrypted, 16);
+            assert!(estimate > 0, "no estimate for {}", key);
+            assert_eq!(
+                estimate % key.len(),
+                0,
+                "estimated {} for {}",
+                estimate,
+                key
+            );
+            assert!(
+                estimate <= 2 * key.len(),
+                "estimated {} for {}",
+                estimate,
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn estimate_exact_key_length() {
+        assert_eq!(
+            estimate_key_length(&vigenere(ENGLISH_TEXT, "secret"), 20),
+            6
+        );
+        assert_eq!(
+            estimate_key_length(&vigenere(ENGLISH_TEXT, "austen"), 20),
+            6
+        );
+    }
+
+    #[test]
+    fn estimate_plain_text() {
+        // unencrypted text looks like a key of length 1
+        assert_eq!(estimate_key_length(ENGLISH_TEXT, 12), 1);
+    }
+
+    #[test]
+    fn estimate_degenerate_inputs() {
+        assert_eq!(estimate_key_length(ENGLISH_TEXT, 0), 0);
+        assert_eq!(estimate_key_length("", 5), 1);
+    }
 }
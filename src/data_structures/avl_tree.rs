@@ -189,6 +189,49 @@ impl<T: Ord> AVLTree<T> {
         self.length == 0
     }
 
+    /// Returns the height of the tree.
+    ///
+    /// # Returns
+    ///
+    /// The number of nodes on the longest path from the root to a leaf, which is `0` for an
+    /// empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::AVLTree;
+    ///
+    /// let tree: AVLTree<_> = (1..8).collect();
+    ///
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    pub fn height(&self) -> usize {
+        self.root.as_ref().map_or(0, |n| n.height)
+    }
+
+    /// Checks the AVL invariant on every node of the tree.
+    ///
+    /// The heights are recomputed from scratch instead of trusting the ones stored in the
+    /// nodes, so this also catches heights that were not kept up to date.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the heights of the left and right subtrees of every node differ by at most 1,
+    /// `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::AVLTree;
+    ///
+    /// let tree: AVLTree<_> = (1..100).collect();
+    ///
+    /// assert!(tree.is_balanced());
+    /// ```
+    pub fn is_balanced(&self) -> bool {
+        checked_height(&self.root).is_some()
+    }
+
     /// Returns an iterator that visits the nodes in the tree in order.
     fn node_iter(&self) -> NodeIter<T> {
         let cap = self.root.as_ref().map_or(0, |n| n.height);
@@ -273,6 +316,23 @@ fn remove<T: Ord>(tree: &mut Option<Box<AVLNode<T>>>, value: &T) -> bool {
     }
 }
 
+/// Recursive helper function for `AVLTree::is_balanced`.
+///
+/// Returns the height of the tree, or `None` if some node in it is unbalanced.
+fn checked_height<T: Ord>(tree: &Option<Box<AVLNode<T>>>) -> Option<usize> {
+    match tree {
+        None => Some(0),
+        Some(node) => {
+            let left = checked_height(&node.left)?;
+            let right = checked_height(&node.right)?;
+            if left.abs_diff(right) > 1 {
+                return None;
+            }
+            Some(1 + max(left, right))
+        }
+    }
+}
+
 /// Merges two trees and returns the root of the merged tree.
 fn merge<T: Ord>(left: Box<AVLNode<T>>, right: Box<AVLNode<T>>) -> Box<AVLNode<T>> {
     let mut op_right = Some(right);
@@ -482,12 +542,7 @@ impl<'a, T: Ord> Iterator for Iter<'a, T> {
 #[cfg(test)]
 mod tests {
     use super::AVLTree;
-
-    /// Returns `true` if all nodes in the tree are balanced.
-    fn is_balanced<T: Ord>(tree: &AVLTree<T>) -> bool {
-        tree.node_iter()
-            .all(|n| (-1..=1).contains(&n.balance_factor()))
-    }
+    use crate::math::PCG32;
 
     #[test]
     fn sorted() {
@@ -498,10 +553,56 @@ mod tests {
     #[test]
     fn balanced() {
         let mut tree: AVLTree<_> = (1..8).collect();
-        assert!(is_balanced(&tree));
+        assert!(tree.is_balanced());
         for x in 1..8 {
             tree.remove(&x);
-            assert!(is_balanced(&tree));
+            assert!(tree.is_balanced());
+        }
+    }
+
+    #[test]
+    fn height() {
+        let mut tree = AVLTree::new();
+        assert_eq!(tree.height(), 0);
+        tree.insert(1);
+        assert_eq!(tree.height(), 1);
+        tree.insert(2);
+        assert_eq!(tree.height(), 2);
+        // inserting 3 rotates the path 1 -> 2 -> 3 into a tree rooted at 2
+        tree.insert(3);
+        assert_eq!(tree.height(), 2);
+
+        // an AVL tree with n nodes is at most about 1.44 * log2(n) high
+        let tree: AVLTree<_> = (0..1023).collect();
+        assert_eq!(tree.height(), 10);
+        let tree: AVLTree<_> = (0..100_000).rev().collect();
+        assert!(tree.height() <= 24);
+    }
+
+    #[test]
+    fn balanced_after_random_operations() {
+        let mut rng = PCG32::new_default(314);
+        let mut tree = AVLTree::new();
+        let mut expected = std::collections::BTreeSet::new();
+
+        for _ in 0..5000 {
+            let value = rng.get_u32() % 500;
+            if rng.get_u32().is_multiple_of(3) {
+                assert_eq!(tree.remove(&value), expected.remove(&value));
+            } else {
+                assert_eq!(tree.insert(value), expected.insert(value));
+            }
+            assert!(tree.is_balanced());
+        }
+
+        assert_eq!(tree.len(), expected.len());
+        assert!(tree.iter().eq(expected.iter()));
+
+        for value in expected {
+            tree.remove(&value);
+            assert!(tree.is_balanced());
         }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
     }
 }
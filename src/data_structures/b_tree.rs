@@ -131,6 +131,38 @@ impl BTreeProps {
         }
     }
 
+    /// Builds a subtree of the given height holding exactly `keys`.
+    ///
+    /// Every child receives the same number of keys, give or take one, and there
+    /// are as few children as possible, but at least half of `degree` (or two for
+    /// the root), so that each node stays at least half full.
+    fn build_node<T: Ord + Copy>(&self, keys: &[T], height: usize, is_root: bool) -> Node<T> {
+        if height == 1 {
+            return Node::new(self.degree, Some(keys.to_vec()), None);
+        }
+
+        // A subtree of height h - 1 holds at most child_capacity - 1 keys. Counting one
+        // separator per child, the keys fill `slots` slots of up to child_capacity each.
+        let child_capacity = self.degree.pow((height - 1) as u32);
+        let slots = keys.len() + 1;
+        let min_children = if is_root { 2 } else { self.degree / 2 };
+        let child_count = slots.div_ceil(child_capacity).max(min_children);
+
+        let mut node_keys = Vec::with_capacity(child_count - 1);
+        let mut children = Vec::with_capacity(child_count);
+        let mut start = 0;
+        for i in 0..child_count {
+            let child_slots = slots / child_count + usize::from(i < slots % child_count);
+            let end = start + child_slots - 1;
+            children.push(self.build_node(&keys[start..end], height - 1, false));
+            if i + 1 < child_count {
+                node_keys.push(keys[end]);
+            }
+            start = end + 1;
+        }
+        Node::new(self.degree, Some(node_keys), Some(children))
+    }
+
     fn traverse_node<T: Ord + Debug>(&self, node: &Node<T>, depth: usize) {
         if node.is_leaf() {
             print!(" {0:{<1$}{2:?}{0:}<1$} ", "", depth, node.keys);
@@ -173,6 +205,43 @@ where
         }
     }
 
+    /// Build a BTree with the given branch factor from keys that are already sorted.
+    ///
+    /// Instead of inserting the keys one by one, which splits nodes over and over,
+    /// the tree is built directly with the smallest height that can hold every key,
+    /// and the keys are spread evenly across the nodes of each level.
+    ///
+    /// `sorted` must be sorted in ascending order and hold no duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BTree;
+    ///
+    /// let keys: Vec<u32> = (1..=100).collect();
+    /// let tree = BTree::bulk_load(&keys, 2);
+    ///
+    /// assert!(tree.search(42));
+    /// assert_eq!(tree.search(101), false);
+    /// ```
+    pub fn bulk_load(sorted: &[T], branch_factor: usize) -> Self {
+        let mut tree = BTree::new(branch_factor);
+        if sorted.is_empty() {
+            return tree;
+        }
+
+        // A tree of height h holds at most degree^h - 1 keys.
+        let degree = tree.props.degree;
+        let mut height = 1;
+        let mut capacity = degree;
+        while capacity - 1 < sorted.len() {
+            height += 1;
+            capacity *= degree;
+        }
+        tree.root = tree.props.build_node(sorted, height, true);
+        tree
+    }
+
     /// Insert a key into the BTree.
     ///
     /// # Examples
@@ -294,4 +363,89 @@ mod test {
         assert!(tree.search(15));
         assert_eq!(tree.search(16), false);
     }
+
+    /// Checks the B-tree invariants and returns the height of `node`.
+    fn check_node(node: &super::Node<u32>, max_keys: usize, min_keys: usize) -> usize {
+        assert!(node.keys.len() <= max_keys);
+        assert!(node.keys.len() >= min_keys);
+        assert!(node.keys.windows(2).all(|w| w[0] < w[1]));
+        if node.is_leaf() {
+            return 1;
+        }
+        assert_eq!(node.children.len(), node.keys.len() + 1);
+        let heights: Vec<usize> = node
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| {
+                if i > 0 {
+                    assert!(child.keys[0] > node.keys[i - 1]);
+                }
+                if i < node.keys.len() {
+                    assert!(*child.keys.last().unwrap() < node.keys[i]);
+                }
+                check_node(child, max_keys, min_keys)
+            })
+            .collect();
+        assert!(heights.iter().all(|&h| h == heights[0]));
+        heights[0] + 1
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        let keys: Vec<u32> = (1..=10000).collect();
+        let tree = BTree::bulk_load(&keys, 2);
+
+        for key in keys.iter() {
+            assert!(tree.search(*key));
+        }
+        assert!(!tree.search(0));
+        assert!(!tree.search(10001));
+
+        // Degree 4 holds at most 4^h - 1 keys at height h, and 4^6 - 1 < 10000.
+        assert_eq!(check_node(&tree.root, 3, 0), 7);
+        for child in tree.root.children.iter() {
+            check_node(child, 3, 1);
+        }
+    }
+
+    #[test]
+    fn test_bulk_load_minimal_height() {
+        for branch_factor in 2..5 {
+            let degree = 2 * branch_factor;
+            for len in [1, 2, 7, 15, 16, 63, 64, 100, 511, 512, 4095, 5000].iter() {
+                let keys: Vec<u32> = (0..*len).collect();
+                let tree = BTree::bulk_load(&keys, branch_factor);
+
+                let mut expected_height = 1;
+                while degree.pow(expected_height) - 1 < keys.len() {
+                    expected_height += 1;
+                }
+                let height = check_node(&tree.root, degree - 1, 0);
+                assert_eq!(height, expected_height as usize);
+                for child in tree.root.children.iter() {
+                    check_node(child, degree - 1, branch_factor - 1);
+                }
+                assert!(keys.iter().all(|&key| tree.search(key)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bulk_load_then_insert() {
+        let keys: Vec<u32> = (0..200).map(|i| i * 2).collect();
+        let mut tree = BTree::bulk_load(&keys, 2);
+        for key in (0..200).map(|i| i * 2 + 1) {
+            tree.insert(key);
+        }
+        assert!((0..400).all(|key| tree.search(key)));
+        check_node(&tree.root, 3, 0);
+    }
+
+    #[test]
+    fn test_bulk_load_empty() {
+        let tree: BTree<u32> = BTree::bulk_load(&[], 2);
+        assert!(tree.is_empty());
+        assert!(!tree.search(1));
+    }
 }
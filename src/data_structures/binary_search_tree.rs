@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::iter::FromIterator;
 use std::ops::Deref;
 
 /// A binary search tree (BST) is a binary tree where each node has at most two children, and the
@@ -106,6 +107,62 @@ where
         self.value.is_none()
     }
 
+    /// Counts the values stored in this tree, including duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let mut tree = BinarySearchTree::new();
+    ///
+    /// assert_eq!(tree.len(), 0);
+    /// tree.insert(5);
+    /// tree.insert(3);
+    /// tree.insert(5);
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let left = self.left.as_ref().map_or(0, |node| node.len());
+        let right = self.right.as_ref().map_or(0, |node| node.len());
+        1 + left + right
+    }
+
+    /// Gets the height of this tree, counted in nodes: the number of nodes on the longest
+    /// path from the root down to a leaf.
+    ///
+    /// # Returns
+    ///
+    /// `0` for an empty tree, and `1` for a tree holding a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let mut tree = BinarySearchTree::new();
+    ///
+    /// assert_eq!(tree.height(), 0);
+    /// tree.insert(5);
+    /// assert_eq!(tree.height(), 1);
+    /// tree.insert(3);
+    /// tree.insert(7);
+    /// assert_eq!(tree.height(), 2);
+    /// tree.insert(1);
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    pub fn height(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let left = self.left.as_ref().map_or(0, |node| node.height());
+        let right = self.right.as_ref().map_or(0, |node| node.height());
+        1 + left.max(right)
+    }
+
     /// Find a value in this tree.
     ///
     /// # Returns
@@ -232,6 +289,172 @@ where
         }
     }
 
+    /// Deletes a value from this tree.
+    ///
+    /// A node with no children is removed, and a node with one child is replaced by that
+    /// child. A node with two children takes the value of its in-order successor, the
+    /// smallest value of its right subtree, which is then removed from that subtree. If the
+    /// value was inserted more than once, only one occurrence is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to delete from this tree.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was in this tree, and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let mut tree = BinarySearchTree::new();
+    ///
+    /// tree.insert(5);
+    /// tree.insert(3);
+    /// tree.insert(7);
+    ///
+    /// assert!(tree.delete(&5));
+    /// assert!(!tree.delete(&5));
+    /// assert!(!tree.search(&5));
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&3, &7]);
+    /// ```
+    pub fn delete(&mut self, value: &T) -> bool {
+        match &self.value {
+            Some(key) => match key.cmp(value) {
+                Ordering::Equal => {
+                    self.delete_root();
+                    true
+                }
+                Ordering::Greater => Self::delete_from(&mut self.left, value),
+                Ordering::Less => Self::delete_from(&mut self.right, value),
+            },
+            None => false,
+        }
+    }
+
+    /// Deletes a value from the subtree in `child`, dropping the subtree if it ends up empty.
+    fn delete_from(child: &mut Option<Box<BinarySearchTree<T>>>, value: &T) -> bool {
+        match child {
+            Some(node) => {
+                let deleted = node.delete(value);
+                if node.is_empty() {
+                    *child = None;
+                }
+                deleted
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the value stored in this node, keeping the rest of the tree in place.
+    fn delete_root(&mut self) {
+        match (self.left.take(), self.right.take()) {
+            (None, None) => self.value = None,
+            (Some(child), None) | (None, Some(child)) => *self = *child,
+            (Some(left), Some(right)) => {
+                let mut right = Some(right);
+                self.value = Self::take_minimum(&mut right);
+                self.left = Some(left);
+                self.right = right;
+            }
+        }
+    }
+
+    /// Removes the smallest node of the subtree in `child` and returns its value.
+    fn take_minimum(child: &mut Option<Box<BinarySearchTree<T>>>) -> Option<T> {
+        match child {
+            Some(node) if node.left.is_some() => Self::take_minimum(&mut node.left),
+            Some(_) => {
+                let node = child.take().unwrap();
+                *child = node.right;
+                node.value
+            }
+            None => None,
+        }
+    }
+
+    /// Consumes this tree and returns its values in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let tree: BinarySearchTree<i32> = vec![5, 3, 7, 3].into_iter().collect();
+    ///
+    /// assert_eq!(tree.into_sorted_vec(), vec![3, 3, 5, 7]);
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut sorted = Vec::new();
+        let mut stack = Vec::new();
+        let mut current = Some(self);
+        loop {
+            while let Some(mut node) = current {
+                current = node.left.take().map(|left| *left);
+                stack.push(node);
+            }
+            match stack.pop() {
+                Some(mut node) => {
+                    sorted.extend(node.value.take());
+                    current = node.right.take().map(|right| *right);
+                }
+                None => return sorted,
+            }
+        }
+    }
+
+    /// Counts the values in this tree that are strictly smaller than `value`.
+    ///
+    /// Subtree sizes are not stored, so this walks the tree in order and takes time
+    /// linear in the returned rank plus the height of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to rank, which does not need to be in this tree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let tree: BinarySearchTree<i32> = vec![5, 3, 7].into_iter().collect();
+    ///
+    /// assert_eq!(tree.rank(&3), 0);
+    /// assert_eq!(tree.rank(&5), 1);
+    /// assert_eq!(tree.rank(&6), 2);
+    /// assert_eq!(tree.rank(&100), 3);
+    /// ```
+    pub fn rank(&self, value: &T) -> usize {
+        self.iter().take_while(|&key| key < value).count()
+    }
+
+    /// Gets the `k`-th smallest value in this tree, counting from 0, so that
+    /// `select(rank(x))` is `x` for every `x` in this tree.
+    ///
+    /// Like `rank`, this walks the tree in order and takes time linear in `k` plus the
+    /// height of the tree.
+    ///
+    /// # Returns
+    ///
+    /// The `k`-th smallest value, or `None` if this tree holds `k` values or fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::BinarySearchTree;
+    ///
+    /// let tree: BinarySearchTree<i32> = vec![5, 3, 7].into_iter().collect();
+    ///
+    /// assert_eq!(tree.select(0), Some(&3));
+    /// assert_eq!(tree.select(2), Some(&7));
+    /// assert_eq!(tree.select(3), None);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<&T> {
+        self.iter().nth(k)
+    }
+
     /// Gets the smallest value in this tree.
     ///
     /// # Returns
@@ -410,6 +633,19 @@ where
     }
 }
 
+impl<T> FromIterator<T> for BinarySearchTree<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = BinarySearchTree::new();
+        for value in iter {
+            tree.insert(value);
+        }
+        tree
+    }
+}
+
 /// Iterator for BinarySearchTree
 ///
 /// Iterates over the tree in ascending order
@@ -597,4 +833,207 @@ mod test {
         assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None);
     }
+
+    fn numbers_tree() -> BinarySearchTree<i32> {
+        //         8
+        //      /     \
+        //     3       10
+        //    / \        \
+        //   1   6        14
+        //      / \      /
+        //     4   7    13
+        let mut tree = BinarySearchTree::new();
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            tree.insert(*value);
+        }
+        tree
+    }
+
+    fn values(tree: &BinarySearchTree<i32>) -> Vec<i32> {
+        tree.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_delete_leaf() {
+        let mut tree = numbers_tree();
+        assert!(tree.delete(&7));
+        assert!(!tree.search(&7));
+        assert_eq!(values(&tree), vec![1, 3, 4, 6, 8, 10, 13, 14]);
+
+        assert!(tree.delete(&13));
+        assert_eq!(values(&tree), vec![1, 3, 4, 6, 8, 10, 14]);
+    }
+
+    #[test]
+    fn test_delete_node_with_one_child() {
+        let mut tree = numbers_tree();
+        assert!(tree.delete(&10));
+        assert!(!tree.search(&10));
+        assert!(tree.search(&14));
+        assert!(tree.search(&13));
+        assert_eq!(values(&tree), vec![1, 3, 4, 6, 7, 8, 13, 14]);
+    }
+
+    #[test]
+    fn test_delete_internal_node_with_two_children() {
+        let mut tree = numbers_tree();
+        assert!(tree.delete(&3));
+        assert!(!tree.search(&3));
+        assert_eq!(values(&tree), vec![1, 4, 6, 7, 8, 10, 13, 14]);
+
+        assert!(tree.delete(&6));
+        assert_eq!(values(&tree), vec![1, 4, 7, 8, 10, 13, 14]);
+    }
+
+    #[test]
+    fn test_delete_root() {
+        let mut tree = numbers_tree();
+        assert!(tree.delete(&8));
+        assert!(!tree.search(&8));
+        assert_eq!(values(&tree), vec![1, 3, 4, 6, 7, 10, 13, 14]);
+
+        let mut single = BinarySearchTree::new();
+        single.insert(1);
+        assert!(single.delete(&1));
+        assert!(single.is_empty());
+        assert_eq!(single.iter().next(), None);
+    }
+
+    #[test]
+    fn test_delete_missing() {
+        let mut tree = numbers_tree();
+        assert!(!tree.delete(&5));
+        assert!(!tree.delete(&100));
+        assert_eq!(values(&tree), vec![1, 3, 4, 6, 7, 8, 10, 13, 14]);
+
+        let mut empty: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert!(!empty.delete(&1));
+    }
+
+    #[test]
+    fn test_delete_duplicates_and_everything() {
+        let mut tree = numbers_tree();
+        tree.insert(6);
+        assert!(tree.delete(&6));
+        assert!(tree.search(&6));
+        assert!(tree.delete(&6));
+        assert!(!tree.search(&6));
+
+        let mut expected = vec![1, 3, 4, 7, 8, 10, 13, 14];
+        for value in [8, 1, 13, 3, 14, 4, 10, 7].iter() {
+            assert!(tree.delete(value));
+            expected.retain(|x| x != value);
+            assert_eq!(values(&tree), expected);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.minimum(), None);
+    }
+
+    #[test]
+    fn test_len_and_height() {
+        let tree = numbers_tree();
+        assert_eq!(tree.len(), 9);
+        assert_eq!(tree.height(), 4);
+
+        let memes = prequel_memes_tree();
+        assert_eq!(memes.len(), 7);
+        assert_eq!(memes.height(), 4);
+
+        let empty: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.height(), 0);
+
+        let mut single = BinarySearchTree::new();
+        single.insert(1);
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.height(), 1);
+    }
+
+    #[test]
+    fn test_len_and_height_after_changes() {
+        // sorted inserts degenerate into a path
+        let mut tree = BinarySearchTree::new();
+        for value in 0..10 {
+            tree.insert(value);
+        }
+        assert_eq!(tree.len(), 10);
+        assert_eq!(tree.height(), 10);
+
+        let mut tree = numbers_tree();
+        tree.delete(&13);
+        assert_eq!(tree.len(), 8);
+        assert_eq!(tree.height(), 4);
+        tree.delete(&4);
+        tree.delete(&7);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.height(), 3);
+        for value in [8, 3, 10, 1, 6, 14].iter() {
+            tree.delete(value);
+        }
+        assert_eq!(tree.len(), 0);
+        assert_eq!(tree.height(), 0);
+    }
+
+    #[test]
+    fn test_from_iter_and_into_sorted_vec() {
+        let mut values: Vec<i32> = (0..200).map(|i| i % 50 - 25).collect();
+        crate::general::shuffle(&mut values, 7);
+
+        let tree: BinarySearchTree<i32> = values.iter().copied().collect();
+        assert_eq!(tree.len(), values.len());
+        for value in values.iter() {
+            assert!(tree.search(value));
+        }
+
+        values.sort_unstable();
+        assert_eq!(tree.into_sorted_vec(), values);
+    }
+
+    #[test]
+    fn test_into_sorted_vec_edge_cases() {
+        let empty: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert!(empty.into_sorted_vec().is_empty());
+
+        let tree: BinarySearchTree<&str> = prequel_memes_tree().iter().copied().collect();
+        let mut expected: Vec<&str> = prequel_memes_tree().iter().copied().collect();
+        expected.sort_unstable();
+        assert_eq!(tree.into_sorted_vec(), expected);
+
+        let mut tree = numbers_tree();
+        tree.delete(&8);
+        assert_eq!(tree.into_sorted_vec(), vec![1, 3, 4, 6, 7, 10, 13, 14]);
+    }
+
+    #[test]
+    fn test_rank_and_select() {
+        let tree = numbers_tree();
+        let sorted = [1, 3, 4, 6, 7, 8, 10, 13, 14];
+        for (k, value) in sorted.iter().enumerate() {
+            assert_eq!(tree.rank(value), k);
+            assert_eq!(tree.select(k), Some(value));
+            assert_eq!(tree.select(tree.rank(value)), Some(value));
+        }
+
+        // values between and outside the stored ones
+        assert_eq!(tree.rank(&0), 0);
+        assert_eq!(tree.rank(&5), 3);
+        assert_eq!(tree.rank(&9), 6);
+        assert_eq!(tree.rank(&15), 9);
+        assert_eq!(tree.select(9), None);
+        assert_eq!(tree.select(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_rank_and_select_duplicates_and_empty() {
+        let tree: BinarySearchTree<i32> = vec![2, 1, 2, 3, 2].into_iter().collect();
+        assert_eq!(tree.rank(&2), 1);
+        assert_eq!(tree.rank(&3), 4);
+        assert_eq!(tree.select(tree.rank(&2)), Some(&2));
+        assert_eq!(tree.select(3), Some(&2));
+        assert_eq!(tree.select(4), Some(&3));
+
+        let empty: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert_eq!(empty.rank(&1), 0);
+        assert_eq!(empty.select(0), None);
+    }
 }
@@ -0,0 +1,268 @@
+//! A set of small non-negative integers stored as one bit per possible element, using the
+//! [BitVec](https://crates.io/crates/bitvec) crate.
+//!
+//! Membership tests and updates take constant time, and set operations work on whole
+//! machine words at once. The memory used grows with the largest element, not with the
+//! number of elements, so it suits dense sets of indices.
+
+use bitvec::prelude::*;
+use std::iter::FromIterator;
+
+/// A growable set of `usize` values backed by a `BitVec`: bit `i` is set when `i` is in
+/// the set. The bit vector grows as needed when larger values are inserted.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::data_structures::BitSet;
+///
+/// let mut primes = BitSet::new();
+/// for p in [2, 3, 5, 7, 11].iter() {
+///     primes.insert(*p);
+/// }
+/// let odd: BitSet = (1..12).step_by(2).collect();
+///
+/// assert!(primes.contains(7));
+/// assert!(!primes.contains(9));
+/// assert_eq!(primes.intersection(&odd).iter().collect::<Vec<_>>(), vec![3, 5, 7, 11]);
+/// assert_eq!(primes.difference(&odd).iter().collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(primes.union(&odd).count_ones(), 7);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    bits: BitVec,
+}
+
+impl BitSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        BitSet {
+            bits: BitVec::new(),
+        }
+    }
+
+    /// Creates an empty set with room for the values below `capacity`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        BitSet {
+            bits: bitvec![0; capacity],
+        }
+    }
+
+    /// Adds `value` to the set, returning `true` if it was not in the set already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `usize::MAX`, since the set would need one bit more than a
+    /// `usize` can count.
+    pub fn insert(&mut self, value: usize) -> bool {
+        if value >= self.bits.len() {
+            let len = value.checked_add(1).expect("BitSet cannot hold usize::MAX");
+            self.bits.resize(len, false);
+        }
+        !self.bits.replace(value, true)
+    }
+
+    /// Removes `value` from the set, returning `true` if it was in the set.
+    pub fn remove(&mut self, value: usize) -> bool {
+        value < self.bits.len() && self.bits.replace(value, false)
+    }
+
+    /// Returns `true` if `value` is in the set.
+    pub fn contains(&self, value: usize) -> bool {
+        self.bits.get(value).is_some_and(|bit| *bit)
+    }
+
+    /// Returns the number of values in the set.
+    pub fn count_ones(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// Returns `true` if the set holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.bits.not_any()
+    }
+
+    /// Iterates over the values in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter_ones()
+    }
+
+    /// Returns the values that are in `self`, in `other`, or in both.
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (mut bits, shorter) = if self.bits.len() >= other.bits.len() {
+            (self.bits.clone(), &other.bits)
+        } else {
+            (other.bits.clone(), &self.bits)
+        };
+        bits[..shorter.len()] |= shorter.as_bitslice();
+        BitSet { bits }
+    }
+
+    /// Returns the values that are both in `self` and in `other`.
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        let len = self.bits.len().min(other.bits.len());
+        let mut bits = self.bits[..len].to_bitvec();
+        bits &= &other.bits[..len];
+        BitSet { bits }
+    }
+
+    /// Returns the values that are in `self` but not in `other`.
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        let len = self.bits.len().min(other.bits.len());
+        let mut bits = self.bits.clone();
+        let kept = !other.bits[..len].to_bitvec();
+        bits[..len] &= kept.as_bitslice();
+        BitSet { bits }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::PCG32;
+    use std::collections::HashSet;
+
+    fn sorted(set: &HashSet<usize>) -> Vec<usize> {
+        let mut values: Vec<usize> = set.iter().copied().collect();
+        values.sort_unstable();
+        values
+    }
+
+    fn random_sets(rng: &mut PCG32, len: usize, range: u32) -> (BitSet, HashSet<usize>) {
+        let values: Vec<usize> = (0..len).map(|_| (rng.get_u32() % range) as usize).collect();
+        (
+            values.iter().copied().collect(),
+            values.iter().copied().collect(),
+        )
+    }
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = BitSet::new();
+        assert!(set.is_empty());
+        assert!(!set.contains(0));
+        assert!(!set.remove(3));
+
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(200));
+        assert!(set.insert(0));
+        assert!(set.contains(3));
+        assert!(set.contains(200));
+        assert!(!set.contains(4));
+        assert!(!set.contains(10_000));
+        assert_eq!(set.count_ones(), 3);
+
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert!(!set.contains(3));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 200]);
+
+        set.remove(0);
+        set.remove(200);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut set = BitSet::with_capacity(64);
+        assert!(set.is_empty());
+        assert_eq!(set.count_ones(), 0);
+        set.insert(63);
+        set.insert(64);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![63, 64]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot hold usize::MAX")]
+    fn insert_usize_max() {
+        BitSet::new().insert(usize::MAX);
+    }
+
+    #[test]
+    fn matches_hash_set() {
+        let mut rng = PCG32::new_default(11);
+        let mut set = BitSet::new();
+        let mut reference = HashSet::new();
+        for _ in 0..2000 {
+            let value = (rng.get_u32() % 300) as usize;
+            if rng.get_u32().is_multiple_of(3) {
+                assert_eq!(set.remove(value), reference.remove(&value));
+            } else {
+                assert_eq!(set.insert(value), reference.insert(value));
+            }
+            assert_eq!(set.count_ones(), reference.len());
+        }
+        assert_eq!(set.iter().collect::<Vec<_>>(), sorted(&reference));
+        for value in 0..320 {
+            assert_eq!(set.contains(value), reference.contains(&value));
+        }
+    }
+
+    #[test]
+    fn set_operations_match_hash_set() {
+        let mut rng = PCG32::new_default(5);
+        for &(len_a, range_a, len_b, range_b) in [
+            (50, 100, 50, 100),
+            (30, 64, 80, 500),
+            (200, 1000, 10, 20),
+            (0, 1, 40, 70),
+        ]
+        .iter()
+        {
+            let (a, set_a) = random_sets(&mut rng, len_a, range_a);
+            let (b, set_b) = random_sets(&mut rng, len_b, range_b);
+
+            let union: HashSet<usize> = set_a.union(&set_b).copied().collect();
+            let intersection: HashSet<usize> = set_a.intersection(&set_b).copied().collect();
+            let a_minus_b: HashSet<usize> = set_a.difference(&set_b).copied().collect();
+            let b_minus_a: HashSet<usize> = set_b.difference(&set_a).copied().collect();
+
+            assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), sorted(&union));
+            assert_eq!(b.union(&a).iter().collect::<Vec<_>>(), sorted(&union));
+            assert_eq!(
+                a.intersection(&b).iter().collect::<Vec<_>>(),
+                sorted(&intersection)
+            );
+            assert_eq!(
+                b.intersection(&a).iter().collect::<Vec<_>>(),
+                sorted(&intersection)
+            );
+            assert_eq!(
+                a.difference(&b).iter().collect::<Vec<_>>(),
+                sorted(&a_minus_b)
+            );
+            assert_eq!(
+                b.difference(&a).iter().collect::<Vec<_>>(),
+                sorted(&b_minus_a)
+            );
+        }
+    }
+
+    #[test]
+    fn count_ones() {
+        let evens: BitSet = (0..1000).step_by(2).collect();
+        assert_eq!(evens.count_ones(), 500);
+
+        let threes: BitSet = (0..1000).step_by(3).collect();
+        assert_eq!(evens.union(&threes).count_ones(), 500 + 334 - 167);
+        assert_eq!(evens.intersection(&threes).count_ones(), 167);
+        assert_eq!(evens.difference(&threes).count_ones(), 500 - 167);
+
+        let empty = BitSet::new();
+        assert_eq!(empty.count_ones(), 0);
+        assert_eq!(evens.union(&empty).count_ones(), 500);
+        assert!(evens.intersection(&empty).is_empty());
+        assert_eq!(evens.difference(&empty).count_ones(), 500);
+    }
+}
@@ -6,6 +6,9 @@
 //! This uses the [BitVec](https://crates.io/crates/bitvec) crate to store the bits.
 //!
 //! Consider looking into [Fnv](https://crates.io/crates/fnv) crate for more efficient hashing.
+//!
+//! [`ScalableBloomFilter`] builds on top of [`BloomFilter`] to keep the false positive
+//! probability bounded when the number of elements is not known in advance.
 
 use bitvec::prelude::*;
 use std::collections::hash_map::DefaultHasher;
@@ -112,6 +115,120 @@ impl BloomFilter {
     }
 }
 
+/// Each new sub-filter holds this many times more elements than the previous one.
+const GROWTH_FACTOR: usize = 2;
+/// Each new sub-filter targets this fraction of the previous one's error rate.
+const TIGHTENING_RATIO: f64 = 0.5;
+
+/// Bloom Filter that grows as elements are inserted, as described by Almeida et al. in
+/// "Scalable Bloom Filters".
+///
+/// It starts with a single [`BloomFilter`]. Once the newest one has received as many
+/// insertions as it was sized for, a larger sub-filter with a tighter error rate is added, and new elements go
+/// there. `contains` checks every sub-filter. Because the error rates of the sub-filters
+/// form a geometric series, the overall false positive probability stays below the
+/// configured `error_rate` no matter how many elements are inserted.
+///
+/// Example usage:
+/// ```
+/// use rust_algorithms::data_structures::ScalableBloomFilter;
+///
+/// let mut bloom_filter = ScalableBloomFilter::new(10, 0.01);
+///
+/// for i in 0..1000 {
+///     bloom_filter.insert(&i.to_string());
+/// }
+///
+/// assert!(bloom_filter.contains(&"42".to_string()));
+/// assert!(bloom_filter.contains(&"999".to_string()));
+/// ```
+pub struct ScalableBloomFilter {
+    /// Sub-filters, from the oldest (smallest) to the newest (largest)
+    filters: Vec<BloomFilter>,
+    /// Number of elements the newest sub-filter was sized for
+    capacity: usize,
+    /// Number of insertions into the newest sub-filter
+    inserted: usize,
+    /// Error rate the newest sub-filter was sized for
+    filter_error_rate: f64,
+}
+
+impl ScalableBloomFilter {
+    /// Creates a new Scalable Bloom Filter whose first sub-filter is sized for
+    /// `initial_capacity` elements, with an overall false positive probability of at most
+    /// `error_rate`.
+    pub fn new(initial_capacity: usize, error_rate: f64) -> Self {
+        assert!(initial_capacity > 0, "initial capacity must be positive");
+        assert!(
+            error_rate > 0.0 && error_rate < 1.0,
+            "error rate must be in (0, 1)"
+        );
+
+        // The error rates of the sub-filters sum up to at most
+        // `filter_error_rate / (1 - TIGHTENING_RATIO)`.
+        let filter_error_rate = error_rate * (1.0 - TIGHTENING_RATIO);
+        ScalableBloomFilter {
+            filters: vec![Self::sub_filter(initial_capacity, filter_error_rate)],
+            capacity: initial_capacity,
+            inserted: 0,
+            filter_error_rate,
+        }
+    }
+
+    /// Inserts an element into the newest sub-filter, adding a new one first if it is full
+    ///
+    /// Time Complexity: O(k) where k is the number of hash functions of the newest sub-filter
+    pub fn insert<T>(&mut self, item: &T)
+    where
+        T: AsRef<[u8]> + Hash,
+    {
+        if self.inserted >= self.capacity {
+            self.capacity *= GROWTH_FACTOR;
+            self.filter_error_rate *= TIGHTENING_RATIO;
+            self.filters
+                .push(Self::sub_filter(self.capacity, self.filter_error_rate));
+            self.inserted = 0;
+        }
+
+        if let Some(filter) = self.filters.last_mut() {
+            filter.insert(item);
+            self.inserted += 1;
+        }
+    }
+
+    /// Checks if an element may be in any of the sub-filters
+    /// NOTE: `true` implies the element may be in the set, `false` implies the element is not in the set.
+    ///
+    /// Time Complexity: O(k * s) where s is the number of sub-filters
+    pub fn contains<T>(&self, item: &T) -> bool
+    where
+        T: AsRef<[u8]> + Hash,
+    {
+        self.filters.iter().any(|filter| filter.contains(item))
+    }
+
+    /// Creates a Bloom Filter sized for `capacity` elements and the given error rate, using
+    /// the optimal number of bits and hash functions.
+    fn sub_filter(capacity: usize, error_rate: f64) -> BloomFilter {
+        let ln2 = std::f64::consts::LN_2;
+        let size = (capacity as f64 * -error_rate.ln() / (ln2 * ln2)).ceil() as usize;
+        let hash_count = (-error_rate.log2()).ceil().max(1.0) as u64;
+
+        let hash_functions = (0..hash_count)
+            .map(|seed| {
+                Box::new(move |data: &[u8]| {
+                    let mut hasher = DefaultHasher::new();
+                    seed.hash(&mut hasher);
+                    data.hash(&mut hasher);
+                    hasher.finish()
+                }) as Box<dyn Fn(&[u8]) -> u64>
+            })
+            .collect();
+
+        BloomFilter::new(size.max(1), hash_functions)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,4 +448,79 @@ mod tests {
         assert!(!bloom_filter.contains(&"grape"));
         assert!(!bloom_filter.contains(&"kiwi"));
     }
+
+    #[test]
+    fn test_scalable_no_false_negatives() {
+        let mut bloom_filter = ScalableBloomFilter::new(8, 0.01);
+        let items: Vec<String> = (0..500).map(|i| format!("item-{}", i)).collect();
+
+        for item in &items {
+            bloom_filter.insert(item);
+        }
+
+        for item in &items {
+            assert!(bloom_filter.contains(item), "False negative for: {}", item);
+        }
+    }
+
+    #[test]
+    fn test_scalable_grows() {
+        let mut bloom_filter = ScalableBloomFilter::new(16, 0.01);
+        assert_eq!(bloom_filter.filters.len(), 1);
+
+        for i in 0..1000 {
+            bloom_filter.insert(&i.to_string());
+        }
+
+        assert!(bloom_filter.filters.len() > 1);
+        for pair in bloom_filter.filters.windows(2) {
+            assert!(pair[0].bit_array.len() < pair[1].bit_array.len());
+            assert!(pair[0].hash_functions.len() <= pair[1].hash_functions.len());
+        }
+    }
+
+    #[test]
+    fn test_scalable_grows_at_capacity() {
+        let mut bloom_filter = ScalableBloomFilter::new(16, 0.01);
+        for i in 0..16 {
+            bloom_filter.insert(&i.to_string());
+        }
+        assert_eq!(bloom_filter.filters.len(), 1);
+
+        bloom_filter.insert(&"16");
+        assert_eq!(bloom_filter.filters.len(), 2);
+
+        // the second sub-filter holds twice as many elements
+        for i in 17..48 {
+            bloom_filter.insert(&i.to_string());
+        }
+        assert_eq!(bloom_filter.filters.len(), 2);
+        bloom_filter.insert(&"48");
+        assert_eq!(bloom_filter.filters.len(), 3);
+    }
+
+    #[test]
+    fn test_scalable_false_positive_rate() {
+        let target = 0.01;
+        let initial_capacity = 100;
+        let mut bloom_filter = ScalableBloomFilter::new(initial_capacity, target);
+
+        // Insert a hundred times more elements than the initial capacity
+        for i in 0..initial_capacity * 100 {
+            bloom_filter.insert(&format!("inserted-{}", i));
+        }
+
+        let queries = 20_000;
+        let false_positives = (0..queries)
+            .filter(|i| bloom_filter.contains(&format!("absent-{}", i)))
+            .count();
+        let observed = false_positives as f64 / queries as f64;
+
+        assert!(
+            observed <= target,
+            "observed false positive rate {} exceeds target {}",
+            observed,
+            target
+        );
+    }
 }
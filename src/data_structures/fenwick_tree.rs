@@ -1,4 +1,4 @@
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Sub};
 
 /// A Fenwick Tree (also known as a Binary Indexed Tree) is a data structure
 /// that can efficiently update elements and calculate prefix sums in a table of numbers.
@@ -26,11 +26,15 @@ use std::ops::{Add, AddAssign};
 /// perform efficient queries and updates. The tree is constructed in such a way that the value
 /// of each node is the sum of the values of the nodes in its subtree.
 ///
-pub struct FenwickTree<T: Add + AddAssign + Copy + Default> {
+/// The values must form an abelian group: `Add` is the group operation, `T::default()` its
+/// identity and `Sub` its inverse. Subtraction is what turns two prefix sums into the sum of a
+/// range, a single element, or the update that replaces an element.
+///
+pub struct FenwickTree<T: Add + Sub + AddAssign + Copy + Default> {
     data: Vec<T>,
 }
 
-impl<T: Add<Output = T> + AddAssign + Copy + Default> FenwickTree<T> {
+impl<T: Add<Output = T> + Sub<Output = T> + AddAssign + Copy + Default> FenwickTree<T> {
     /// Create a new FenwickTree with length `len`
     ///
     /// # Arguments
@@ -75,7 +79,7 @@ impl<T: Add<Output = T> + AddAssign + Copy + Default> FenwickTree<T> {
     /// assert_eq!(ft.prefix_sum(1), 5);
     /// ```
     pub fn add(&mut self, i: usize, val: T) {
-        assert!(i < self.data.len());
+        assert!(i < self.len());
 
         let mut i = i + 1;
 
@@ -120,7 +124,7 @@ impl<T: Add<Output = T> + AddAssign + Copy + Default> FenwickTree<T> {
     /// assert_eq!(ft.prefix_sum(9), 55);
     /// ```
     pub fn prefix_sum(&self, i: usize) -> T {
-        assert!(i < self.data.len());
+        assert!(i < self.len());
 
         let mut i = i + 1;
         let mut res = T::default();
@@ -132,6 +136,272 @@ impl<T: Add<Output = T> + AddAssign + Copy + Default> FenwickTree<T> {
 
         res
     }
+
+    /// Get the number of elements in the FenwickTree
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::FenwickTree;
+    ///
+    /// let ft: FenwickTree<i64> = FenwickTree::with_len(10);
+    ///
+    /// assert_eq!(ft.len(), 10);
+    /// assert!(!ft.is_empty());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len() - 1
+    }
+
+    /// Check whether the FenwickTree has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the sum of the elements from index `l` to index `r`, both included
+    ///
+    /// # Arguments
+    ///
+    /// * `l` - The index of the first element of the range
+    /// * `r` - The index of the last element of the range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::FenwickTree;
+    ///
+    /// let mut ft = FenwickTree::with_len(5);
+    /// ft.add(0, 4);
+    /// ft.add(1, -7);
+    /// ft.add(2, 2);
+    /// ft.add(3, 5);
+    ///
+    /// assert_eq!(ft.range_sum(1, 3), 0);
+    /// assert_eq!(ft.range_sum(0, 1), -3);
+    /// ```
+    pub fn range_sum(&self, l: usize, r: usize) -> T {
+        assert!(l <= r);
+
+        if l == 0 {
+            self.prefix_sum(r)
+        } else {
+            self.prefix_sum(r) - self.prefix_sum(l - 1)
+        }
+    }
+
+    /// Get the value of the `i`-th element
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the element
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::FenwickTree;
+    ///
+    /// let mut ft = FenwickTree::with_len(3);
+    /// ft.add(1, 4);
+    /// ft.add(1, -6);
+    ///
+    /// assert_eq!(ft.point_get(0), 0);
+    /// assert_eq!(ft.point_get(1), -2);
+    /// ```
+    pub fn point_get(&self, i: usize) -> T {
+        self.range_sum(i, i)
+    }
+
+    /// Replace the value of the `i`-th element with `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the element to replace
+    /// * `val` - The new value of the `i`-th element
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::FenwickTree;
+    ///
+    /// let mut ft = FenwickTree::with_len(3);
+    /// ft.add(0, 1);
+    /// ft.add(1, 10);
+    /// ft.set(1, -3);
+    ///
+    /// assert_eq!(ft.point_get(1), -3);
+    /// assert_eq!(ft.prefix_sum(2), -2);
+    /// ```
+    pub fn set(&mut self, i: usize, val: T) {
+        let current = self.point_get(i);
+        self.add(i, val - current);
+    }
+}
+
+/// A Fenwick Tree in range-update point-query mode: adding a value to every element of a
+/// range and reading a single element both take O(log n) time.
+///
+/// It stores the difference array of the values in a `FenwickTree`: adding `delta` to the
+/// range `[l, r]` adds `delta` at `l` and subtracts it right after `r`, and the prefix sum
+/// up to `i` adds back up to the `i`-th value. Keeping it a separate type from
+/// `FenwickTree` means the two modes cannot be mixed on the same tree.
+pub struct RangeUpdateFenwickTree<T: Add + Sub + AddAssign + Copy + Default> {
+    differences: FenwickTree<T>,
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + AddAssign + Copy + Default> RangeUpdateFenwickTree<T> {
+    /// Create a new RangeUpdateFenwickTree over `len` zeroes
+    pub fn with_len(len: usize) -> Self {
+        RangeUpdateFenwickTree {
+            differences: FenwickTree::with_len(len),
+        }
+    }
+
+    /// Add `delta` to every element from index `l` to index `r`, both included
+    ///
+    /// # Arguments
+    ///
+    /// * `l` - The index of the first element of the range
+    /// * `r` - The index of the last element of the range
+    /// * `delta` - The value to add to every element of the range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::RangeUpdateFenwickTree;
+    ///
+    /// let mut ft = RangeUpdateFenwickTree::with_len(5);
+    /// ft.update_range(1, 3, 4);
+    /// ft.update_range(2, 4, -1);
+    ///
+    /// assert_eq!(ft.point_query(0), 0);
+    /// assert_eq!(ft.point_query(1), 4);
+    /// assert_eq!(ft.point_query(2), 3);
+    /// assert_eq!(ft.point_query(4), -1);
+    /// ```
+    pub fn update_range(&mut self, l: usize, r: usize, delta: T) {
+        assert!(l <= r && r < self.len());
+
+        self.differences.add(l, delta);
+        if r + 1 < self.len() {
+            self.differences.add(r + 1, T::default() - delta);
+        }
+    }
+
+    /// Get the value of the `i`-th element
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the element
+    pub fn point_query(&self, i: usize) -> T {
+        self.differences.prefix_sum(i)
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    /// Check whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// A two-dimensional Fenwick Tree, answering sums over rectangles of a grid of numbers.
+///
+/// Every row of the tree is itself a Fenwick Tree over the columns, and the rows are
+/// combined the same way, so adding to a cell and summing the rectangle `[0..=x, 0..=y]`
+/// both take O(log n * log m) time on an n by m grid.
+///
+/// The values must form an abelian group, as for `FenwickTree`.
+pub struct FenwickTree2D<T: Add + Sub + AddAssign + Copy + Default> {
+    data: Vec<Vec<T>>,
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + AddAssign + Copy + Default> FenwickTree2D<T> {
+    /// Create a new FenwickTree2D over a grid of `x_len` by `y_len` zeroes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::FenwickTree2D;
+    ///
+    /// let mut ft = FenwickTree2D::with_size(3, 4);
+    /// ft.add(0, 0, 1);
+    /// ft.add(1, 2, 5);
+    /// ft.add(2, 3, 7);
+    ///
+    /// assert_eq!(ft.prefix_sum(1, 2), 6);
+    /// assert_eq!(ft.prefix_sum(2, 3), 13);
+    /// assert_eq!(ft.rectangle_sum(1, 1, 2, 3), 12);
+    /// ```
+    pub fn with_size(x_len: usize, y_len: usize) -> Self {
+        FenwickTree2D {
+            data: vec![vec![T::default(); y_len + 1]; x_len + 1],
+        }
+    }
+
+    /// Add `val` to the element at `(x, y)`
+    pub fn add(&mut self, x: usize, y: usize, val: T) {
+        assert!(x < self.x_len() && y < self.y_len());
+
+        let mut i = x + 1;
+        while i < self.data.len() {
+            let row = &mut self.data[i];
+            let mut j = y + 1;
+            while j < row.len() {
+                row[j] += val;
+                j += lowbit(j);
+            }
+            i += lowbit(i);
+        }
+    }
+
+    /// Get the sum of the elements in the rectangle `[0..=x, 0..=y]`
+    pub fn prefix_sum(&self, x: usize, y: usize) -> T {
+        assert!(x < self.x_len() && y < self.y_len());
+
+        let mut res = T::default();
+        let mut i = x + 1;
+        while i > 0 {
+            let mut j = y + 1;
+            while j > 0 {
+                res += self.data[i][j];
+                j -= lowbit(j);
+            }
+            i -= lowbit(i);
+        }
+
+        res
+    }
+
+    /// Get the sum of the elements in the rectangle `[x1..=x2, y1..=y2]`
+    pub fn rectangle_sum(&self, x1: usize, y1: usize, x2: usize, y2: usize) -> T {
+        assert!(x1 <= x2 && y1 <= y2);
+
+        // inclusion-exclusion over the prefix rectangles, skipping the empty ones
+        let mut res = self.prefix_sum(x2, y2);
+        if x1 > 0 {
+            res = res - self.prefix_sum(x1 - 1, y2);
+        }
+        if y1 > 0 {
+            res = res - self.prefix_sum(x2, y1 - 1);
+        }
+        if x1 > 0 && y1 > 0 {
+            res += self.prefix_sum(x1 - 1, y1 - 1);
+        }
+        res
+    }
+
+    /// Get the number of elements along the first coordinate
+    pub fn x_len(&self) -> usize {
+        self.data.len() - 1
+    }
+
+    /// Get the number of elements along the second coordinate
+    pub fn y_len(&self) -> usize {
+        self.data[0].len() - 1
+    }
 }
 
 /// get the lowest bit of `i`
@@ -139,3 +409,179 @@ const fn lowbit(x: usize) -> usize {
     let x = x as isize;
     (x & (-x)) as usize
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FenwickTree, FenwickTree2D, RangeUpdateFenwickTree};
+    use crate::math::PCG32;
+
+    #[test]
+    fn negative_updates() {
+        let mut ft = FenwickTree::with_len(6);
+        for (i, val) in [5i64, -3, 8, -10, 2, -2].iter().enumerate() {
+            ft.add(i, *val);
+        }
+
+        assert_eq!(ft.prefix_sum(5), 0);
+        assert_eq!(ft.range_sum(0, 1), 2);
+        assert_eq!(ft.range_sum(1, 3), -5);
+        assert_eq!(ft.range_sum(3, 3), -10);
+
+        ft.add(3, -5);
+        assert_eq!(ft.point_get(3), -15);
+        assert_eq!(ft.range_sum(2, 4), -5);
+    }
+
+    #[test]
+    fn range_sums_crossing_zero() {
+        let mut ft = FenwickTree::with_len(4);
+        ft.add(0, 3i64);
+        ft.add(1, -4);
+        ft.add(2, 2);
+        ft.add(3, -1);
+
+        // prefix sums go 3, -1, 1, 0
+        let prefixes: Vec<i64> = (0..4).map(|i| ft.prefix_sum(i)).collect();
+        assert_eq!(prefixes, vec![3, -1, 1, 0]);
+        assert_eq!(ft.range_sum(1, 2), -2);
+        assert_eq!(ft.range_sum(0, 3), 0);
+    }
+
+    #[test]
+    fn set_replaces_value() {
+        let mut ft = FenwickTree::with_len(3);
+        ft.set(0, -7i64);
+        ft.set(2, 4);
+        ft.set(0, 9);
+
+        assert_eq!(ft.point_get(0), 9);
+        assert_eq!(ft.point_get(1), 0);
+        assert_eq!(ft.point_get(2), 4);
+        assert_eq!(ft.range_sum(0, 2), 13);
+    }
+
+    #[test]
+    fn matches_naive_sums() {
+        let mut rng = PCG32::new_default(17);
+        let len = 100;
+        let mut ft = FenwickTree::with_len(len);
+        let mut values = vec![0i64; len];
+
+        for _ in 0..1000 {
+            let i = rng.get_u32() as usize % len;
+            let val = (rng.get_u32() % 2001) as i64 - 1000;
+            if rng.get_u32() & 1 == 0 {
+                ft.add(i, val);
+                values[i] += val;
+            } else {
+                ft.set(i, val);
+                values[i] = val;
+            }
+
+            let l = rng.get_u32() as usize % len;
+            let r = l + rng.get_u32() as usize % (len - l);
+            assert_eq!(ft.range_sum(l, r), values[l..=r].iter().sum::<i64>());
+        }
+
+        for (i, val) in values.iter().enumerate() {
+            assert_eq!(ft.point_get(i), *val);
+        }
+    }
+
+    #[test]
+    fn overlapping_range_updates() {
+        let mut ft = RangeUpdateFenwickTree::with_len(8);
+        ft.update_range(0, 7, 1i64);
+        ft.update_range(2, 5, 10);
+        ft.update_range(4, 7, -3);
+        ft.update_range(5, 5, 100);
+
+        let values: Vec<i64> = (0..8).map(|i| ft.point_query(i)).collect();
+        assert_eq!(values, vec![1, 1, 11, 11, 8, 108, -2, -2]);
+    }
+
+    #[test]
+    fn range_updates_match_naive() {
+        let mut rng = PCG32::new_default(23);
+        let len = 50;
+        let mut ft = RangeUpdateFenwickTree::with_len(len);
+        let mut values = vec![0i64; len];
+
+        for _ in 0..500 {
+            let l = rng.get_u32() as usize % len;
+            let r = l + rng.get_u32() as usize % (len - l);
+            let delta = (rng.get_u32() % 201) as i64 - 100;
+            ft.update_range(l, r, delta);
+            for v in values[l..=r].iter_mut() {
+                *v += delta;
+            }
+
+            let i = rng.get_u32() as usize % len;
+            assert_eq!(ft.point_query(i), values[i]);
+        }
+
+        for (i, val) in values.iter().enumerate() {
+            assert_eq!(ft.point_query(i), *val);
+        }
+    }
+
+    #[test]
+    fn two_dimensional_matches_brute_force() {
+        let mut rng = PCG32::new_default(31);
+        let (x_len, y_len) = (12, 7);
+        let mut ft = FenwickTree2D::with_size(x_len, y_len);
+        let mut grid = vec![vec![0i64; y_len]; x_len];
+
+        for _ in 0..300 {
+            let x = rng.get_u32() as usize % x_len;
+            let y = rng.get_u32() as usize % y_len;
+            let val = (rng.get_u32() % 201) as i64 - 100;
+            ft.add(x, y, val);
+            grid[x][y] += val;
+
+            let x1 = rng.get_u32() as usize % x_len;
+            let x2 = x1 + rng.get_u32() as usize % (x_len - x1);
+            let y1 = rng.get_u32() as usize % y_len;
+            let y2 = y1 + rng.get_u32() as usize % (y_len - y1);
+            let brute: i64 = grid[x1..=x2]
+                .iter()
+                .map(|row| row[y1..=y2].iter().sum::<i64>())
+                .sum();
+            assert_eq!(ft.rectangle_sum(x1, y1, x2, y2), brute);
+        }
+
+        for x in 0..x_len {
+            for y in 0..y_len {
+                let brute: i64 = grid[..=x]
+                    .iter()
+                    .map(|row| row[..=y].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(ft.prefix_sum(x, y), brute);
+            }
+        }
+    }
+
+    #[test]
+    fn two_dimensional_single_row() {
+        let mut ft = FenwickTree2D::with_size(1, 5);
+        for y in 0..5 {
+            ft.add(0, y, y as i64 + 1);
+        }
+        assert_eq!(ft.prefix_sum(0, 4), 15);
+        assert_eq!(ft.rectangle_sum(0, 1, 0, 3), 9);
+        assert_eq!((ft.x_len(), ft.y_len()), (1, 5));
+    }
+
+    #[test]
+    fn empty() {
+        let ft: FenwickTree<i64> = FenwickTree::with_len(0);
+        assert!(ft.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        let mut ft = FenwickTree::with_len(3);
+        ft.add(3, 1i64);
+    }
+}
@@ -32,6 +32,51 @@ where
     }
 }
 
+impl<'a, T> DirectedGraph<'a, T>
+where
+    T: 'a + Eq + Hash,
+{
+    /// Counts the edges going into `node`, self-loops included.
+    pub fn in_degree(&self, node: &T) -> Result<usize, NodeNotInGraph> {
+        if !self.adjacency_table.contains_key(node) {
+            return Err(NodeNotInGraph);
+        }
+        Ok(self
+            .adjacency_table
+            .values()
+            .flatten()
+            .filter(|(to, _)| *to == node)
+            .count())
+    }
+
+    /// Counts the edges going out of `node`, self-loops included.
+    pub fn out_degree(&self, node: &T) -> Result<usize, NodeNotInGraph> {
+        match self.adjacency_table.get(node) {
+            None => Err(NodeNotInGraph),
+            Some(neighbours) => Ok(neighbours.len()),
+        }
+    }
+}
+
+impl<'a, T> DirectedGraph<'a, T>
+where
+    T: 'a + Eq + Hash + fmt::Display,
+{
+    /// Renders the graph in the Graphviz DOT language, as a `digraph` whose edges are
+    /// written `"from" -> "to"` and labelled with their weight.
+    ///
+    /// Nodes are named after their `Display` output, and lines are sorted so the output
+    /// does not depend on the order of the adjacency table.
+    pub fn to_dot(&self) -> String {
+        let edges = self
+            .edges()
+            .into_iter()
+            .map(|(from, to, weight)| dot_edge(&from.to_string(), "->", &to.to_string(), weight))
+            .collect();
+        dot_graph("digraph", self.nodes(), edges)
+    }
+}
+
 pub struct UndirectedGraph<'a, T> {
     adjacency_table: HashMap<&'a T, Vec<(&'a T, i32)>>,
 }
@@ -62,6 +107,102 @@ where
             e.push((edge.0, edge.2));
         });
     }
+    fn remove_edge(&mut self, from: &T, to: &T) -> bool {
+        let removed = remove_edges_between(&mut self.adjacency_table, from, to);
+        remove_edges_between(&mut self.adjacency_table, to, from);
+        removed
+    }
+}
+
+impl<'a, T> UndirectedGraph<'a, T>
+where
+    T: 'a + Eq + Hash + fmt::Display,
+{
+    /// Renders the graph in the Graphviz DOT language, as a `graph` whose edges are
+    /// written `"a" -- "b"` and labelled with their weight.
+    ///
+    /// Nodes are named after their `Display` output, and lines are sorted so the output
+    /// does not depend on the order of the adjacency table. Every edge is written once,
+    /// even though the adjacency table holds it in both directions.
+    pub fn to_dot(&self) -> String {
+        let mut edges: Vec<String> = self
+            .edges()
+            .into_iter()
+            .map(|(from, to, weight)| {
+                let (from, to) = (from.to_string(), to.to_string());
+                if from <= to {
+                    dot_edge(&from, "--", &to, weight)
+                } else {
+                    dot_edge(&to, "--", &from, weight)
+                }
+            })
+            .collect();
+        // once sorted, the two copies of every edge are next to each other
+        edges.sort();
+        let edges = edges.into_iter().step_by(2).collect();
+        dot_graph("graph", self.nodes(), edges)
+    }
+}
+
+/// Drops the edges from `from` to `to` out of an adjacency table, returning whether there were any.
+fn remove_edges_between<T: Eq + Hash>(
+    table: &mut HashMap<&T, Vec<(&T, i32)>>,
+    from: &T,
+    to: &T,
+) -> bool {
+    match table.get_mut(from) {
+        Some(neighbours) => {
+            let before = neighbours.len();
+            neighbours.retain(|(node, _)| *node != to);
+            neighbours.len() != before
+        }
+        None => false,
+    }
+}
+
+/// Quotes a DOT identifier, escaping the characters that would end or break the string.
+fn dot_id(label: &str) -> String {
+    let mut quoted = String::with_capacity(label.len() + 2);
+    quoted.push('"');
+    for c in label.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn dot_edge(from: &str, op: &str, to: &str, weight: i32) -> String {
+    format!(
+        "    {} {} {} [label=\"{}\"];",
+        dot_id(from),
+        op,
+        dot_id(to),
+        weight
+    )
+}
+
+fn dot_graph<T: fmt::Display>(keyword: &str, nodes: HashSet<&T>, mut edges: Vec<String>) -> String {
+    let mut nodes: Vec<String> = nodes
+        .into_iter()
+        .map(|node| format!("    {};", dot_id(&node.to_string())))
+        .collect();
+    nodes.sort();
+    edges.sort();
+
+    let mut dot = format!("{} {{\n", keyword);
+    for line in nodes.iter().chain(edges.iter()) {
+        dot.push_str(line);
+        dot.push('\n');
+    }
+    dot.push_str("}\n");
+    dot
 }
 
 pub trait Graph<'a, T>
@@ -92,6 +233,35 @@ where
             });
     }
 
+    /// Removes a node along with every edge going to or from it.
+    /// Returns `false` if the node was not in the graph.
+    fn remove_node(&mut self, node: &T) -> bool {
+        let table = self.adjacency_table_mutable();
+        if table.remove(node).is_none() {
+            return false;
+        }
+        for neighbours in table.values_mut() {
+            neighbours.retain(|(to, _)| *to != node);
+        }
+        true
+    }
+
+    /// Removes every edge going from `from` to `to`.
+    /// Returns `false` if there was no such edge.
+    fn remove_edge(&mut self, from: &T, to: &T) -> bool {
+        remove_edges_between(self.adjacency_table_mutable(), from, to)
+    }
+
+    /// Returns the weight of the edge going from `from` to `to`, or `None` if there is no
+    /// such edge. If several edges join them, the weight of the first one added is returned.
+    fn edge_weight(&self, from: &T, to: &T) -> Option<i32> {
+        self.adjacency_table()
+            .get(from)?
+            .iter()
+            .find(|(node, _)| *node == to)
+            .map(|(_, weight)| *weight)
+    }
+
     fn neighbours(&self, node: &'a T) -> Result<&Vec<(&'a T, i32)>, NodeNotInGraph> {
         match self.adjacency_table().get(node) {
             None => Err(NodeNotInGraph),
@@ -163,6 +333,108 @@ mod test_undirected_graph {
 
         assert_eq!(graph.neighbours(&a).unwrap(), &vec![(&b, 5), (&c, 7)]);
     }
+
+    #[test]
+    fn test_edge_weight() {
+        let mut graph: UndirectedGraph<String> = UndirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_node(&c);
+
+        assert_eq!(graph.edge_weight(&a, &b), Some(5));
+        assert_eq!(graph.edge_weight(&b, &a), Some(5));
+        assert_eq!(graph.edge_weight(&a, &c), None);
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph: UndirectedGraph<String> = UndirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&b, &c, 10));
+
+        // either end can be given first
+        assert!(graph.remove_edge(&b, &a));
+        assert!(!graph.remove_edge(&a, &b));
+        assert!(!graph.remove_edge(&a, &c));
+
+        assert_eq!(graph.edges().len(), 2);
+        assert!(graph.edges().contains(&(&b, &c, 10)));
+        assert!(graph.edges().contains(&(&c, &b, 10)));
+        assert!(graph.neighbours(&a).unwrap().is_empty());
+        assert_eq!(graph.nodes().len(), 3);
+    }
+
+    #[test]
+    fn test_remove_node() {
+        let mut graph: UndirectedGraph<String> = UndirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+        let d = String::from("d");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&b, &c, 10));
+        graph.add_edge((&c, &a, 7));
+        graph.add_edge((&b, &b, 1));
+
+        assert!(graph.remove_node(&b));
+        assert!(!graph.remove_node(&b));
+        assert!(!graph.remove_node(&d));
+
+        assert_eq!(graph.nodes(), [&a, &c].iter().cloned().collect());
+        let mut edges = graph.edges();
+        edges.sort();
+        assert_eq!(edges, vec![(&a, &c, 7), (&c, &a, 7)]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph: UndirectedGraph<String> = UndirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+        let d = String::from("d");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&c, &b, 10));
+        graph.add_edge((&c, &c, 1));
+        graph.add_node(&d);
+
+        let dot = graph.to_dot();
+        assert_eq!(
+            dot,
+            "graph {\n    \"a\";\n    \"b\";\n    \"c\";\n    \"d\";\n    \"a\" -- \"b\" [label=\"5\"];\n    \"b\" -- \"c\" [label=\"10\"];\n    \"c\" -- \"c\" [label=\"1\"];\n}\n"
+        );
+        assert!(!dot.contains("->"));
+    }
+
+    #[test]
+    fn test_to_dot_parallel_edges() {
+        let mut graph: UndirectedGraph<String> = UndirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+
+        graph.add_edge((&a, &b, 3));
+        graph.add_edge((&b, &a, 3));
+
+        let dot = graph.to_dot();
+        assert_eq!(
+            dot.matches("    \"a\" -- \"b\" [label=\"3\"];\n").count(),
+            2
+        );
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +538,142 @@ mod test_directed_graph {
         assert!(graph.contains(&c));
         assert!(!graph.contains(&d));
     }
+
+    #[test]
+    fn test_edge_weight() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+        let d = String::from("d");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&a, &b, 8));
+        graph.add_edge((&b, &c, -2));
+
+        assert_eq!(graph.edge_weight(&a, &b), Some(5));
+        assert_eq!(graph.edge_weight(&b, &c), Some(-2));
+        assert_eq!(graph.edge_weight(&b, &a), None);
+        assert_eq!(graph.edge_weight(&a, &c), None);
+        assert_eq!(graph.edge_weight(&d, &a), None);
+    }
+
+    #[test]
+    fn test_degrees() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+        let d = String::from("d");
+        let e = String::from("e");
+
+        graph.add_edge((&a, &b, 1));
+        graph.add_edge((&a, &c, 1));
+        graph.add_edge((&b, &c, 1));
+        graph.add_edge((&c, &a, 1));
+        graph.add_edge((&c, &c, 1));
+        graph.add_node(&d);
+
+        let degrees = |node: &String| {
+            (
+                graph.in_degree(node).unwrap(),
+                graph.out_degree(node).unwrap(),
+            )
+        };
+        assert_eq!(degrees(&a), (1, 2));
+        assert_eq!(degrees(&b), (1, 1));
+        assert_eq!(degrees(&c), (3, 2));
+        assert_eq!(degrees(&d), (0, 0));
+
+        assert!(graph.in_degree(&e).is_err());
+        assert!(graph.out_degree(&e).is_err());
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&b, &a, 7));
+        graph.add_edge((&a, &b, 6));
+
+        // the reverse edge is a different edge
+        assert!(graph.remove_edge(&a, &b));
+        assert!(!graph.remove_edge(&a, &b));
+
+        assert_eq!(graph.edges(), vec![(&b, &a, 7)]);
+        assert_eq!(graph.nodes(), [&a, &b].iter().cloned().collect());
+    }
+
+    #[test]
+    fn test_remove_node() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&b, &c, 10));
+        graph.add_edge((&c, &a, 7));
+        graph.add_edge((&c, &b, 3));
+
+        assert!(graph.remove_node(&b));
+        assert!(!graph.remove_node(&b));
+        assert!(!graph.contains(&b));
+
+        assert_eq!(graph.nodes(), [&a, &c].iter().cloned().collect());
+        assert_eq!(graph.edges(), vec![(&c, &a, 7)]);
+        assert!(graph.neighbours(&a).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = String::from("c");
+
+        graph.add_edge((&a, &b, 5));
+        graph.add_edge((&b, &c, -10));
+        graph.add_edge((&c, &a, 7));
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        for line in [
+            "    \"a\" -> \"b\" [label=\"5\"];",
+            "    \"b\" -> \"c\" [label=\"-10\"];",
+            "    \"c\" -> \"a\" [label=\"7\"];",
+            "    \"a\";",
+        ]
+        .iter()
+        {
+            assert!(dot.lines().any(|l| l == *line), "missing {}", line);
+        }
+        assert!(!dot.contains("--"));
+        assert_eq!(dot.lines().count(), 8);
+    }
+
+    #[test]
+    fn test_to_dot_escapes_labels() {
+        let mut graph: DirectedGraph<String> = DirectedGraph::new();
+
+        let quoted = String::from("say \"hi\"");
+        let path = String::from("C:\\dir");
+        let lines = String::from("two\nlines");
+
+        graph.add_edge((&quoted, &path, 1));
+        graph.add_edge((&path, &lines, 2));
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("    \"say \\\"hi\\\"\" -> \"C:\\\\dir\" [label=\"1\"];\n"));
+        assert!(dot.contains("    \"C:\\\\dir\" -> \"two\\nlines\" [label=\"2\"];\n"));
+    }
 }
@@ -1,4 +1,6 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::LinkedList;
+use std::hash::Hasher;
 
 /// The growth factor of the hash table when resizing.
 const GROWTH_FACTOR: usize = 2;
@@ -135,7 +137,7 @@ impl<K: Hashable + std::cmp::PartialEq, V> HashTable<K, V> {
     /// assert_eq!(result, Some(&10));
     /// ```
     pub fn insert(&mut self, key: K, value: V) {
-        if self.count >= self.elements.len() * LOAD_FACTOR_BOUND as usize {
+        if self.count >= (self.elements.len() as f64 * LOAD_FACTOR_BOUND) as usize {
             self.resize();
         }
         let index = key.hash() % self.elements.len();
@@ -190,6 +192,74 @@ impl<K: Hashable + std::cmp::PartialEq, V> HashTable<K, V> {
             .map(|(_, v)| v)
     }
 
+    /// Search for a key in the hash table, allowing its value to be modified.
+    ///
+    /// # Arguments:
+    ///
+    /// * `key` - The key to search for.
+    ///
+    /// # Returns:
+    ///
+    /// An Option containing a mutable reference to the value if the key is found, or None if the
+    /// key is not found.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::HashTable;
+    ///
+    /// let mut hash_table = HashTable::new();
+    ///
+    /// hash_table.insert(1usize, 10);
+    /// if let Some(value) = hash_table.search_mut(1) {
+    ///     *value += 5;
+    /// }
+    ///
+    /// assert_eq!(hash_table.search(1), Some(&15));
+    /// ```
+    pub fn search_mut(&mut self, key: K) -> Option<&mut V> {
+        let index = key.hash() % self.elements.len();
+        self.elements[index]
+            .iter_mut()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Remove a key from the hash table.
+    ///
+    /// # Arguments:
+    ///
+    /// * `key` - The key to remove.
+    ///
+    /// # Returns:
+    ///
+    /// An Option containing the value the key was mapped to, or None if the key is not found.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::HashTable;
+    ///
+    /// let mut hash_table = HashTable::new();
+    ///
+    /// hash_table.insert(1usize, 10);
+    ///
+    /// assert_eq!(hash_table.remove(1), Some(10));
+    /// assert_eq!(hash_table.remove(1), None);
+    /// assert!(hash_table.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let index = key.hash() % self.elements.len();
+        let list = &mut self.elements[index];
+        let position = list.iter().position(|(k, _)| *k == key)?;
+
+        let mut tail = list.split_off(position);
+        let (_, value) = tail.pop_front().unwrap();
+        list.append(&mut tail);
+        self.count -= 1;
+        Some(value)
+    }
+
     fn resize(&mut self) {
         let new_size = self.elements.len() * GROWTH_FACTOR;
         let mut new_elements = Vec::with_capacity(new_size);
@@ -209,6 +279,250 @@ impl<K: Hashable + std::cmp::PartialEq, V> HashTable<K, V> {
     }
 }
 
+/// The initial number of slots of a `RobinHoodHashTable`.
+const ROBIN_HOOD_INITIAL_CAPACITY: usize = 8;
+
+/// A slot of a `RobinHoodHashTable`.
+#[derive(Debug)]
+enum Slot<K, V> {
+    Empty,
+    Full {
+        hash: u64,
+        key: K,
+        value: V,
+    },
+    /// A removed entry. Its hash is kept, so that insertions still know how far from
+    /// home that entry was.
+    Tombstone {
+        hash: u64,
+    },
+}
+
+/// A hash table implementation with open addressing and Robin Hood probing.
+///
+/// Every entry is stored directly in the slot array, at or after the slot its hash
+/// points to. The number of slots it had to move forward is its probe distance. While
+/// inserting, an entry takes the place of any entry it meets that is closer to its own
+/// slot, which then moves forward instead. This keeps the probe distances even, so that
+/// a search can stop as soon as it meets an entry closer to home than the key would be.
+///
+/// Removing an entry leaves a tombstone, which searches skip and insertions reuse. The
+/// table is rebuilt without tombstones, and doubled if needed, once the entries and
+/// tombstones fill more than the load factor bound of the slots.
+///
+/// Unlike `HashTable`, keys use the standard library's `Hash` trait.
+///
+/// # Examples:
+///
+/// ```rust
+/// use rust_algorithms::data_structures::RobinHoodHashTable;
+///
+/// let mut table = RobinHoodHashTable::new();
+///
+/// assert_eq!(table.insert("one", 1), None);
+/// assert_eq!(table.insert("one", 10), Some(1));
+/// assert_eq!(table.get(&"one"), Some(&10));
+/// assert_eq!(table.remove(&"one"), Some(10));
+/// assert!(table.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct RobinHoodHashTable<K, V> {
+    slots: Vec<Slot<K, V>>,
+    count: usize,
+    tombstones: usize,
+}
+
+impl<K: std::hash::Hash + Eq, V> Default for RobinHoodHashTable<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: std::hash::Hash + Eq, V> RobinHoodHashTable<K, V> {
+    /// Create an empty RobinHoodHashTable.
+    pub fn new() -> Self {
+        RobinHoodHashTable {
+            slots: Self::empty_slots(ROBIN_HOOD_INITIAL_CAPACITY),
+            count: 0,
+            tombstones: 0,
+        }
+    }
+
+    /// Returns the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the number of slots of the table.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Insert a key-value pair into the table.
+    ///
+    /// # Returns:
+    ///
+    /// The value the key was mapped to before, if any. Unlike `HashTable::insert`, an
+    /// existing value is overwritten.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = hash_key(&key);
+        if let Some(index) = self.find(hash, &key) {
+            if let Slot::Full { value: old, .. } = &mut self.slots[index] {
+                return Some(std::mem::replace(old, value));
+            }
+        }
+
+        if (self.count + self.tombstones + 1) as f64 > self.slots.len() as f64 * LOAD_FACTOR_BOUND {
+            self.resize();
+        }
+        self.place(hash, key, value);
+        self.count += 1;
+        None
+    }
+
+    /// Get a reference to the value a key is mapped to.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let index = self.find(hash_key(key), key)?;
+        match &self.slots[index] {
+            Slot::Full { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable reference to the value a key is mapped to.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.find(hash_key(key), key)?;
+        match &mut self.slots[index] {
+            Slot::Full { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Remove a key from the table, returning the value it was mapped to.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let hash = hash_key(key);
+        let index = self.find(hash, key)?;
+        let removed = std::mem::replace(&mut self.slots[index], Slot::Tombstone { hash });
+
+        match removed {
+            Slot::Full { value, .. } => {
+                self.count -= 1;
+                self.tombstones += 1;
+                Some(value)
+            }
+            _ => unreachable!("find only returns full slots"),
+        }
+    }
+
+    /// The slot `hash` points to.
+    fn home(&self, hash: u64) -> usize {
+        (hash % self.slots.len() as u64) as usize
+    }
+
+    /// The number of slots between the one `hash` points to and `index`.
+    fn probe_distance(&self, hash: u64, index: usize) -> usize {
+        (index + self.slots.len() - self.home(hash)) % self.slots.len()
+    }
+
+    /// Returns the index of the slot holding `key`, if any.
+    fn find(&self, hash: u64, key: &K) -> Option<usize> {
+        let mut index = self.home(hash);
+        for distance in 0..self.slots.len() {
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Tombstone { .. } => {}
+                Slot::Full {
+                    hash: other_hash,
+                    key: other,
+                    ..
+                } => {
+                    if *other_hash == hash && other == key {
+                        return Some(index);
+                    }
+                    // the key would have displaced this entry
+                    if self.probe_distance(*other_hash, index) < distance {
+                        return None;
+                    }
+                }
+            }
+            index = (index + 1) % self.slots.len();
+        }
+        None
+    }
+
+    /// Stores an entry whose key is not in the table yet, displacing the entries closer
+    /// to their home slot than the one being placed.
+    ///
+    /// A tombstone is taken under the same rule as an entry: only if the removed entry was
+    /// not further from home than the one being placed. Every key probing past that slot
+    /// was closer to home there than the removed entry, so it stays reachable.
+    fn place(&mut self, hash: u64, key: K, value: V) {
+        let mut entry = Slot::Full { hash, key, value };
+        let mut index = self.home(hash);
+        let mut distance = 0;
+
+        loop {
+            match &self.slots[index] {
+                Slot::Empty => {
+                    self.slots[index] = entry;
+                    return;
+                }
+                Slot::Tombstone { hash: other_hash } => {
+                    if self.probe_distance(*other_hash, index) <= distance {
+                        self.slots[index] = entry;
+                        self.tombstones -= 1;
+                        return;
+                    }
+                }
+                Slot::Full {
+                    hash: other_hash, ..
+                } => {
+                    let other_distance = self.probe_distance(*other_hash, index);
+                    if other_distance < distance {
+                        entry = std::mem::replace(&mut self.slots[index], entry);
+                        distance = other_distance;
+                    }
+                }
+            }
+            index = (index + 1) % self.slots.len();
+            distance += 1;
+        }
+    }
+
+    /// Rebuilds the table without tombstones. The number of slots is doubled only if the
+    /// entries alone fill half of them; otherwise the tombstones were taking the room.
+    fn resize(&mut self) {
+        let new_size = if self.count * 2 >= self.slots.len() {
+            self.slots.len() * GROWTH_FACTOR
+        } else {
+            self.slots.len()
+        };
+        let old_slots = std::mem::replace(&mut self.slots, Self::empty_slots(new_size));
+        self.tombstones = 0;
+
+        for slot in old_slots {
+            if let Slot::Full { hash, key, value } = slot {
+                self.place(hash, key, value);
+            }
+        }
+    }
+
+    fn empty_slots(size: usize) -> Vec<Slot<K, V>> {
+        (0..size).map(|_| Slot::Empty).collect()
+    }
+}
+
+fn hash_key<K: std::hash::Hash>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,13 +532,55 @@ mod tests {
         let mut hash_table = HashTable::new();
         let initial_capacity = hash_table.elements.capacity();
 
-        for i in 0..initial_capacity * LOAD_FACTOR_BOUND as usize + 1 {
+        for i in 0..(initial_capacity as f64 * LOAD_FACTOR_BOUND) as usize + 1 {
             hash_table.insert(i, i + 10);
         }
 
         assert!(hash_table.elements.capacity() > initial_capacity);
     }
 
+    #[test]
+    fn test_no_resize_below_load_factor() {
+        let mut hash_table = HashTable::new();
+        let initial_capacity = hash_table.elements.capacity();
+
+        for i in 0..(initial_capacity as f64 * LOAD_FACTOR_BOUND) as usize {
+            hash_table.insert(i, i);
+        }
+
+        assert_eq!(hash_table.elements.capacity(), initial_capacity);
+    }
+
+    #[test]
+    fn test_search_mut() {
+        let mut hash_table = HashTable::new();
+        hash_table.insert(1, 100);
+
+        *hash_table.search_mut(1).unwrap() += 1;
+        assert_eq!(hash_table.search(1), Some(&101));
+        assert_eq!(hash_table.search_mut(2), None);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut hash_table = HashTable::new();
+        // these keys all land in the same bucket
+        for i in 0..4 {
+            hash_table.insert(i * INITIAL_CAPACITY, i);
+        }
+
+        assert_eq!(hash_table.remove(INITIAL_CAPACITY), Some(1));
+        assert_eq!(hash_table.remove(INITIAL_CAPACITY), None);
+        assert_eq!(hash_table.search(0), Some(&0));
+        assert_eq!(hash_table.search(2 * INITIAL_CAPACITY), Some(&2));
+        assert_eq!(hash_table.search(3 * INITIAL_CAPACITY), Some(&3));
+
+        for i in [0, 2, 3].iter() {
+            assert_eq!(hash_table.remove(i * INITIAL_CAPACITY), Some(*i));
+        }
+        assert!(hash_table.is_empty());
+    }
+
     #[test]
     fn test_search_nonexistent() {
         let mut hash_table = HashTable::new();
@@ -267,4 +623,165 @@ mod tests {
 
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn robin_hood_many_keys() {
+        let mut table = RobinHoodHashTable::new();
+        let initial_capacity = table.capacity();
+
+        for i in 0..10_000u64 {
+            assert_eq!(table.insert(i * 7919, i), None);
+        }
+        assert_eq!(table.len(), 10_000);
+        assert!(table.capacity() > initial_capacity);
+
+        for i in 0..10_000u64 {
+            assert_eq!(table.get(&(i * 7919)), Some(&i));
+        }
+        assert_eq!(table.get(&1), None);
+    }
+
+    #[test]
+    fn robin_hood_remove_subset() {
+        let mut table = RobinHoodHashTable::new();
+        for i in 0..2000 {
+            table.insert(format!("key{}", i), i);
+        }
+
+        for i in (0..2000).filter(|i| i % 3 == 0) {
+            assert_eq!(table.remove(&format!("key{}", i)), Some(i));
+        }
+        assert_eq!(table.remove(&String::from("key0")), None);
+        assert_eq!(table.len(), 1333);
+
+        for i in 0..2000 {
+            let expected = if i % 3 == 0 { None } else { Some(&i) };
+            assert_eq!(table.get(&format!("key{}", i)), expected);
+        }
+    }
+
+    #[test]
+    fn robin_hood_overwrite_and_get_mut() {
+        let mut table = RobinHoodHashTable::new();
+        assert_eq!(table.insert('a', 1), None);
+        assert_eq!(table.insert('a', 2), Some(1));
+        assert_eq!(table.len(), 1);
+
+        *table.get_mut(&'a').unwrap() += 40;
+        assert_eq!(table.get(&'a'), Some(&42));
+        assert_eq!(table.get_mut(&'b'), None);
+    }
+
+    #[test]
+    fn robin_hood_tombstones_are_reused() {
+        let mut table = RobinHoodHashTable::new();
+        // keep the table small while churning through many keys
+        for round in 0..1000 {
+            table.insert(round, round);
+            if round >= 3 {
+                assert_eq!(table.remove(&(round - 3)), Some(round - 3));
+            }
+        }
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.capacity(), ROBIN_HOOD_INITIAL_CAPACITY);
+        for key in 997..1000 {
+            assert_eq!(table.get(&key), Some(&key));
+        }
+    }
+
+    #[test]
+    fn robin_hood_insert_after_remove_keeps_run_reachable() {
+        let mut table = RobinHoodHashTable::new();
+        let slots = table.capacity() as u64;
+        let home = |key: &u64| hash_key(key) % slots;
+        let mut at_home_0 = (0u64..).filter(|key| home(key) == 0);
+        let (a, b, y) = (
+            at_home_0.next().unwrap(),
+            at_home_0.next().unwrap(),
+            at_home_0.next().unwrap(),
+        );
+        let z = (0u64..).find(|key| home(key) == 1).unwrap();
+
+        table.insert(a, 'a');
+        table.insert(b, 'b');
+        table.insert(y, 'y');
+        assert_eq!(table.remove(&b), Some('b'));
+        table.insert(z, 'z');
+        assert_eq!(table.capacity() as u64, slots);
+
+        assert_eq!(table.get(&y), Some(&'y'));
+        assert_eq!(table.insert(y, 'Y'), Some('y'));
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.get(&a), Some(&'a'));
+        assert_eq!(table.get(&z), Some(&'z'));
+        assert_eq!(table.get(&b), None);
+    }
+
+    #[test]
+    fn robin_hood_insert_takes_tombstone_of_same_home() {
+        let mut table = RobinHoodHashTable::new();
+        let slots = table.capacity() as u64;
+        let mut at_home_0 = (0u64..).filter(|key| hash_key(key) % slots == 0);
+        let (a, b, c, w) = (
+            at_home_0.next().unwrap(),
+            at_home_0.next().unwrap(),
+            at_home_0.next().unwrap(),
+            at_home_0.next().unwrap(),
+        );
+
+        table.insert(a, 'a');
+        table.insert(b, 'b');
+        table.insert(c, 'c');
+        let slot_of_b = table.find(hash_key(&b), &b);
+        assert_eq!(table.remove(&b), Some('b'));
+        assert_eq!(table.tombstones, 1);
+
+        table.insert(w, 'w');
+        assert_eq!(table.find(hash_key(&w), &w), slot_of_b);
+        assert_eq!(table.tombstones, 0);
+        assert_eq!(table.get(&c), Some(&'c'));
+    }
+
+    #[test]
+    fn robin_hood_matches_hash_map_under_churn() {
+        let mut rng = crate::math::PCG32::new_default(23);
+        let mut table = RobinHoodHashTable::new();
+        let mut expected = std::collections::HashMap::new();
+        for step in 0..20_000u32 {
+            let key = rng.get_u32() % 200;
+            if rng.get_u32().is_multiple_of(2) {
+                assert_eq!(table.insert(key, step), expected.insert(key, step));
+            } else {
+                assert_eq!(table.remove(&key), expected.remove(&key));
+            }
+            assert_eq!(table.len(), expected.len());
+        }
+        for key in 0..200 {
+            assert_eq!(table.get(&key), expected.get(&key));
+        }
+    }
+
+    #[test]
+    fn robin_hood_probe_distances_stay_sorted() {
+        let mut table = RobinHoodHashTable::new();
+        for i in 0..500 {
+            table.insert(i, ());
+        }
+        for i in (0..500).step_by(2) {
+            table.remove(&i);
+        }
+
+        // along every run of full slots, a slot is never more than one step further from
+        // home than the slot before it
+        let len = table.slots.len();
+        for index in 0..len {
+            let next = (index + 1) % len;
+            if let (Slot::Full { hash: a, .. }, Slot::Full { hash: b, .. }) =
+                (&table.slots[index], &table.slots[next])
+            {
+                assert!(table.probe_distance(*b, next) <= table.probe_distance(*a, index) + 1);
+            }
+        }
+    }
 }
@@ -1,9 +1,64 @@
-/// Heap implementation.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Heap implementation, ordered by a comparison function.
+///
+/// The key that compares greatest is on top, so passing a reversed comparison
+/// gives a min-heap. This is the structure behind the Min/Max Heap
+/// implementations, and it can order keys that are not `Ord` themselves, such
+/// as tasks compared by one of their fields.
+///
+/// The keys are stored in level order, so the children of the key at index `k`
+/// are at `2k + 1` and `2k + 2`. Keys are moved in and out of the heap and never
+/// copied.
+///
+/// # Examples:
+///
+/// ```rust
+/// use rust_algorithms::data_structures::Heap;
+///
+/// // the task with the lowest priority number comes first
+/// let mut tasks = Heap::new_by(|a: &(u32, &str), b: &(u32, &str)| b.0.cmp(&a.0));
+/// tasks.insert((2, "write tests"));
+/// tasks.insert((1, "fix the build"));
+/// tasks.insert((3, "update the docs"));
 ///
-/// This is an internal structure  used by the Min/Max Heap implementations.
-struct Heap<T: Ord + Copy> {
+/// assert_eq!(tasks.peek(), Some(&(1, "fix the build")));
+/// assert_eq!(tasks.del(), (1, "fix the build"));
+/// assert_eq!(tasks.del(), (2, "write tests"));
+/// assert_eq!(tasks.size(), 1);
+/// ```
+pub struct Heap<T> {
     pq: Vec<T>,
-    n: usize,
+    cmp: fn(&T, &T) -> Ordering,
+    index: Option<Index<T>>,
+}
+
+/// The position of every key in an indexed heap, used to find a key whose
+/// priority changes.
+///
+/// Updating the map needs `T: Hash`, which the rest of the heap does not ask
+/// for, so the function that does it is picked when the heap is created.
+struct Index<T> {
+    positions: HashMap<T, usize>,
+    record: fn(&mut HashMap<T, usize>, &T, Option<usize>),
+}
+
+/// Records that `key` is now at `position`, or no longer in the heap.
+fn record_position<T: Eq + Hash + Clone>(
+    positions: &mut HashMap<T, usize>,
+    key: &T,
+    position: Option<usize>,
+) {
+    match position {
+        Some(position) => {
+            positions.insert(key.clone(), position);
+        }
+        None => {
+            positions.remove(key);
+        }
+    }
 }
 
 /// MaxHeap implementation.
@@ -29,7 +84,7 @@ struct Heap<T: Ord + Copy> {
 /// assert_eq!(heap.del_max(), 1);
 /// assert_eq!(heap.is_empty(), true);
 /// ```
-pub struct MaxHeap<T: Ord + Copy> {
+pub struct MaxHeap<T: Ord> {
     heap: Heap<T>,
 }
 
@@ -56,83 +111,172 @@ pub struct MaxHeap<T: Ord + Copy> {
 /// assert_eq!(heap.del_min(), 5);
 /// assert_eq!(heap.is_empty(), true);
 /// ```
-pub struct MinHeap<T: Ord + Copy> {
+pub struct MinHeap<T: Ord> {
     heap: Heap<T>,
 }
 
-impl<T: Ord + Copy> Heap<T> {
-    fn new() -> Heap<T> {
+impl<T> Heap<T> {
+    /// Creates an empty `Heap` that keeps the greatest key according to `cmp` on top.
+    pub fn new_by(cmp: fn(&T, &T) -> Ordering) -> Heap<T> {
         Heap {
             pq: Vec::new(),
-            n: 0,
+            cmp,
+            index: None,
         }
     }
 
-    fn is_empty(&self) -> bool {
-        self.n == 0
+    /// Checks if the `Heap` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pq.is_empty()
     }
 
-    fn size(&self) -> usize {
-        self.n
+    /// Returns the number of keys in the `Heap`.
+    pub fn size(&self) -> usize {
+        self.pq.len()
     }
 
-    fn insert(&mut self, key: T, less: fn(T, T) -> bool) {
-        if self.is_empty() {
-            self.pq.insert(0, key);
-        }
-        self.n += 1;
-        self.pq.insert(self.n, key);
-        self.swim(self.n, less);
+    /// Inserts a new key into the `Heap`.
+    pub fn insert(&mut self, key: T) {
+        self.pq.push(key);
+        self.record(self.pq.len() - 1);
+        self.swim(self.pq.len() - 1);
     }
 
-    fn del(&mut self, less: fn(T, T) -> bool) -> T {
-        let item = self.peek();
-        self.exch(1, self.pq.len() - 1);
-        self.pq.remove(self.pq.len() - 1);
-        self.n -= 1;
-        self.sink(1, less);
+    /// Deletes the greatest key in the `Heap` and returns it.
+    ///
+    /// # Panics:
+    ///
+    /// If the heap is empty.
+    pub fn del(&mut self) -> T {
+        assert!(!self.is_empty(), "Heap is empty");
+        let item = self.pq.swap_remove(0);
+        if let Some(index) = &mut self.index {
+            (index.record)(&mut index.positions, &item, None);
+        }
+        if !self.is_empty() {
+            self.record(0);
+        }
+        self.sink(0);
         item
     }
 
-    fn peek(&self) -> T {
-        if self.is_empty() {
-            panic!("Heap is empty")
-        }
-        self.pq[1]
+    /// Gets the greatest key in the `Heap`, or `None` if it is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.pq.first()
     }
 
     fn exch(&mut self, i: usize, j: usize) {
         self.pq.swap(i, j);
+        self.record(i);
+        self.record(j);
+    }
+
+    /// Updates the index, if any, with the position of the key at `k`.
+    fn record(&mut self, k: usize) {
+        if let Some(index) = &mut self.index {
+            (index.record)(&mut index.positions, &self.pq[k], Some(k));
+        }
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.cmp)(&self.pq[i], &self.pq[j]) == Ordering::Less
     }
 
-    fn swim(&mut self, mut k: usize, less: fn(T, T) -> bool) {
-        while k > 1 && less(self.pq[k / 2], self.pq[k]) {
-            self.pq.swap((k / 2) as usize, k as usize);
-            k = k / 2;
+    fn swim(&mut self, mut k: usize) {
+        while k > 0 && self.less((k - 1) / 2, k) {
+            self.exch((k - 1) / 2, k);
+            k = (k - 1) / 2;
         }
     }
 
-    fn sink(&mut self, mut k: usize, less: fn(T, T) -> bool) {
-        while 2 * k <= self.n {
-            let mut j = 2 * k;
-            if j < self.n && less(self.pq[j], self.pq[j + 1]) {
+    fn sink(&mut self, mut k: usize) {
+        let n = self.pq.len();
+        while 2 * k + 1 < n {
+            let mut j = 2 * k + 1;
+            if j + 1 < n && self.less(j, j + 1) {
                 j += 1;
             }
-            if !less(self.pq[k], self.pq[j]) {
+            if !self.less(k, j) {
                 break;
             }
-            self.pq.swap(k as usize, j as usize);
+            self.exch(k, j);
             k = j
         }
     }
+}
 
-    fn iter(&mut self) -> Vec<T> {
-        self.pq[1..].to_vec()
+impl<T: Eq + Hash + Clone> Heap<T> {
+    /// Creates an empty `Heap` ordered by `cmp` that also keeps the position of
+    /// every key, so that `change_priority` can find them. The keys must be
+    /// distinct, and every move of a key within the heap updates a hash map.
+    pub fn new_indexed_by(cmp: fn(&T, &T) -> Ordering) -> Heap<T> {
+        Heap {
+            pq: Vec::new(),
+            cmp,
+            index: Some(Index {
+                positions: HashMap::new(),
+                record: record_position,
+            }),
+        }
+    }
+
+    /// Replaces the key `old` with `new`, and moves it up or down to where it
+    /// belongs now.
+    ///
+    /// The key is found through the index in O(1) expected time, and restoring
+    /// the heap order takes O(log n) swaps, each updating the index.
+    ///
+    /// # Returns:
+    ///
+    /// `true` if `old` was in the heap and got replaced, `false` otherwise.
+    ///
+    /// # Panics:
+    ///
+    /// If the heap was not created with `new_indexed_by`.
+    pub fn change_priority(&mut self, old: &T, new: T) -> bool {
+        let index = self
+            .index
+            .as_mut()
+            .expect("change_priority needs an indexed heap");
+        let k = match index.positions.remove(old) {
+            Some(k) => k,
+            None => return false,
+        };
+
+        let order = (self.cmp)(&new, &self.pq[k]);
+        self.pq[k] = new;
+        self.record(k);
+        match order {
+            Ordering::Greater => self.swim(k),
+            _ => self.sink(k),
+        }
+        true
+    }
+}
+
+impl<T: Clone> Heap<T> {
+    /// Copies `keys` and orders them into a heap in linear time, sinking every
+    /// internal node from the last one up to the root.
+    fn from_slice_by(keys: &[T], cmp: fn(&T, &T) -> Ordering) -> Heap<T> {
+        let mut heap = Heap {
+            pq: keys.to_vec(),
+            cmp,
+            index: None,
+        };
+        for k in (0..heap.pq.len() / 2).rev() {
+            heap.sink(k);
+        }
+        heap
+    }
+
+    /// Returns the keys of the `Heap`, in an arbitrary order.
+    pub fn iter(&mut self) -> Vec<T> {
+        self.pq.clone()
     }
 }
 
 /// MaxHeap implementation.
-impl<T: Ord + Copy> MaxHeap<T> {
+impl<T: Ord> MaxHeap<T> {
     /// Creates a new `MaxHeap`` instance.
     ///
     /// # Examples:
@@ -146,7 +290,9 @@ impl<T: Ord + Copy> MaxHeap<T> {
     /// assert_eq!(heap.size(), 0);
     /// ```
     pub fn new() -> MaxHeap<T> {
-        MaxHeap { heap: Heap::new() }
+        MaxHeap {
+            heap: Heap::new_by(T::cmp),
+        }
     }
 
     /// Inserts a new key into the `MaxHeap`.
@@ -171,7 +317,7 @@ impl<T: Ord + Copy> MaxHeap<T> {
     /// assert_eq!(heap.size(), 5);
     /// ```
     pub fn insert(&mut self, key: T) {
-        self.heap.insert(key, less_max);
+        self.heap.insert(key);
     }
 
     /// Checks if the `MaxHeap` is empty.
@@ -217,7 +363,8 @@ impl<T: Ord + Copy> MaxHeap<T> {
     ///
     /// # Returns:
     ///
-    /// The maximum key in the `MaxHeap`.
+    /// A reference to the maximum key in the `MaxHeap`, or `None` if the heap is
+    /// empty. Unlike `del_max`, this never panics.
     ///
     /// # Examples:
     ///
@@ -231,9 +378,17 @@ impl<T: Ord + Copy> MaxHeap<T> {
     /// heap.insert(4);
     /// heap.insert(5);
     ///
-    /// assert_eq!(heap.peek(), 5);
+    /// assert_eq!(heap.peek(), Some(&5));
+    ///
+    /// heap.del_max();
+    /// heap.del_max();
+    /// heap.del_max();
+    /// heap.del_max();
+    /// heap.del_max();
+    ///
+    /// assert_eq!(heap.peek(), None);
     /// ```
-    pub fn peek(&self) -> T {
+    pub fn peek(&self) -> Option<&T> {
         self.heap.peek()
     }
 
@@ -266,7 +421,97 @@ impl<T: Ord + Copy> MaxHeap<T> {
     /// assert_eq!(heap.del_max(), 1);
     /// ```
     pub fn del_max(&mut self) -> T {
-        self.heap.del(less_max)
+        self.heap.del()
+    }
+
+    /// Consumes the `MaxHeap` and returns its keys sorted in ascending order.
+    ///
+    /// The keys are popped from the largest to the smallest and the result is
+    /// reversed, so the order is the same as for `MinHeap::into_sorted_vec` and
+    /// `BinaryHeap::into_sorted_vec`.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MaxHeap;
+    ///
+    /// let mut heap = MaxHeap::new();
+    /// for key in [3, 1, 4, 1, 5].iter() {
+    ///     heap.insert(*key);
+    /// }
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 1, 3, 4, 5]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.size());
+        while !self.is_empty() {
+            sorted.push(self.del_max());
+        }
+        sorted.reverse();
+        sorted
+    }
+}
+
+impl<T: Ord + Hash + Clone> MaxHeap<T> {
+    /// Creates a new `MaxHeap` that keeps the position of every key, so that
+    /// `change_priority` can be used. The keys must be distinct.
+    pub fn new_indexed() -> MaxHeap<T> {
+        MaxHeap {
+            heap: Heap::new_indexed_by(T::cmp),
+        }
+    }
+
+    /// Replaces the key `old` with `new` in O(log n) time, returning `false` if
+    /// `old` is not in the heap.
+    ///
+    /// # Panics:
+    ///
+    /// If the heap was not created with `new_indexed`.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MaxHeap;
+    ///
+    /// let mut heap = MaxHeap::new_indexed();
+    /// heap.insert(10);
+    /// heap.insert(20);
+    /// heap.insert(30);
+    ///
+    /// assert!(heap.change_priority(&10, 40));
+    /// assert!(!heap.change_priority(&99, 1));
+    /// assert_eq!(heap.del_max(), 40);
+    /// ```
+    pub fn change_priority(&mut self, old: &T, new: T) -> bool {
+        self.heap.change_priority(old, new)
+    }
+}
+
+impl<T: Ord + Clone> MaxHeap<T> {
+    /// Builds a `MaxHeap` from a copy of `keys`.
+    ///
+    /// The heap is ordered in a single bottom-up pass, which takes O(n) time
+    /// instead of the O(n log n) of inserting the keys one by one.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MaxHeap;
+    ///
+    /// let mut heap = MaxHeap::from_slice(&[3, 1, 5, 2, 4]);
+    ///
+    /// assert_eq!(heap.size(), 5);
+    /// assert_eq!(heap.del_max(), 5);
+    /// assert_eq!(heap.del_max(), 4);
+    /// assert_eq!(heap.del_max(), 3);
+    /// assert_eq!(heap.del_max(), 2);
+    /// assert_eq!(heap.del_max(), 1);
+    /// assert_eq!(heap.is_empty(), true);
+    /// ```
+    pub fn from_slice(keys: &[T]) -> MaxHeap<T> {
+        MaxHeap {
+            heap: Heap::from_slice_by(keys, T::cmp),
+        }
     }
 
     /// Returns an iterator over the MaxHeap.
@@ -302,7 +547,7 @@ impl<T: Ord + Copy> MaxHeap<T> {
 }
 
 /// MinHeap implementation.
-impl<T: Ord + Copy> MinHeap<T> {
+impl<T: Ord> MinHeap<T> {
     /// Creates a new `MinHeap`` instance.
     ///
     /// # Examples:
@@ -316,7 +561,9 @@ impl<T: Ord + Copy> MinHeap<T> {
     /// assert_eq!(heap.size(), 0);
     /// ```
     pub fn new() -> MinHeap<T> {
-        MinHeap { heap: Heap::new() }
+        MinHeap {
+            heap: Heap::new_by(reverse_cmp),
+        }
     }
 
     /// Inserts a new key into the `MinHeap`.
@@ -341,7 +588,7 @@ impl<T: Ord + Copy> MinHeap<T> {
     /// assert_eq!(heap.size(), 5);
     /// ```
     pub fn insert(&mut self, key: T) {
-        self.heap.insert(key, less_min);
+        self.heap.insert(key);
     }
 
     /// Checks if the `MinHeap` is empty.
@@ -386,7 +633,8 @@ impl<T: Ord + Copy> MinHeap<T> {
     ///
     /// # Returns:
     ///
-    /// The minimum key in the `MinHeap`.
+    /// A reference to the minimum key in the `MinHeap`, or `None` if the heap is
+    /// empty. Unlike `del_min`, this never panics.
     ///
     /// # Examples:
     ///
@@ -400,10 +648,17 @@ impl<T: Ord + Copy> MinHeap<T> {
     /// heap.insert(4);
     /// heap.insert(5);
     ///
-    /// assert_eq!(heap.peek(), 1);
+    /// assert_eq!(heap.peek(), Some(&1));
+    ///
+    /// heap.del_min();
+    /// heap.del_min();
+    /// heap.del_min();
+    /// heap.del_min();
+    /// heap.del_min();
     ///
+    /// assert_eq!(heap.peek(), None);
     /// ```
-    pub fn peek(&self) -> T {
+    pub fn peek(&self) -> Option<&T> {
         self.heap.peek()
     }
 
@@ -436,7 +691,93 @@ impl<T: Ord + Copy> MinHeap<T> {
     /// assert_eq!(heap.del_min(), 5);
     /// ```
     pub fn del_min(&mut self) -> T {
-        self.heap.del(less_min)
+        self.heap.del()
+    }
+
+    /// Consumes the `MinHeap` and returns its keys sorted in ascending order,
+    /// the order in which they are popped.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MinHeap;
+    ///
+    /// let mut heap = MinHeap::new();
+    /// for key in [3, 1, 4, 1, 5].iter() {
+    ///     heap.insert(*key);
+    /// }
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 1, 3, 4, 5]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.size());
+        while !self.is_empty() {
+            sorted.push(self.del_min());
+        }
+        sorted
+    }
+}
+
+impl<T: Ord + Hash + Clone> MinHeap<T> {
+    /// Creates a new `MinHeap` that keeps the position of every key, so that
+    /// `change_priority` can be used. The keys must be distinct.
+    pub fn new_indexed() -> MinHeap<T> {
+        MinHeap {
+            heap: Heap::new_indexed_by(reverse_cmp),
+        }
+    }
+
+    /// Replaces the key `old` with `new` in O(log n) time, returning `false` if
+    /// `old` is not in the heap.
+    ///
+    /// # Panics:
+    ///
+    /// If the heap was not created with `new_indexed`.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MinHeap;
+    ///
+    /// let mut heap = MinHeap::new_indexed();
+    /// heap.insert(10);
+    /// heap.insert(20);
+    /// heap.insert(30);
+    ///
+    /// assert!(heap.change_priority(&30, 5));
+    /// assert!(!heap.change_priority(&99, 1));
+    /// assert_eq!(heap.del_min(), 5);
+    /// ```
+    pub fn change_priority(&mut self, old: &T, new: T) -> bool {
+        self.heap.change_priority(old, new)
+    }
+}
+
+impl<T: Ord + Clone> MinHeap<T> {
+    /// Builds a `MinHeap` from a copy of `keys`.
+    ///
+    /// The heap is ordered in a single bottom-up pass, which takes O(n) time
+    /// instead of the O(n log n) of inserting the keys one by one.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MinHeap;
+    ///
+    /// let mut heap = MinHeap::from_slice(&[3, 1, 5, 2, 4]);
+    ///
+    /// assert_eq!(heap.size(), 5);
+    /// assert_eq!(heap.del_min(), 1);
+    /// assert_eq!(heap.del_min(), 2);
+    /// assert_eq!(heap.del_min(), 3);
+    /// assert_eq!(heap.del_min(), 4);
+    /// assert_eq!(heap.del_min(), 5);
+    /// assert_eq!(heap.is_empty(), true);
+    /// ```
+    pub fn from_slice(keys: &[T]) -> MinHeap<T> {
+        MinHeap {
+            heap: Heap::from_slice_by(keys, reverse_cmp),
+        }
     }
 
     /// Returns an iterator over the MinHeap.
@@ -471,10 +812,265 @@ impl<T: Ord + Copy> MinHeap<T> {
     }
 }
 
-fn less_max<T: Ord + Copy>(i: T, j: T) -> bool {
-    i.lt(&j)
+fn reverse_cmp<T: Ord>(i: &T, j: &T) -> Ordering {
+    j.cmp(i)
 }
 
-fn less_min<T: Ord + Copy>(i: T, j: T) -> bool {
-    !i.lt(&j)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peek_empty() {
+        let max_heap = MaxHeap::<i32>::new();
+        assert_eq!(max_heap.peek(), None);
+
+        let min_heap = MinHeap::<i32>::new();
+        assert_eq!(min_heap.peek(), None);
+    }
+
+    #[test]
+    fn peek_after_draining() {
+        let mut heap = MaxHeap::new();
+        heap.insert(3);
+        heap.insert(7);
+        assert_eq!(heap.peek(), Some(&7));
+
+        heap.del_max();
+        heap.del_max();
+        assert_eq!(heap.peek(), None);
+    }
+
+    #[test]
+    fn from_slice_drains_in_order() {
+        let mut rng = crate::math::PCG32::new_default(87);
+        let keys: Vec<i64> = (0..1000)
+            .map(|_| (rng.get_u32() % 500) as i64 - 250)
+            .collect();
+
+        let mut max_heap = MaxHeap::from_slice(&keys);
+        let descending: Vec<i64> = (0..keys.len()).map(|_| max_heap.del_max()).collect();
+        assert!(descending.windows(2).all(|w| w[0] >= w[1]));
+        assert!(max_heap.is_empty());
+
+        let mut min_heap = MinHeap::from_slice(&keys);
+        let ascending: Vec<i64> = (0..keys.len()).map(|_| min_heap.del_min()).collect();
+        assert!(ascending.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut sorted = keys;
+        sorted.sort_unstable();
+        assert_eq!(ascending, sorted);
+    }
+
+    #[test]
+    fn from_slice_matches_inserting() {
+        let mut rng = crate::math::PCG32::new_default(10_000);
+        let keys: Vec<u32> = (0..10_000).map(|_| rng.get_u32()).collect();
+
+        let mut built = MaxHeap::from_slice(&keys);
+        let mut inserted = MaxHeap::new();
+        for &key in &keys {
+            inserted.insert(key);
+        }
+
+        assert_eq!(built.size(), inserted.size());
+        assert_eq!(built.peek(), inserted.peek());
+        while !inserted.is_empty() {
+            assert_eq!(built.del_max(), inserted.del_max());
+        }
+        assert!(built.is_empty());
+    }
+
+    #[test]
+    fn from_slice_small() {
+        let empty = MinHeap::<i32>::from_slice(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.peek(), None);
+
+        let mut single = MaxHeap::from_slice(&[42]);
+        assert_eq!(single.peek(), Some(&42));
+        assert_eq!(single.del_max(), 42);
+        assert!(single.is_empty());
+
+        let mut heap = MaxHeap::from_slice(&[1, 2]);
+        heap.insert(3);
+        assert_eq!(heap.del_max(), 3);
+        assert_eq!(heap.del_max(), 2);
+        assert_eq!(heap.del_max(), 1);
+    }
+
+    #[test]
+    fn owned_values() {
+        let words = ["pear", "apple", "fig", "banana", "cherry", "date"];
+
+        let mut max_heap = MaxHeap::new();
+        for word in words.iter() {
+            max_heap.insert(word.to_string());
+        }
+        assert_eq!(max_heap.peek().map(String::as_str), Some("pear"));
+        let popped: Vec<String> = (0..words.len()).map(|_| max_heap.del_max()).collect();
+        assert_eq!(
+            popped,
+            vec!["pear", "fig", "date", "cherry", "banana", "apple"]
+        );
+        assert!(max_heap.is_empty());
+
+        let mut min_heap = MinHeap::new();
+        for word in words.iter() {
+            min_heap.insert(vec![word.to_string()]);
+        }
+        assert_eq!(min_heap.del_min(), vec!["apple".to_string()]);
+        assert_eq!(min_heap.del_min(), vec!["banana".to_string()]);
+        assert_eq!(min_heap.size(), 4);
+    }
+
+    #[test]
+    fn refill_after_draining() {
+        let mut heap = MinHeap::new();
+        heap.insert(String::from("b"));
+        heap.del_min();
+
+        heap.insert(String::from("c"));
+        heap.insert(String::from("a"));
+        assert_eq!(heap.size(), 2);
+        assert_eq!(heap.iter().len(), 2);
+        assert_eq!(heap.del_min(), "a");
+        assert_eq!(heap.del_min(), "c");
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Heap is empty")]
+    fn del_from_empty() {
+        MaxHeap::<String>::new().del_max();
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Task {
+        priority: u32,
+        name: &'static str,
+    }
+
+    #[test]
+    fn custom_comparator() {
+        let mut heap = Heap::new_by(|a: &Task, b: &Task| a.priority.cmp(&b.priority));
+        for &(priority, name) in [(3, "c"), (7, "g"), (1, "a"), (5, "e"), (4, "d")].iter() {
+            heap.insert(Task { priority, name });
+        }
+
+        assert_eq!(heap.size(), 5);
+        assert_eq!(heap.peek().map(|task| task.name), Some("g"));
+        let names: Vec<&str> = (0..5).map(|_| heap.del().name).collect();
+        assert_eq!(names, vec!["g", "e", "d", "c", "a"]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn tuples_ordered_by_priority() {
+        // lowest priority first, ignoring the task names entirely
+        let mut heap = Heap::new_by(|a: &(u8, String), b: &(u8, String)| b.0.cmp(&a.0));
+        heap.insert((2, String::from("zeta")));
+        heap.insert((9, String::from("alpha")));
+        heap.insert((0, String::from("omega")));
+        heap.insert((5, String::from("beta")));
+
+        let priorities: Vec<u8> = (0..4).map(|_| heap.del().0).collect();
+        assert_eq!(priorities, vec![0, 2, 5, 9]);
+    }
+
+    #[test]
+    fn lowering_a_key_reorders_del_min() {
+        let mut heap = MinHeap::new_indexed();
+        for key in [50, 10, 40, 20, 30, 60].iter() {
+            heap.insert(*key);
+        }
+
+        assert!(heap.change_priority(&60, 5));
+        assert!(heap.change_priority(&40, 15));
+        assert_eq!(heap.peek(), Some(&5));
+
+        let order: Vec<i32> = (0..6).map(|_| heap.del_min()).collect();
+        assert_eq!(order, vec![5, 10, 15, 20, 30, 50]);
+    }
+
+    #[test]
+    fn raising_and_missing_keys() {
+        let mut heap = MinHeap::new_indexed();
+        for key in 1..=5 {
+            heap.insert(key);
+        }
+
+        assert!(heap.change_priority(&1, 9));
+        assert!(!heap.change_priority(&1, 0));
+        assert_eq!(heap.del_min(), 2);
+        // a key that was deleted cannot be changed anymore
+        assert!(!heap.change_priority(&2, 0));
+
+        let order: Vec<i32> = (0..4).map(|_| heap.del_min()).collect();
+        assert_eq!(order, vec![3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn change_priority_matches_sorting() {
+        let mut rng = crate::math::PCG32::new_default(29);
+        let mut heap = Heap::new_indexed_by(|a: &u32, b: &u32| b.cmp(a));
+        let mut keys: Vec<u32> = Vec::new();
+        while keys.len() < 200 {
+            let key = rng.get_u32() % 100_000;
+            if !keys.contains(&key) {
+                keys.push(key);
+                heap.insert(key);
+            }
+        }
+
+        for _ in 0..300 {
+            let i = rng.get_u32() as usize % keys.len();
+            let new = rng.get_u32() % 100_000;
+            if keys.contains(&new) {
+                continue;
+            }
+            assert!(heap.change_priority(&keys[i], new));
+            keys[i] = new;
+            if rng.get_u32() & 3 == 0 {
+                let smallest = heap.del();
+                let position = keys.iter().position(|&k| k == smallest).unwrap();
+                assert_eq!(Some(&smallest), keys.iter().min());
+                keys.swap_remove(position);
+            }
+        }
+
+        keys.sort_unstable();
+        let drained: Vec<u32> = (0..keys.len()).map(|_| heap.del()).collect();
+        assert_eq!(drained, keys);
+    }
+
+    #[test]
+    #[should_panic(expected = "indexed heap")]
+    fn change_priority_needs_index() {
+        let mut heap = MinHeap::new();
+        heap.insert(1);
+        heap.change_priority(&1, 0);
+    }
+
+    #[test]
+    fn into_sorted_vec_is_ascending() {
+        let mut rng = crate::math::PCG32::new_default(41);
+        let keys: Vec<i32> = (0..500)
+            .map(|_| (rng.get_u32() % 100) as i32 - 50)
+            .collect();
+        let mut expected = keys.clone();
+        expected.sort_unstable();
+
+        let mut max_heap = MaxHeap::new();
+        let mut min_heap = MinHeap::new();
+        for &key in &keys {
+            max_heap.insert(key);
+            min_heap.insert(key);
+        }
+        assert_eq!(max_heap.into_sorted_vec(), expected);
+        assert_eq!(min_heap.into_sorted_vec(), expected);
+
+        assert_eq!(MaxHeap::from_slice(&keys).into_sorted_vec(), expected);
+        assert!(MinHeap::<String>::new().into_sorted_vec().is_empty());
+    }
 }
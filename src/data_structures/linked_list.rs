@@ -187,6 +187,59 @@ impl<T> LinkedList<T> {
 
         Some(pointer)
     }
+
+    /// Reverses the list in place in O(n) time, by turning every `next` link around
+    /// and swapping the head and the tail. No node is allocated or freed.
+    pub fn reverse(&mut self) {
+        let mut prev: Option<Link<T>> = None;
+        let mut link = self.head.take();
+        self.tail = link.clone();
+        while let Some(node) = link {
+            link = node.borrow_mut().next.take();
+            node.borrow_mut().next = prev;
+            prev = Some(node);
+        }
+        self.head = prev;
+    }
+
+    /// Checks whether following the `next` links from the head ever revisits a node,
+    /// using Floyd's tortoise-and-hare algorithm in O(n) time and O(1) extra space.
+    pub fn has_cycle(&self) -> bool {
+        fn next<T>(link: &Option<Link<T>>) -> Option<Link<T>> {
+            link.as_ref().and_then(|node| node.borrow().next.clone())
+        }
+
+        let mut slow = self.head.clone();
+        let mut fast = self.head.clone();
+        loop {
+            fast = next(&next(&fast));
+            slow = next(&slow);
+            match (&slow, &fast) {
+                (Some(slow), Some(fast)) if Rc::ptr_eq(slow, fast) => return true,
+                (_, None) => return false,
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Frees the nodes one by one. The default drop would free every node from
+    /// within the drop of the node before it, and overflow the stack on a long list.
+    ///
+    /// Only the nodes owned by this list alone are unlinked. The walk stops at the
+    /// first node also held elsewhere, which keeps that node and the ones after it
+    /// intact for their other owner.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut link = self.head.take();
+        while let Some(node) = link {
+            link = match Rc::try_unwrap(node) {
+                Ok(node) => node.into_inner().next,
+                Err(_) => None,
+            };
+        }
+    }
 }
 
 #[cfg(test)]
@@ -282,4 +335,108 @@ mod test {
             assert_eq!(node.borrow().val, arr[i])
         }
     }
+
+    fn values<T: Clone>(list: LinkedList<T>) -> Vec<T> {
+        list.map(|node| node.borrow().val.clone()).collect()
+    }
+
+    #[test]
+    fn reverse_test() {
+        let mut list = create_list(&[1, 2, 3]);
+        list.reverse();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek_front().unwrap().borrow().val, 3);
+        assert_eq!(list.peek_back().unwrap().borrow().val, 1);
+        // the new tail must be the real last node, so pushing extends the reversed list
+        list.push_back(0);
+        list.push_front(4);
+        assert_eq!(values(list), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn reverse_twice_test() {
+        let arr: Vec<i32> = (0..100).collect();
+        let mut list = create_list(&arr);
+        list.reverse();
+        list.reverse();
+        assert_eq!(list.len(), arr.len());
+        assert_eq!(values(list), arr);
+    }
+
+    #[test]
+    fn reverse_short_lists_test() {
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.reverse();
+        assert_eq!(LinkedList::new(), empty);
+        assert_eq!(empty.peek_back(), None);
+
+        let mut single = create_list(&[7]);
+        single.reverse();
+        assert_eq!(single.len(), 1);
+        single.push_back(8);
+        assert_eq!(values(single), vec![7, 8]);
+    }
+
+    /// Wires the tail back to the node at `index`, creating a cycle.
+    fn link_tail_to<T>(list: &LinkedList<T>, index: usize) {
+        let mut target = list.head.clone().unwrap();
+        for _ in 0..index {
+            let next = target.borrow().next.clone().unwrap();
+            target = next;
+        }
+        list.tail.as_ref().unwrap().borrow_mut().next = Some(target);
+    }
+
+    /// Breaks the cycle created by `link_tail_to`, so the nodes can be freed.
+    fn unlink_tail<T>(list: &LinkedList<T>) {
+        list.tail.as_ref().unwrap().borrow_mut().next = None;
+    }
+
+    #[test]
+    fn has_cycle_test() {
+        assert!(!LinkedList::<i32>::new().has_cycle());
+        assert!(!create_list(&[0]).has_cycle());
+        assert!(!create_list(&[0, 1]).has_cycle());
+        assert!(!create_list(&[0, 1, 2, 3, 4]).has_cycle());
+    }
+
+    #[test]
+    fn has_cycle_cyclic_test() {
+        let single = create_list(&[0]);
+        link_tail_to(&single, 0);
+        assert!(single.has_cycle());
+        unlink_tail(&single);
+
+        let list = create_list(&[0, 1, 2, 3, 4]);
+        link_tail_to(&list, 0);
+        assert!(list.has_cycle());
+        unlink_tail(&list);
+        assert!(!list.has_cycle());
+
+        link_tail_to(&list, 3);
+        assert!(list.has_cycle());
+        unlink_tail(&list);
+    }
+
+    #[test]
+    fn drop_long_list() {
+        let mut list = LinkedList::new();
+        for i in 0..1_000_000 {
+            list.push_back(i);
+        }
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+    }
+
+    #[test]
+    fn drop_keeps_shared_nodes() {
+        let list = create_list(&[0, 1, 2]);
+        let second = list.head.as_ref().unwrap().borrow().next.clone().unwrap();
+        drop(list);
+
+        assert_eq!(second.borrow().val, 1);
+        let third = second.borrow().next.clone().unwrap();
+        assert_eq!(third.borrow().val, 2);
+        assert!(third.borrow().next.is_none());
+    }
 }
@@ -2,12 +2,14 @@
 mod avl_tree;
 mod b_tree;
 mod binary_search_tree;
+mod bit_set;
 mod bloom_filter;
 mod fenwick_tree;
 mod graph;
 mod hashtable;
 mod heap;
 mod linked_list;
+mod multiset;
 mod queue;
 mod rb_tree;
 mod rope;
@@ -17,12 +19,15 @@ mod stack_using_singly_linked_list;
 mod trie;
 mod union_find;
 
-pub use bloom_filter::BloomFilter;
-pub use hashtable::HashTable;
+pub use bit_set::BitSet;
+pub use bloom_filter::{BloomFilter, ScalableBloomFilter};
+pub use hashtable::{HashTable, Hashable, RobinHoodHashTable};
+pub use heap::Heap;
 pub use heap::MaxHeap;
 pub use heap::MinHeap;
 pub use linked_list::LinkedList;
-pub use queue::Queue;
+pub use multiset::MultiSet;
+pub use queue::{BoundedQueue, Queue};
 pub use rope::Rope;
 pub use stack::Stack;
 
@@ -30,10 +35,12 @@ pub use stack::Stack;
 pub use avl_tree::AVLTree;
 pub use b_tree::BTree;
 pub use binary_search_tree::BinarySearchTree;
-pub use fenwick_tree::FenwickTree;
+pub use fenwick_tree::{FenwickTree, FenwickTree2D, RangeUpdateFenwickTree};
 pub use graph::{DirectedGraph, Graph, UndirectedGraph};
 pub use rb_tree::RBTree;
-pub use segment_tree::SegmentTree;
+pub use segment_tree::{
+    LazySegmentTree, MaxSubarraySegmentTree, ModularProductSegmentTree, Ops, SegmentTree,
+};
 pub use stack_using_singly_linked_list::Stack as SllStack;
-pub use trie::Trie;
+pub use trie::{GenericTrie, Trie, TrieMap};
 pub use union_find::UnionFind;
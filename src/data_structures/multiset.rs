@@ -0,0 +1,261 @@
+use super::hashtable::{HashTable, Hashable};
+
+/// A multiset, or bag: a set where every value can appear more than once.
+///
+/// Every distinct value is stored once in a `HashTable`, together with the number of times it
+/// appears. The keys of the `HashTable` are taken by value, so looking a value up clones it.
+///
+/// # Examples:
+///
+/// ```rust
+/// use rust_algorithms::data_structures::MultiSet;
+///
+/// let mut bag = MultiSet::new();
+///
+/// bag.insert(3usize);
+/// bag.insert(3);
+/// bag.insert(7);
+///
+/// assert_eq!(bag.count(&3), 2);
+/// assert_eq!(bag.count(&7), 1);
+/// assert_eq!(bag.len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct MultiSet<T> {
+    counts: HashTable<T, usize>,
+    length: usize,
+}
+
+impl<T: Hashable + PartialEq + Clone> Default for MultiSet<T> {
+    /// Create an empty MultiSet.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hashable + PartialEq + Clone> MultiSet<T> {
+    /// Create an empty MultiSet.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let bag = MultiSet::<usize>::new();
+    ///
+    /// assert!(bag.is_empty());
+    /// ```
+    pub fn new() -> MultiSet<T> {
+        MultiSet {
+            counts: HashTable::new(),
+            length: 0,
+        }
+    }
+
+    /// Add one occurrence of a value to the multiset.
+    ///
+    /// # Arguments:
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let mut bag = MultiSet::new();
+    ///
+    /// bag.insert(1usize);
+    /// bag.insert(1);
+    ///
+    /// assert_eq!(bag.count(&1), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        match self.counts.search_mut(value.clone()) {
+            Some(count) => *count += 1,
+            None => self.counts.insert(value, 1),
+        }
+        self.length += 1;
+    }
+
+    /// Remove one occurrence of a value from the multiset. The value is dropped from the
+    /// underlying `HashTable` once its count reaches zero.
+    ///
+    /// # Arguments:
+    ///
+    /// * `value` - The value to remove.
+    ///
+    /// # Returns:
+    ///
+    /// `true` if the value was in the multiset, `false` otherwise.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let mut bag = MultiSet::new();
+    ///
+    /// bag.insert(1usize);
+    ///
+    /// assert!(bag.remove(&1));
+    /// assert!(!bag.remove(&1));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        let count = match self.counts.search_mut(value.clone()) {
+            Some(count) => count,
+            None => return false,
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(value.clone());
+        }
+        self.length -= 1;
+        true
+    }
+
+    /// Returns how many times a value appears in the multiset.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let mut bag = MultiSet::new();
+    ///
+    /// bag.insert(1usize);
+    ///
+    /// assert_eq!(bag.count(&1), 1);
+    /// assert_eq!(bag.count(&2), 0);
+    /// ```
+    pub fn count(&self, value: &T) -> usize {
+        self.counts.search(value.clone()).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of values in the multiset, counting every occurrence.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let mut bag = MultiSet::new();
+    ///
+    /// bag.insert(1usize);
+    /// bag.insert(1);
+    /// bag.insert(2);
+    ///
+    /// assert_eq!(bag.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the multiset contains no values.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::data_structures::MultiSet;
+    ///
+    /// let mut bag = MultiSet::new();
+    ///
+    /// assert!(bag.is_empty());
+    /// bag.insert(1usize);
+    /// assert!(!bag.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_same_value() {
+        let mut bag = MultiSet::new();
+        for _ in 0..5 {
+            bag.insert(42usize);
+        }
+
+        assert_eq!(bag.count(&42), 5);
+        assert_eq!(bag.count(&41), 0);
+        assert_eq!(bag.len(), 5);
+    }
+
+    #[test]
+    fn test_counts() {
+        let mut bag = MultiSet::new();
+        for value in [3usize, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5].iter() {
+            bag.insert(*value);
+        }
+
+        assert_eq!(bag.count(&1), 2);
+        assert_eq!(bag.count(&3), 2);
+        assert_eq!(bag.count(&5), 3);
+        assert_eq!(bag.count(&9), 1);
+        assert_eq!(bag.count(&7), 0);
+        assert_eq!(bag.len(), 11);
+    }
+
+    #[test]
+    fn test_remove_down_to_zero() {
+        let mut bag = MultiSet::new();
+        bag.insert(7usize);
+        bag.insert(7);
+        bag.insert(7);
+        bag.insert(8);
+
+        assert!(bag.remove(&7));
+        assert_eq!(bag.count(&7), 2);
+        assert!(bag.remove(&7));
+        assert!(bag.remove(&7));
+        assert_eq!(bag.count(&7), 0);
+        assert!(!bag.remove(&7));
+        assert_eq!(bag.len(), 1);
+
+        // the value can come back after reaching zero
+        bag.insert(7);
+        assert_eq!(bag.count(&7), 1);
+
+        assert!(bag.remove(&7));
+        assert!(bag.remove(&8));
+        assert!(bag.is_empty());
+        assert!(bag.counts.is_empty());
+    }
+
+    #[test]
+    fn test_remove_missing() {
+        let mut bag: MultiSet<usize> = MultiSet::new();
+        assert!(!bag.remove(&1));
+        assert!(bag.is_empty());
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Word(&'static str);
+
+    impl Hashable for Word {
+        fn hash(&self) -> usize {
+            self.0
+                .bytes()
+                .fold(0, |h, b| h.wrapping_mul(31).wrapping_add(b as usize))
+        }
+    }
+
+    #[test]
+    fn test_custom_hashable() {
+        let mut bag = MultiSet::new();
+        for word in "the cat and the dog and the bird".split(' ') {
+            bag.insert(Word(word));
+        }
+
+        assert_eq!(bag.count(&Word("the")), 3);
+        assert_eq!(bag.count(&Word("and")), 2);
+        assert_eq!(bag.count(&Word("cat")), 1);
+        assert_eq!(bag.count(&Word("fish")), 0);
+        assert_eq!(bag.len(), 8);
+    }
+}
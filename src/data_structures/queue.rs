@@ -33,9 +33,74 @@ impl<T> Queue<T> {
     }
 }
 
+/// A queue that holds at most `capacity` items, stored in a ring buffer.
+///
+/// `enqueue` rejects items once the queue is full instead of growing, and
+/// every operation takes constant time.
+pub struct BoundedQueue<T> {
+    buf: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        BoundedQueue {
+            buf: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds `item` at the back of the queue, or returns `false` if it is full.
+    pub fn enqueue(&mut self, item: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        let tail = (self.head + self.len) % self.capacity();
+        self.buf[tail] = Some(item);
+        self.len += 1;
+        true
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.buf[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        item
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.buf[self.head].as_ref()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Queue;
+    use super::{BoundedQueue, Queue};
 
     #[test]
     fn starts_empty() {
@@ -105,4 +170,59 @@ mod tests {
         assert_eq!(q.len(), 1);
         assert_eq!(q.peek(), Some(&"D"));
     }
+
+    #[test]
+    fn bounded_rejects_when_full() {
+        let mut q = BoundedQueue::with_capacity(3);
+
+        assert!(q.enqueue(1));
+        assert!(q.enqueue(2));
+        assert!(q.enqueue(3));
+
+        assert!(q.is_full());
+        assert!(!q.enqueue(4));
+        assert_eq!(q.len(), 3);
+        assert_eq!(q.peek(), Some(&1));
+
+        assert_eq!(q.dequeue(), Some(1));
+        assert!(!q.is_full());
+        assert!(q.enqueue(4));
+        assert!(!q.enqueue(5));
+
+        assert_eq!(q.dequeue(), Some(2));
+        assert_eq!(q.dequeue(), Some(3));
+        assert_eq!(q.dequeue(), Some(4));
+        assert_eq!(q.dequeue(), None);
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn bounded_wraps_around() {
+        let mut q = BoundedQueue::with_capacity(4);
+        let mut next = 0;
+        let mut expected = 0;
+
+        // keep the buffer between two and four items so the head and tail wrap many times
+        for round in 0..50 {
+            while q.enqueue(next) {
+                next += 1;
+            }
+            for _ in 0..(2 + round % 3) {
+                assert_eq!(q.dequeue(), Some(expected));
+                expected += 1;
+            }
+            assert_eq!(q.peek(), if q.is_empty() { None } else { Some(&expected) });
+            assert_eq!(q.len(), next - expected);
+        }
+    }
+
+    #[test]
+    fn bounded_zero_capacity() {
+        let mut q = BoundedQueue::with_capacity(0);
+
+        assert!(q.is_full());
+        assert!(!q.enqueue("A"));
+        assert_eq!(q.peek(), None);
+        assert_eq!(q.dequeue(), None);
+    }
 }
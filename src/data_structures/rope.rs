@@ -78,6 +78,18 @@ impl Rope {
         }
     }
 
+    /// Returns the length of the whole text held by the rope.
+    pub fn len(&self) -> usize {
+        match self {
+            Rope::Node(node) => node.weight + node.right.as_ref().map_or(0, |r| r.len()),
+            Rope::Leaf(str) => str.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn split_at(self: Box<Rope>, index: usize) -> (Option<Box<Rope>>, Option<Box<Rope>>) {
         if index == 0 {
             return (None, Some(self));
@@ -157,12 +169,29 @@ impl Rope {
 
     pub fn concat(self: Box<Rope>, target: Box<Rope>) -> Box<Rope> {
         Box::new(Rope::Node(NodeData {
-            weight: self.get_weight(),
+            // The weight counts the whole left subtree, not just its own left child.
+            weight: self.len(),
             right: Some(target),
             left: Some(self),
         }))
     }
 
+    /// Adds `text` at the end of the rope.
+    pub fn append(self: Box<Rope>, text: &str) -> Box<Rope> {
+        if text.is_empty() {
+            return self;
+        }
+        self.concat(Box::new(Rope::Leaf(String::from(text))))
+    }
+
+    /// Adds `text` at the start of the rope.
+    pub fn prepend(self: Box<Rope>, text: &str) -> Box<Rope> {
+        if text.is_empty() {
+            return self;
+        }
+        Box::new(Rope::Leaf(String::from(text))).concat(self)
+    }
+
     pub fn insert(self: Box<Rope>, value: &str, index: usize) -> Box<Rope> {
         if value.len() == 0 {
             return self;
@@ -446,4 +475,37 @@ mod tests {
         assert_eq!(rope.slice(0, 5), "hello");
         assert_eq!(rope.slice(5, 6), " world");
     }
+
+    #[test]
+    fn append_and_prepend() {
+        let mut rope = boxed!(Rope::Leaf(String::from("quick")));
+        rope = rope.append(" brown");
+        rope = rope.prepend("the ");
+        rope = rope.append(" fox");
+        rope = rope.prepend("");
+        rope = rope.append("");
+        rope = rope.append(" jumps");
+
+        let expected = "the quick brown fox jumps";
+        assert_eq!(rope.to_string(), expected);
+        assert_eq!(rope.len(), expected.len());
+        for (i, c) in expected.chars().enumerate() {
+            assert_eq!(&rope[i], c.to_string());
+        }
+    }
+
+    #[test]
+    fn len_stays_consistent() {
+        let mut rope = boxed!(Rope::Leaf(String::new()));
+        let mut expected = String::new();
+        assert!(rope.is_empty());
+
+        for word in ["lorem", "ipsum", "dolor", "sit", "amet"].iter() {
+            rope = rope.append(word).prepend(word);
+            expected = format!("{}{}{}", word, expected, word);
+            assert_eq!(rope.len(), expected.len());
+            assert_eq!(rope.to_string(), expected);
+        }
+        assert_eq!(rope.slice(7, 5), "dolor");
+    }
 }
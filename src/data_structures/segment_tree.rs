@@ -1,9 +1,24 @@
+use std::ops::{Add, Range};
+
 /// This stucture implements a segmented tree that
 /// can efficiently answer range queries on arrays.
-pub struct SegmentTree<T: Default + Ord + Copy> {
+///
+/// The values are combined with an associative operation `combine`. Queries
+/// keep the parts found on the left and right borders of the range apart, so
+/// `combine` does not need to be commutative. A tree built with an `identity`,
+/// such that `combine(identity, x) == combine(x, identity) == x`, also answers
+/// empty ranges with it. Such a pair is a monoid, and the same tree answers sum
+/// queries with `0` and `+`, min queries with the largest value and `min`, and
+/// so on.
+///
+/// The tree is stored bottom-up in a flat array of `2n` values, with the leaves
+/// in the second half. Updates and queries walk up from the leaves in a loop,
+/// without recursion.
+pub struct SegmentTree<T: Copy> {
     len: usize,
     buf: Vec<T>,
-    op: Ops,
+    identity: Option<T>,
+    combine: fn(T, T) -> T,
 }
 
 pub enum Ops {
@@ -11,45 +26,73 @@ pub enum Ops {
     Min,
 }
 
-impl<T: Default + Ord + Copy> SegmentTree<T> {
+impl<T: Ord + Copy> SegmentTree<T> {
     /// function to build the tree
     pub fn from_vec(arr: &[T], op: Ops) -> Self {
+        let combine = match op {
+            Ops::Max => T::max,
+            Ops::Min => T::min,
+        };
+        Self::build(arr, None, combine)
+    }
+}
+
+impl<T: Copy> SegmentTree<T> {
+    /// function to build the tree over the monoid of `combine` and `identity`
+    pub fn from_slice(arr: &[T], identity: T, combine: fn(T, T) -> T) -> Self {
+        Self::build(arr, Some(identity), combine)
+    }
+
+    fn build(arr: &[T], identity: Option<T>, combine: fn(T, T) -> T) -> Self {
         let len = arr.len();
-        let mut buf: Vec<T> = vec![T::default(); 2 * len];
-        buf[len..(len + len)].clone_from_slice(&arr[0..len]);
+        // the first half is overwritten below, except for the unused index 0
+        let mut buf: Vec<T> = arr.iter().chain(arr.iter()).copied().collect();
         for i in (1..len).rev() {
-            buf[i] = match op {
-                Ops::Max => buf[2 * i].max(buf[2 * i + 1]),
-                Ops::Min => buf[2 * i].min(buf[2 * i + 1]),
-            };
+            buf[i] = combine(buf[2 * i], buf[2 * i + 1]);
+        }
+        SegmentTree {
+            len,
+            buf,
+            identity,
+            combine,
         }
-        SegmentTree { len, buf, op }
     }
 
-    /// function to get sum on interval [l, r]
+    /// function to get the combined values on interval [l, r]
+    ///
+    /// An empty interval, with `l > r`, gives the identity. It panics if the
+    /// tree was built without one.
     pub fn query(&self, mut l: usize, mut r: usize) -> T {
         l += self.len;
         r += self.len;
-        let mut res = self.buf[l];
+        // the parts found on each border, kept apart to preserve the order
+        let mut left: Option<T> = None;
+        let mut right: Option<T> = None;
         while l <= r {
-            if l % 2 == 1 {
-                res = match self.op {
-                    Ops::Max => res.max(self.buf[l]),
-                    Ops::Min => res.min(self.buf[l]),
-                };
+            if l & 1 == 1 {
+                left = Some(match left {
+                    Some(acc) => (self.combine)(acc, self.buf[l]),
+                    None => self.buf[l],
+                });
                 l += 1;
             }
-            if r % 2 == 0 {
-                res = match self.op {
-                    Ops::Max => res.max(self.buf[r]),
-                    Ops::Min => res.min(self.buf[r]),
-                };
+            if r & 1 == 0 {
+                right = Some(match right {
+                    Some(acc) => (self.combine)(self.buf[r], acc),
+                    None => self.buf[r],
+                });
                 r -= 1;
             }
             l /= 2;
             r /= 2;
         }
-        res
+        match (left, right) {
+            (Some(left), Some(right)) => (self.combine)(left, right),
+            (Some(only), None) | (None, Some(only)) => only,
+            (None, None) => self
+                .identity
+                .expect("querying an empty range needs a tree built with an identity"),
+        }
     }
 
     /// function to update a tree node
@@ -59,15 +102,361 @@ impl<T: Default + Ord + Copy> SegmentTree<T> {
         idx /= 2;
 
         while idx != 0 {
-            self.buf[idx] = match self.op {
-                Ops::Max => self.buf[2 * idx].max(self.buf[2 * idx + 1]),
-                Ops::Min => self.buf[2 * idx].min(self.buf[2 * idx + 1]),
-            };
+            self.buf[idx] = (self.combine)(self.buf[2 * idx], self.buf[2 * idx + 1]);
             idx /= 2;
         }
     }
 }
 
+/// A segment tree over values modulo `modulus` that answers range product
+/// queries and supports multiplying every element of a range by a factor.
+///
+/// Range multiplications are applied lazily: a node stores the factor still
+/// owed to its children, and multiplying a whole node by `factor` scales its
+/// product by `factor^len`, where `len` is the number of elements it covers.
+pub struct ModularProductSegmentTree {
+    len: usize,
+    modulus: u64,
+    tree: Vec<u64>,
+    lazy: Vec<u64>,
+}
+
+impl ModularProductSegmentTree {
+    /// Builds the tree from `arr`, reducing every value modulo `modulus`.
+    pub fn from_vec(arr: &[u64], modulus: u64) -> Self {
+        assert!(modulus > 1, "modulus must be greater than 1");
+        let len = arr.len();
+        let mut tree = ModularProductSegmentTree {
+            len,
+            modulus,
+            tree: vec![1 % modulus; 4 * len.max(1)],
+            lazy: vec![1; 4 * len.max(1)],
+        };
+        if len > 0 {
+            tree.build(arr, 1, 0, len - 1);
+        }
+        tree
+    }
+
+    /// Multiplies every element on the interval [l, r] by `factor`.
+    pub fn range_multiply(&mut self, l: usize, r: usize, factor: u64) {
+        assert!(l <= r && r < self.len, "invalid range");
+        let factor = factor % self.modulus;
+        self.multiply(1, 0, self.len - 1, l, r, factor);
+    }
+
+    /// Returns the product of the elements on the interval [l, r], modulo `modulus`.
+    pub fn range_product(&mut self, l: usize, r: usize) -> u64 {
+        assert!(l <= r && r < self.len, "invalid range");
+        self.product(1, 0, self.len - 1, l, r)
+    }
+
+    fn build(&mut self, arr: &[u64], node: usize, start: usize, end: usize) {
+        if start == end {
+            self.tree[node] = arr[start] % self.modulus;
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.build(arr, 2 * node, start, mid);
+        self.build(arr, 2 * node + 1, mid + 1, end);
+        self.tree[node] = self.mul(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    fn multiply(&mut self, node: usize, start: usize, end: usize, l: usize, r: usize, factor: u64) {
+        if r < start || end < l {
+            return;
+        }
+        if l <= start && end <= r {
+            self.apply(node, end - start + 1, factor);
+            return;
+        }
+        self.push(node, start, end);
+        let mid = (start + end) / 2;
+        self.multiply(2 * node, start, mid, l, r, factor);
+        self.multiply(2 * node + 1, mid + 1, end, l, r, factor);
+        self.tree[node] = self.mul(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    fn product(&mut self, node: usize, start: usize, end: usize, l: usize, r: usize) -> u64 {
+        if r < start || end < l {
+            return 1 % self.modulus;
+        }
+        if l <= start && end <= r {
+            return self.tree[node];
+        }
+        self.push(node, start, end);
+        let mid = (start + end) / 2;
+        let left = self.product(2 * node, start, mid, l, r);
+        let right = self.product(2 * node + 1, mid + 1, end, l, r);
+        self.mul(left, right)
+    }
+
+    /// Multiplies the `len` elements covered by `node` by `factor`.
+    fn apply(&mut self, node: usize, len: usize, factor: u64) {
+        let scale = self.pow(factor, len as u64);
+        self.tree[node] = self.mul(self.tree[node], scale);
+        self.lazy[node] = self.mul(self.lazy[node], factor);
+    }
+
+    /// Hands the pending factor of `node` down to its children.
+    fn push(&mut self, node: usize, start: usize, end: usize) {
+        let factor = self.lazy[node];
+        if factor == 1 {
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.apply(2 * node, mid - start + 1, factor);
+        self.apply(2 * node + 1, end - mid, factor);
+        self.lazy[node] = 1;
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        ((a as u128 * b as u128) % self.modulus as u128) as u64
+    }
+
+    fn pow(&self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = 1 % self.modulus;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+/// A segment tree over any associative `merge`, supporting the addition of a
+/// value to every element of a range.
+///
+/// Range additions are applied lazily: a node that is entirely covered by the
+/// range is updated at once and keeps the addition pending for its children,
+/// which only receive it when a later update or query goes below that node.
+/// How the merged value of a node changes when `delta` is added to each of its
+/// `len` elements depends on `merge`, so it is given by `apply(value, delta, len)`:
+/// for sums the value grows by `delta * len`, for minimums and maximums by `delta`.
+pub struct LazySegmentTree<T: Copy + Add<Output = T>> {
+    len: usize,
+    tree: Vec<T>,
+    lazy: Vec<Option<T>>,
+    merge: fn(T, T) -> T,
+    apply: fn(T, T, usize) -> T,
+}
+
+impl<T: Copy + Add<Output = T>> LazySegmentTree<T> {
+    /// Builds the tree from `arr`.
+    pub fn from_vec(arr: &[T], merge: fn(T, T) -> T, apply: fn(T, T, usize) -> T) -> Self {
+        let len = arr.len();
+        let mut tree = LazySegmentTree {
+            len,
+            // every node is overwritten by build, the filler value does not matter
+            tree: arr
+                .first()
+                .map_or(Vec::new(), |&first| vec![first; 4 * len]),
+            lazy: vec![None; 4 * len],
+            merge,
+            apply,
+        };
+        if len > 0 {
+            tree.build(arr, 1, 0, len - 1);
+        }
+        tree
+    }
+
+    /// Adds `delta` to every element in `range`.
+    pub fn update_range(&mut self, range: Range<usize>, delta: T) {
+        assert!(
+            range.start < range.end && range.end <= self.len,
+            "invalid range"
+        );
+        self.add(1, 0, self.len - 1, range.start, range.end - 1, delta);
+    }
+
+    /// Returns the elements in `range` merged from left to right.
+    pub fn query_range(&mut self, range: Range<usize>) -> T {
+        assert!(
+            range.start < range.end && range.end <= self.len,
+            "invalid range"
+        );
+        self.merged(1, 0, self.len - 1, range.start, range.end - 1)
+    }
+
+    fn build(&mut self, arr: &[T], node: usize, start: usize, end: usize) {
+        if start == end {
+            self.tree[node] = arr[start];
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.build(arr, 2 * node, start, mid);
+        self.build(arr, 2 * node + 1, mid + 1, end);
+        self.tree[node] = (self.merge)(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    fn add(&mut self, node: usize, start: usize, end: usize, l: usize, r: usize, delta: T) {
+        if r < start || end < l {
+            return;
+        }
+        if l <= start && end <= r {
+            self.apply_to(node, end - start + 1, delta);
+            return;
+        }
+        self.push(node, start, end);
+        let mid = (start + end) / 2;
+        self.add(2 * node, start, mid, l, r, delta);
+        self.add(2 * node + 1, mid + 1, end, l, r, delta);
+        self.tree[node] = (self.merge)(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    /// Returns [l, r] intersected with the range of `node`, which must overlap it, merged.
+    fn merged(&mut self, node: usize, start: usize, end: usize, l: usize, r: usize) -> T {
+        if l <= start && end <= r {
+            return self.tree[node];
+        }
+        self.push(node, start, end);
+        let mid = (start + end) / 2;
+        if r <= mid {
+            self.merged(2 * node, start, mid, l, r)
+        } else if mid < l {
+            self.merged(2 * node + 1, mid + 1, end, l, r)
+        } else {
+            let left = self.merged(2 * node, start, mid, l, r);
+            let right = self.merged(2 * node + 1, mid + 1, end, l, r);
+            (self.merge)(left, right)
+        }
+    }
+
+    /// Adds `delta` to the `len` elements covered by `node`.
+    fn apply_to(&mut self, node: usize, len: usize, delta: T) {
+        self.tree[node] = (self.apply)(self.tree[node], delta, len);
+        self.lazy[node] = Some(match self.lazy[node] {
+            Some(pending) => pending + delta,
+            None => delta,
+        });
+    }
+
+    /// Hands the pending addition of `node` down to its children.
+    fn push(&mut self, node: usize, start: usize, end: usize) {
+        if let Some(delta) = self.lazy[node].take() {
+            let mid = (start + end) / 2;
+            self.apply_to(2 * node, mid - start + 1, delta);
+            self.apply_to(2 * node + 1, end - mid, delta);
+        }
+    }
+}
+
+/// A segment tree answering "maximum subarray sum" queries on a range, as in
+/// the classic GSS problems.
+///
+/// Every node keeps the total of its range together with its best prefix sum,
+/// best suffix sum and best subarray sum. Two adjacent ranges are merged by
+/// noting that the best subarray of the union either lies in one half or
+/// crosses the middle, in which case it is a suffix of the left half followed
+/// by a prefix of the right half. Subarrays are never empty, so a range of
+/// negative values reports its largest element.
+pub struct MaxSubarraySegmentTree {
+    len: usize,
+    tree: Vec<SubarraySums>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct SubarraySums {
+    total: i64,
+    prefix: i64,
+    suffix: i64,
+    best: i64,
+}
+
+impl SubarraySums {
+    fn leaf(value: i64) -> Self {
+        SubarraySums {
+            total: value,
+            prefix: value,
+            suffix: value,
+            best: value,
+        }
+    }
+
+    fn merge(left: Self, right: Self) -> Self {
+        SubarraySums {
+            total: left.total + right.total,
+            prefix: left.prefix.max(left.total + right.prefix),
+            suffix: right.suffix.max(right.total + left.suffix),
+            best: left.best.max(right.best).max(left.suffix + right.prefix),
+        }
+    }
+}
+
+impl MaxSubarraySegmentTree {
+    /// Builds the tree from `arr`.
+    pub fn from_vec(arr: &[i64]) -> Self {
+        let len = arr.len();
+        let mut tree = MaxSubarraySegmentTree {
+            len,
+            tree: vec![SubarraySums::default(); 4 * len.max(1)],
+        };
+        if len > 0 {
+            tree.build(arr, 1, 0, len - 1);
+        }
+        tree
+    }
+
+    /// Sets the element at `idx` to `val`.
+    pub fn update(&mut self, idx: usize, val: i64) {
+        assert!(idx < self.len, "index out of bounds");
+        self.set(1, 0, self.len - 1, idx, val);
+    }
+
+    /// Returns the largest sum of a non-empty contiguous subarray of the interval [l, r].
+    pub fn range_max_subarray(&self, l: usize, r: usize) -> i64 {
+        assert!(l <= r && r < self.len, "invalid range");
+        self.sums(1, 0, self.len - 1, l, r).best
+    }
+
+    fn build(&mut self, arr: &[i64], node: usize, start: usize, end: usize) {
+        if start == end {
+            self.tree[node] = SubarraySums::leaf(arr[start]);
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.build(arr, 2 * node, start, mid);
+        self.build(arr, 2 * node + 1, mid + 1, end);
+        self.tree[node] = SubarraySums::merge(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    fn set(&mut self, node: usize, start: usize, end: usize, idx: usize, val: i64) {
+        if start == end {
+            self.tree[node] = SubarraySums::leaf(val);
+            return;
+        }
+        let mid = (start + end) / 2;
+        if idx <= mid {
+            self.set(2 * node, start, mid, idx, val);
+        } else {
+            self.set(2 * node + 1, mid + 1, end, idx, val);
+        }
+        self.tree[node] = SubarraySums::merge(self.tree[2 * node], self.tree[2 * node + 1]);
+    }
+
+    /// Returns the sums of [l, r] intersected with the range of `node`, which must overlap it.
+    fn sums(&self, node: usize, start: usize, end: usize, l: usize, r: usize) -> SubarraySums {
+        if l <= start && end <= r {
+            return self.tree[node];
+        }
+        let mid = (start + end) / 2;
+        if r <= mid {
+            self.sums(2 * node, start, mid, l, r)
+        } else if mid < l {
+            self.sums(2 * node + 1, mid + 1, end, l, r)
+        } else {
+            SubarraySums::merge(
+                self.sums(2 * node, start, mid, l, r),
+                self.sums(2 * node + 1, mid + 1, end, l, r),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +473,286 @@ mod tests {
         max_seg_tree.update(6, 8);
         assert_eq!(8, max_seg_tree.query(4, 6));
     }
+
+    #[test]
+    fn min_max_and_sum_trees() {
+        let values = vec![5, -3, 8, 0, -7, 2, 9, -1, 4, 6, -2];
+        let min_tree = SegmentTree::from_slice(&values, i32::MAX, i32::min);
+        let max_tree = SegmentTree::from_slice(&values, i32::MIN, i32::max);
+        let sum_tree = SegmentTree::from_slice(&values, 0, |a, b| a + b);
+
+        for l in 0..values.len() {
+            for r in l..values.len() {
+                let range = &values[l..=r];
+                assert_eq!(min_tree.query(l, r), *range.iter().min().unwrap());
+                assert_eq!(max_tree.query(l, r), *range.iter().max().unwrap());
+                assert_eq!(sum_tree.query(l, r), range.iter().sum::<i32>());
+            }
+        }
+    }
+
+    #[test]
+    fn non_commutative_combine() {
+        // concatenating decimal numbers, each kept as a (value, number of digits) pair
+        fn concat(a: (u64, u32), b: (u64, u32)) -> (u64, u32) {
+            (a.0 * 10u64.pow(b.1) + b.0, a.1 + b.1)
+        }
+        let digits: Vec<(u64, u32)> = (1..=9).map(|d| (d, 1)).collect();
+        let mut tree = SegmentTree::from_slice(&digits, (0, 0), concat);
+
+        assert_eq!(tree.query(0, 8), (123_456_789, 9));
+        assert_eq!(tree.query(2, 5), (3456, 4));
+        assert_eq!(tree.query(7, 7), (8, 1));
+        tree.update(3, (0, 1));
+        assert_eq!(tree.query(1, 6), (230_567, 6));
+    }
+
+    #[test]
+    fn modular_product_matches_brute_force() {
+        const MOD: u64 = 1_000_000_007;
+        let mut values: Vec<u64> = vec![3, 7, 1, 12, 999_999_999, 5, 8, 2, 0, 6, 4];
+        let mut tree = ModularProductSegmentTree::from_vec(&values, MOD);
+
+        let brute_product = |values: &[u64], l: usize, r: usize| {
+            values[l..=r].iter().fold(1u64, |acc, &v| {
+                (acc as u128 * v as u128 % MOD as u128) as u64
+            })
+        };
+
+        let operations = [
+            (0, 10, 2),
+            (2, 5, 1_000_000_006),
+            (4, 4, 123_456_789),
+            (1, 9, 3),
+            (7, 10, 0),
+            (0, 3, 17),
+        ];
+        for &(l, r, factor) in operations.iter() {
+            tree.range_multiply(l, r, factor);
+            for v in values[l..=r].iter_mut() {
+                *v = (*v as u128 * factor as u128 % MOD as u128) as u64;
+            }
+
+            for ql in 0..values.len() {
+                for qr in ql..values.len() {
+                    assert_eq!(tree.range_product(ql, qr), brute_product(&values, ql, qr));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn modular_product_small_modulus() {
+        let mut tree = ModularProductSegmentTree::from_vec(&[2, 3, 4, 5], 7);
+        assert_eq!(tree.range_product(0, 3), 120 % 7);
+
+        tree.range_multiply(1, 2, 3);
+        assert_eq!(tree.range_product(0, 3), (2 * 9 * 12 * 5) % 7);
+        assert_eq!(tree.range_product(2, 2), 12 % 7);
+
+        tree.range_multiply(0, 1, 2);
+        assert_eq!(tree.range_product(0, 1), (4 * 18) % 7);
+        assert_eq!(tree.range_product(1, 3), (18 * 12 * 5) % 7);
+    }
+
+    /// Kadane's algorithm over a non-empty slice.
+    fn kadane(values: &[i64]) -> i64 {
+        let mut best = values[0];
+        let mut current = values[0];
+        for &v in &values[1..] {
+            current = v.max(current + v);
+            best = best.max(current);
+        }
+        best
+    }
+
+    #[test]
+    fn max_subarray_matches_kadane() {
+        let mut values = vec![2, -1, 3, -7, 4, 5, -2, -9, 6, -1, 1, 8, -3, -4, 2];
+        let mut tree = MaxSubarraySegmentTree::from_vec(&values);
+
+        let check = |tree: &MaxSubarraySegmentTree, values: &[i64]| {
+            for l in 0..values.len() {
+                for r in l..values.len() {
+                    assert_eq!(tree.range_max_subarray(l, r), kadane(&values[l..=r]));
+                }
+            }
+        };
+        check(&tree, &values);
+
+        for &(idx, val) in [(3, 10), (0, -5), (14, -20), (7, 0)].iter() {
+            tree.update(idx, val);
+            values[idx] = val;
+            check(&tree, &values);
+        }
+    }
+
+    #[test]
+    fn max_subarray_all_negative() {
+        let values = [-3, -1, -4, -1, -5, -9, -2, -6];
+        let tree = MaxSubarraySegmentTree::from_vec(&values);
+        assert_eq!(tree.range_max_subarray(0, 7), -1);
+        assert_eq!(tree.range_max_subarray(4, 5), -5);
+        assert_eq!(tree.range_max_subarray(5, 5), -9);
+        assert_eq!(tree.range_max_subarray(5, 7), -2);
+    }
+
+    #[test]
+    fn max_subarray_crossing_the_middle() {
+        let tree = MaxSubarraySegmentTree::from_vec(&[-1, -1, 5, -1, 6, -1, -1, -1]);
+        assert_eq!(tree.range_max_subarray(0, 7), 10);
+        assert_eq!(tree.range_max_subarray(0, 3), 5);
+        assert_eq!(tree.range_max_subarray(3, 7), 6);
+    }
+
+    #[test]
+    fn random_updates_match_brute_force() {
+        let mut rng = crate::math::PCG32::new_default(2024);
+        let mut values: Vec<i64> = (0..37)
+            .map(|_| (rng.get_u32() % 201) as i64 - 100)
+            .collect();
+
+        let mut max_tree = SegmentTree::from_slice(&values, i64::MIN, i64::max);
+        let mut min_tree = SegmentTree::from_slice(&values, i64::MAX, i64::min);
+        let mut sum_tree = SegmentTree::from_slice(&values, 0, |a, b| a + b);
+
+        for _ in 0..200 {
+            let idx = rng.get_u32() as usize % values.len();
+            let val = (rng.get_u32() % 201) as i64 - 100;
+            values[idx] = val;
+            max_tree.update(idx, val);
+            min_tree.update(idx, val);
+            sum_tree.update(idx, val);
+
+            let a = rng.get_u32() as usize % values.len();
+            let b = rng.get_u32() as usize % values.len();
+            let (l, r) = (a.min(b), a.max(b));
+            let range = &values[l..=r];
+            assert_eq!(max_tree.query(l, r), *range.iter().max().unwrap());
+            assert_eq!(min_tree.query(l, r), *range.iter().min().unwrap());
+            assert_eq!(sum_tree.query(l, r), range.iter().sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn affine_map_composition() {
+        // composing affine maps x -> a * x + b modulo a prime, applying the left one first
+        const MOD: u64 = 1_000_000_007;
+        fn compose(f: (u64, u64), g: (u64, u64)) -> (u64, u64) {
+            (g.0 * f.0 % MOD, (g.0 * f.1 + g.1) % MOD)
+        }
+
+        let mut maps: Vec<(u64, u64)> = (1..=13).map(|i| (i + 1, 2 * i + 3)).collect();
+        let mut tree = SegmentTree::from_slice(&maps, (1, 0), compose);
+
+        let check = |tree: &SegmentTree<(u64, u64)>, maps: &[(u64, u64)]| {
+            for l in 0..maps.len() {
+                for r in l..maps.len() {
+                    let expected = maps[l + 1..=r]
+                        .iter()
+                        .fold(maps[l], |acc, &m| compose(acc, m));
+                    assert_eq!(tree.query(l, r), expected);
+                }
+            }
+        };
+        check(&tree, &maps);
+
+        for &(idx, map) in [(0, (5, 0)), (12, (1, 1)), (6, (MOD - 1, 7))].iter() {
+            tree.update(idx, map);
+            maps[idx] = map;
+            check(&tree, &maps);
+        }
+    }
+
+    #[test]
+    fn empty_range() {
+        let values = [3, 1, 4, 1, 5];
+        let sum_tree = SegmentTree::from_slice(&values, 0, |a, b| a + b);
+        assert_eq!(sum_tree.query(3, 2), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a tree built with an identity")]
+    fn empty_range_without_identity() {
+        SegmentTree::from_vec(&[3, 1, 4], Ops::Max).query(2, 1);
+    }
+
+    #[test]
+    fn single_element() {
+        let mut tree = SegmentTree::from_slice(&[42], i32::MIN, i32::max);
+        assert_eq!(tree.query(0, 0), 42);
+        tree.update(0, -1);
+        assert_eq!(tree.query(0, 0), -1);
+    }
+
+    #[test]
+    fn lazy_sum_matches_brute_force() {
+        let mut rng = crate::math::PCG32::new_default(31);
+        let mut values: Vec<i64> = (0..45).map(|_| (rng.get_u32() % 100) as i64 - 50).collect();
+        let mut tree = LazySegmentTree::from_vec(
+            &values,
+            |a, b| a + b,
+            |sum, delta, len| sum + delta * len as i64,
+        );
+
+        for _ in 0..300 {
+            let a = rng.get_u32() as usize % values.len();
+            let b = rng.get_u32() as usize % values.len();
+            let range = a.min(b)..a.max(b) + 1;
+            if rng.get_u32().is_multiple_of(2) {
+                let delta = (rng.get_u32() % 21) as i64 - 10;
+                tree.update_range(range.clone(), delta);
+                for v in values[range].iter_mut() {
+                    *v += delta;
+                }
+            } else {
+                assert_eq!(
+                    tree.query_range(range.clone()),
+                    values[range].iter().sum::<i64>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_min_and_max() {
+        let mut values = vec![5, -2, 7, 0, 3, 3, -8, 12];
+        let mut min_tree =
+            LazySegmentTree::from_vec(&values, i32::min, |min, delta, _| min + delta);
+        let mut max_tree =
+            LazySegmentTree::from_vec(&values, i32::max, |max, delta, _| max + delta);
+
+        for &(l, r, delta) in [(0, 8, 1), (2, 5, -10), (6, 7, 20), (1, 2, 4), (3, 8, -1)].iter() {
+            min_tree.update_range(l..r, delta);
+            max_tree.update_range(l..r, delta);
+            for v in values[l..r].iter_mut() {
+                *v += delta;
+            }
+
+            for ql in 0..values.len() {
+                for qr in ql + 1..=values.len() {
+                    let slice = &values[ql..qr];
+                    assert_eq!(min_tree.query_range(ql..qr), *slice.iter().min().unwrap());
+                    assert_eq!(max_tree.query_range(ql..qr), *slice.iter().max().unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_single_element() {
+        let mut tree =
+            LazySegmentTree::from_vec(&[10u64], |a, b| a + b, |s, d, n| s + d * n as u64);
+        tree.update_range(0..1, 5);
+        tree.update_range(0..1, 1);
+        assert_eq!(tree.query_range(0..1), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range")]
+    fn lazy_empty_range() {
+        let mut tree =
+            LazySegmentTree::from_vec(&[1, 2, 3], |a, b| a + b, |s, d, n| s + d * n as i32);
+        tree.query_range(2..2);
+    }
 }
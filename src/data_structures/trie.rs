@@ -1,56 +1,287 @@
-use std::collections::HashMap;
-use std::hash::Hash;
+use std::collections::BTreeMap;
 
-#[derive(Debug, Default)]
-struct Node<Key: Default, Type: Default> {
-    children: HashMap<Key, Node<Key, Type>>,
-    value: Option<Type>,
+#[derive(Debug)]
+struct Node<K, V> {
+    // ordered, so that walking the children visits keys in lexicographic order
+    children: BTreeMap<K, Node<K, V>>,
+    value: Option<V>,
+    // the number of keys ending at this node or below it
+    words: usize,
 }
 
-#[derive(Debug, Default)]
-pub struct Trie<Key, Type>
-where
-    Key: Default + Eq + Hash,
-    Type: Default,
-{
-    root: Node<Key, Type>,
+impl<K, V> Default for Node<K, V> {
+    fn default() -> Self {
+        Node {
+            children: BTreeMap::new(),
+            value: None,
+            words: 0,
+        }
+    }
+}
+
+/// A trie that maps keys to values, where a key is any sequence of ordered items, such as
+/// the characters of a string or a list of numbers. Keys sharing a prefix share the nodes
+/// of that prefix.
+///
+/// Every node counts the keys that go through it, and every node that is not the root
+/// lies on the path of some key: removing a key prunes the branches that no other key
+/// uses.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::data_structures::GenericTrie;
+///
+/// let mut routes = GenericTrie::new();
+/// routes.insert(vec![10, 0, 0], "local");
+/// routes.insert(vec![10, 0, 1], "office");
+///
+/// assert_eq!(routes.get(vec![10, 0, 1]), Some(&"office"));
+/// assert_eq!(routes.get(vec![10, 0]), None);
+/// ```
+#[derive(Debug)]
+pub struct GenericTrie<K, V> {
+    root: Node<K, V>,
 }
 
-impl<Key, Type> Trie<Key, Type>
-where
-    Key: Default + Eq + Hash,
-    Type: Default,
-{
+impl<K: Ord, V> Default for GenericTrie<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> GenericTrie<K, V> {
     pub fn new() -> Self {
         Self {
             root: Node::default(),
         }
     }
 
-    pub fn insert(&mut self, key: impl IntoIterator<Item = Key>, value: Type)
+    /// Associates `value` with `key`, returning the value it replaces, if any.
+    pub fn insert(&mut self, key: impl IntoIterator<Item = K>, value: V) -> Option<V> {
+        let key: Vec<K> = key.into_iter().collect();
+        if let Some(old) = self.get_mut(key.iter()) {
+            return Some(std::mem::replace(old, value));
+        }
+
+        let mut node = &mut self.root;
+        node.words += 1;
+        for item in key {
+            node = node.children.entry(item).or_default();
+            node.words += 1;
+        }
+        node.value = Some(value);
+        None
+    }
+
+    /// Returns the value associated with `key`, or `None` if `key` was not inserted as a
+    /// whole key, even if it is a prefix of other keys.
+    pub fn get<Q>(&self, key: impl IntoIterator<Item = Q>) -> Option<&V>
+    where
+        Q: std::borrow::Borrow<K>,
+    {
+        self.find(key)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    pub fn get_mut<Q>(&mut self, key: impl IntoIterator<Item = Q>) -> Option<&mut V>
     where
-        Key: Eq + Hash,
+        Q: std::borrow::Borrow<K>,
     {
         let mut node = &mut self.root;
-        for c in key.into_iter() {
-            node = node.children.entry(c).or_default();
+        for item in key {
+            node = node.children.get_mut(item.borrow())?;
         }
-        node.value = Some(value);
+        node.value.as_mut()
+    }
+
+    /// Removes `key` along with the nodes only it was using, and returns its value.
+    pub fn remove<Q>(&mut self, key: impl IntoIterator<Item = Q>) -> Option<V>
+    where
+        Q: std::borrow::Borrow<K>,
+    {
+        Self::remove_below(&mut self.root, key.into_iter())
     }
 
-    pub fn get(&self, key: impl IntoIterator<Item = Key>) -> Option<&Type>
+    fn find<Q>(&self, prefix: impl IntoIterator<Item = Q>) -> Option<&Node<K, V>>
     where
-        Key: Eq + Hash,
+        Q: std::borrow::Borrow<K>,
     {
         let mut node = &self.root;
-        for c in key.into_iter() {
-            if node.children.contains_key(&c) {
-                node = node.children.get(&c).unwrap()
-            } else {
-                return None;
+        for item in prefix {
+            node = node.children.get(item.borrow())?;
+        }
+        Some(node)
+    }
+
+    /// Removes the rest of a key below `node`, pruning children left without any key.
+    fn remove_below<Q>(node: &mut Node<K, V>, mut rest: impl Iterator<Item = Q>) -> Option<V>
+    where
+        Q: std::borrow::Borrow<K>,
+    {
+        let removed = match rest.next() {
+            Some(item) => {
+                let item = item.borrow();
+                let child = node.children.get_mut(item)?;
+                let removed = Self::remove_below(child, rest)?;
+                if child.words == 0 {
+                    node.children.remove(item);
+                }
+                removed
             }
+            None => node.value.take()?,
+        };
+        node.words -= 1;
+        Some(removed)
+    }
+}
+
+/// A trie that maps words to values, like a dictionary keyed by strings in which
+/// words sharing a prefix share the nodes of that prefix. It is a `GenericTrie` keyed
+/// by the characters of the words.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::data_structures::TrieMap;
+///
+/// let mut ages = TrieMap::new();
+/// ages.insert("alice", 31);
+/// ages.insert("alicia", 27);
+///
+/// assert_eq!(ages.get("alice"), Some(&31));
+/// assert_eq!(ages.get("ali"), None);
+///
+/// *ages.get_mut("alicia").unwrap() += 1;
+/// assert_eq!(ages.get("alicia"), Some(&28));
+/// ```
+#[derive(Debug)]
+pub struct TrieMap<V> {
+    trie: GenericTrie<char, V>,
+}
+
+impl<V> Default for TrieMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> TrieMap<V> {
+    pub fn new() -> Self {
+        Self {
+            trie: GenericTrie::new(),
+        }
+    }
+
+    /// Associates `value` with `word`, returning the value it replaces, if any.
+    pub fn insert(&mut self, word: &str, value: V) -> Option<V> {
+        self.trie.insert(word.chars(), value)
+    }
+
+    /// Returns the value associated with `word`, or `None` if `word` was not inserted as a
+    /// whole word, even if it is a prefix of other words.
+    pub fn get(&self, word: &str) -> Option<&V> {
+        self.trie.get(word.chars())
+    }
+
+    /// Returns a mutable reference to the value associated with `word`.
+    pub fn get_mut(&mut self, word: &str) -> Option<&mut V> {
+        self.trie.get_mut(word.chars())
+    }
+
+    /// Removes `word` along with the nodes only it was using, and returns its value.
+    pub fn remove(&mut self, word: &str) -> Option<V> {
+        self.trie.remove(word.chars())
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node<char, V>> {
+        self.trie.find(prefix.chars())
+    }
+
+    /// Appends every word ending at `node` or below it to `words`, in lexicographic order.
+    /// `word` holds the characters on the path to `node`.
+    fn collect_words(node: &Node<char, V>, word: &mut String, words: &mut Vec<String>) {
+        if node.value.is_some() {
+            words.push(word.clone());
+        }
+        for (&c, child) in node.children.iter() {
+            word.push(c);
+            Self::collect_words(child, word, words);
+            word.pop();
         }
-        node.value.as_ref()
+    }
+}
+
+/// A trie, or prefix tree: a set of words stored as paths of characters from the root,
+/// so that words sharing a prefix share the nodes of that prefix. It is a `TrieMap`
+/// without values.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::data_structures::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert("car");
+/// trie.insert("cart");
+///
+/// assert!(trie.search("car"));
+/// assert!(!trie.search("ca"));
+/// assert!(trie.starts_with("ca"));
+///
+/// assert!(trie.delete("car"));
+/// assert!(!trie.search("car"));
+/// assert!(trie.search("cart"));
+/// ```
+#[derive(Debug, Default)]
+pub struct Trie {
+    map: TrieMap<()>,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self {
+            map: TrieMap::new(),
+        }
+    }
+
+    /// Adds `word` to the trie. Inserting a word twice has no further effect.
+    pub fn insert(&mut self, word: &str) {
+        self.map.insert(word, ());
+    }
+
+    /// Returns whether `word` was inserted, as a whole word.
+    pub fn search(&self, word: &str) -> bool {
+        self.map.get(word).is_some()
+    }
+
+    /// Returns whether some inserted word starts with `prefix`. Every word starts with
+    /// the empty prefix, so it only matches when the trie is not empty.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.count_prefix(prefix) > 0
+    }
+
+    /// Returns how many inserted words start with `prefix`, including `prefix` itself.
+    /// The empty prefix counts every word.
+    pub fn count_prefix(&self, prefix: &str) -> usize {
+        self.map.find(prefix).map_or(0, |node| node.words)
+    }
+
+    /// Returns the inserted words that start with `prefix`, including `prefix` itself,
+    /// in lexicographic order.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.map.find(prefix) {
+            TrieMap::collect_words(node, &mut prefix.to_string(), &mut words);
+        }
+        words
+    }
+
+    /// Removes `word` from the trie, along with the nodes only it was using.
+    ///
+    /// Returns `true` if the word was in the trie, and `false` otherwise.
+    pub fn delete(&mut self, word: &str) -> bool {
+        self.map.remove(word).is_some()
     }
 }
 
@@ -61,13 +292,13 @@ mod tests {
 
     #[test]
     fn test_insertion() {
-        let mut trie = Trie::new();
+        let mut trie = GenericTrie::new();
         assert_eq!(trie.get("".chars()), None);
 
         trie.insert("foo".chars(), 1);
         trie.insert("foobar".chars(), 2);
 
-        let mut trie = Trie::new();
+        let mut trie = GenericTrie::new();
         assert_eq!(trie.get(vec![1, 2, 3]), None);
 
         trie.insert(vec![1, 2, 3], 1);
@@ -76,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_get() {
-        let mut trie = Trie::new();
+        let mut trie = GenericTrie::new();
         trie.insert("foo".chars(), 1);
         trie.insert("foobar".chars(), 2);
         trie.insert("bar".chars(), 3);
@@ -85,7 +316,7 @@ mod tests {
         assert_eq!(trie.get("foo".chars()), Some(&1));
         assert_eq!(trie.get("food".chars()), None);
 
-        let mut trie = Trie::new();
+        let mut trie = GenericTrie::new();
         trie.insert(vec![1, 2, 3, 4], 1);
         trie.insert(vec![42], 2);
         trie.insert(vec![42, 6, 1000], 3);
@@ -94,4 +325,331 @@ mod tests {
         assert_eq!(trie.get(vec![42, 6, 1000]), Some(&3));
         assert_eq!(trie.get(vec![43, 44, 45]), None);
     }
+
+    #[test]
+    fn test_generic_remove() {
+        let mut trie = GenericTrie::new();
+        trie.insert(vec![1, 2], "a");
+        trie.insert(vec![1, 2, 3], "b");
+        trie.insert(vec![7], "c");
+
+        assert_eq!(trie.remove([1, 2, 3]), Some("b"));
+        assert_eq!(trie.remove(vec![1, 2, 3]), None);
+        assert_eq!(trie.get([1, 2]), Some(&"a"));
+        assert!(trie.find([1, 2]).unwrap().children.is_empty());
+
+        *trie.get_mut(vec![7]).unwrap() = "d";
+        assert_eq!(trie.insert(vec![7], "e"), Some("d"));
+    }
+
+    #[test]
+    fn test_insert_words() {
+        let mut trie = Trie::new();
+        assert!(!trie.search(""));
+        assert!(!trie.search("foo"));
+
+        trie.insert("foo");
+        trie.insert("foobar");
+        trie.insert("foo");
+
+        assert!(trie.search("foo"));
+        assert!(trie.search("foobar"));
+    }
+
+    #[test]
+    fn test_search() {
+        let mut trie = Trie::new();
+        trie.insert("foo");
+        trie.insert("foobar");
+        trie.insert("bar");
+        trie.insert("baz");
+
+        assert!(trie.search("foo"));
+        assert!(trie.search("baz"));
+        assert!(!trie.search("food"));
+        assert!(!trie.search("fo"));
+        assert!(!trie.search("ba"));
+        assert!(!trie.search("foobarbaz"));
+        assert!(!trie.search(""));
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let mut trie = Trie::new();
+        assert!(!trie.starts_with(""));
+
+        trie.insert("foobar");
+        trie.insert("bar");
+
+        assert!(trie.starts_with(""));
+        assert!(trie.starts_with("f"));
+        assert!(trie.starts_with("foo"));
+        assert!(trie.starts_with("foobar"));
+        assert!(trie.starts_with("bar"));
+        assert!(!trie.starts_with("foobarr"));
+        assert!(!trie.starts_with("baz"));
+        assert!(!trie.starts_with("o"));
+    }
+
+    #[test]
+    fn test_empty_word() {
+        let mut trie = Trie::new();
+        trie.insert("");
+        assert!(trie.search(""));
+        assert!(trie.starts_with(""));
+        assert!(!trie.search("a"));
+
+        trie.insert("a");
+        assert!(trie.delete(""));
+        assert!(!trie.search(""));
+        assert!(!trie.delete(""));
+        assert!(trie.search("a"));
+    }
+
+    #[test]
+    fn test_delete_word_that_is_a_prefix() {
+        let mut trie = Trie::new();
+        trie.insert("foo");
+        trie.insert("foobar");
+
+        assert!(trie.delete("foo"));
+        assert!(!trie.search("foo"));
+        assert!(trie.search("foobar"));
+        assert!(trie.starts_with("foo"));
+    }
+
+    #[test]
+    fn test_delete_word_with_a_prefix_word() {
+        let mut trie = Trie::new();
+        trie.insert("foo");
+        trie.insert("foobar");
+
+        assert!(trie.delete("foobar"));
+        assert!(!trie.search("foobar"));
+        assert!(trie.search("foo"));
+        // the branch below "foo" was pruned
+        assert!(!trie.starts_with("foob"));
+        assert!(trie.map.find("foo").unwrap().children.is_empty());
+    }
+
+    #[test]
+    fn test_delete_prunes_branches() {
+        let mut trie = Trie::new();
+        trie.insert("car");
+        trie.insert("cat");
+        trie.insert("dog");
+
+        assert!(trie.delete("dog"));
+        assert!(!trie.starts_with("d"));
+        assert_eq!(trie.map.trie.root.children.len(), 1);
+
+        assert!(trie.delete("car"));
+        assert!(trie.search("cat"));
+        assert_eq!(trie.map.find("ca").unwrap().children.len(), 1);
+
+        assert!(trie.delete("cat"));
+        assert!(trie.map.trie.root.children.is_empty());
+        assert!(!trie.starts_with(""));
+    }
+
+    #[test]
+    fn test_delete_missing() {
+        let mut trie = Trie::new();
+        trie.insert("foobar");
+
+        assert!(!trie.delete("foo"));
+        assert!(!trie.delete("foobarbaz"));
+        assert!(!trie.delete("bar"));
+        assert!(!trie.delete(""));
+        assert!(trie.search("foobar"));
+
+        assert!(trie.delete("foobar"));
+        assert!(!trie.delete("foobar"));
+    }
+
+    #[test]
+    fn test_unicode() {
+        let mut trie = Trie::new();
+        trie.insert("naïve");
+        trie.insert("日本語");
+
+        assert!(trie.search("naïve"));
+        assert!(trie.starts_with("naï"));
+        assert!(!trie.starts_with("nai"));
+        assert!(trie.starts_with("日本"));
+        assert!(trie.delete("日本語"));
+        assert!(!trie.starts_with("日"));
+    }
+
+    #[test]
+    fn test_map_insert_and_get() {
+        let mut map = TrieMap::new();
+        assert_eq!(map.get(""), None);
+
+        assert_eq!(map.insert("tea", 1), None);
+        assert_eq!(map.insert("ten", 2), None);
+        assert_eq!(map.insert("to", 3), None);
+        assert_eq!(map.insert("", 0), None);
+
+        assert_eq!(map.get("tea"), Some(&1));
+        assert_eq!(map.get("ten"), Some(&2));
+        assert_eq!(map.get("to"), Some(&3));
+        assert_eq!(map.get(""), Some(&0));
+        assert_eq!(map.get("tex"), None);
+    }
+
+    #[test]
+    fn test_map_overwrite() {
+        let mut map = TrieMap::new();
+        map.insert("key", String::from("first"));
+        assert_eq!(
+            map.insert("key", String::from("second")),
+            Some(String::from("first"))
+        );
+        assert_eq!(map.get("key").map(String::as_str), Some("second"));
+    }
+
+    #[test]
+    fn test_map_prefixes_are_not_words() {
+        let mut map = TrieMap::new();
+        map.insert("inn", 5);
+        map.insert("instant", 7);
+
+        assert_eq!(map.get("i"), None);
+        assert_eq!(map.get("in"), None);
+        assert_eq!(map.get("ins"), None);
+        assert_eq!(map.get_mut("inst"), None);
+        assert_eq!(map.get("instantly"), None);
+
+        map.insert("in", 2);
+        assert_eq!(map.get("in"), Some(&2));
+        assert_eq!(map.get("inn"), Some(&5));
+    }
+
+    #[test]
+    fn test_map_get_mut() {
+        let mut map = TrieMap::new();
+        map.insert("count", vec![1]);
+        map.get_mut("count").unwrap().push(2);
+        assert_eq!(map.get("count"), Some(&vec![1, 2]));
+        assert_eq!(map.get_mut("missing"), None);
+    }
+
+    #[test]
+    fn test_map_remove() {
+        let mut map = TrieMap::new();
+        map.insert("a", 1);
+        map.insert("ab", 2);
+
+        assert_eq!(map.remove("ab"), Some(2));
+        assert_eq!(map.remove("ab"), None);
+        assert_eq!(map.get("a"), Some(&1));
+        assert!(map.trie.root.children[&'a'].children.is_empty());
+    }
+
+    #[test]
+    fn test_count_prefix() {
+        let mut trie = Trie::new();
+        assert_eq!(trie.count_prefix(""), 0);
+
+        for word in ["app", "apple", "apply", "apt", "bat", "bath", "apple"].iter() {
+            trie.insert(word);
+        }
+
+        assert_eq!(trie.count_prefix(""), 6);
+        assert_eq!(trie.count_prefix("a"), 4);
+        assert_eq!(trie.count_prefix("ap"), 4);
+        assert_eq!(trie.count_prefix("app"), 3);
+        assert_eq!(trie.count_prefix("appl"), 2);
+        assert_eq!(trie.count_prefix("apple"), 1);
+        assert_eq!(trie.count_prefix("bat"), 2);
+        assert_eq!(trie.count_prefix("bath"), 1);
+        assert_eq!(trie.count_prefix("c"), 0);
+        assert_eq!(trie.count_prefix("applesauce"), 0);
+    }
+
+    #[test]
+    fn test_count_prefix_after_delete() {
+        let mut trie = Trie::new();
+        for word in ["", "a", "ab", "abc", "abd"].iter() {
+            trie.insert(word);
+        }
+        assert_eq!(trie.count_prefix(""), 5);
+        assert_eq!(trie.count_prefix("ab"), 3);
+
+        assert!(trie.delete("ab"));
+        assert_eq!(trie.count_prefix("ab"), 2);
+        assert_eq!(trie.count_prefix("a"), 3);
+
+        // deleting a missing word leaves the counts alone
+        assert!(!trie.delete("ab"));
+        assert!(!trie.delete("abcd"));
+        assert_eq!(trie.count_prefix(""), 4);
+
+        assert!(trie.delete(""));
+        assert!(trie.delete("abc"));
+        assert_eq!(trie.count_prefix(""), 2);
+        assert_eq!(trie.count_prefix("abc"), 0);
+        assert_eq!(trie.count_prefix("abd"), 1);
+    }
+
+    #[test]
+    fn test_words_with_prefix() {
+        let mut trie = Trie::new();
+        for word in ["card", "care", "car", "cat", "carpet", "dog", "ca"].iter() {
+            trie.insert(word);
+        }
+
+        assert_eq!(
+            trie.words_with_prefix("car"),
+            vec!["car", "card", "care", "carpet"]
+        );
+        assert_eq!(
+            trie.words_with_prefix("c"),
+            vec!["ca", "car", "card", "care", "carpet", "cat"]
+        );
+        assert_eq!(trie.words_with_prefix("carp"), vec!["carpet"]);
+        assert_eq!(trie.words_with_prefix("dog"), vec!["dog"]);
+        assert!(trie.words_with_prefix("cb").is_empty());
+        assert!(trie.words_with_prefix("doge").is_empty());
+    }
+
+    #[test]
+    fn test_words_with_prefix_only_complete_words() {
+        let mut trie = Trie::new();
+        trie.insert("internationalization");
+        trie.insert("inter");
+
+        // "i", "in", "int", ... are nodes, but only two of them end words
+        assert_eq!(
+            trie.words_with_prefix(""),
+            vec!["inter", "internationalization"]
+        );
+
+        trie.delete("inter");
+        assert_eq!(
+            trie.words_with_prefix("inter"),
+            vec!["internationalization"]
+        );
+    }
+
+    #[test]
+    fn test_words_with_prefix_is_sorted() {
+        let mut words: Vec<String> = (0..200u32)
+            .map(|i| format!("{:x}", i.wrapping_mul(2_654_435_761) % 4096))
+            .collect();
+        let mut trie = Trie::new();
+        trie.insert("");
+        for word in words.iter() {
+            trie.insert(word);
+        }
+
+        words.push(String::new());
+        words.sort_unstable();
+        words.dedup();
+        assert_eq!(trie.words_with_prefix(""), words);
+
+        let with_a: Vec<String> = words.into_iter().filter(|w| w.starts_with('a')).collect();
+        assert_eq!(trie.words_with_prefix("a"), with_a);
+    }
 }
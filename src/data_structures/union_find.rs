@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// UnionFind data structure
 pub struct UnionFind {
     id: Vec<usize>,
@@ -17,17 +19,30 @@ impl UnionFind {
         Self { id, size, count: n }
     }
 
-    /// Returns the parent of the element
+    /// Returns the representative of the set containing the element.
+    ///
+    /// Every element visited on the way is then pointed directly at the
+    /// representative (path compression), which keeps later finds short.
     pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while root != self.id[root] {
+            root = self.id[root];
+        }
+
         let mut x = x;
-        while x != self.id[x] {
-            x = self.id[x];
-            // self.id[x] = self.id[self.id[x]]; // path compression
+        while x != root {
+            let parent = self.id[x];
+            self.id[x] = root;
+            x = parent;
         }
-        x
+        root
     }
 
-    /// Unions the sets containing x and y
+    /// Unions the sets containing x and y, returning `false` if they were
+    /// already the same set.
+    ///
+    /// The root of the smaller set is attached under the root of the larger
+    /// one (union by size), so a tree of k elements is at most log2(k) tall.
     pub fn union(&mut self, x: usize, y: usize) -> bool {
         let x = self.find(x);
         let y = self.find(y);
@@ -50,10 +65,32 @@ impl UnionFind {
         self.find(x) == self.find(y)
     }
 
+    /// Checks if x and y are connected, that is, in the same set
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.is_same_set(x, y)
+    }
+
+    /// Returns the number of elements in the set containing x
+    pub fn size_of_set(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
     /// Returns the number of disjoint sets
     pub fn count(&self) -> usize {
         self.count
     }
+
+    /// Returns each set's representative mapped to the members of that set,
+    /// in ascending order
+    pub fn groups(&mut self) -> HashMap<usize, Vec<usize>> {
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for x in 0..self.id.len() {
+            let root = self.find(x);
+            groups.entry(root).or_default().push(x);
+        }
+        groups
+    }
 }
 
 #[cfg(test)]
@@ -87,4 +124,132 @@ mod tests {
 
         assert_eq!(1, uf.count());
     }
+
+    #[test]
+    fn test_groups() {
+        let mut uf = UnionFind::new(8);
+        uf.union(0, 3);
+        uf.union(3, 5);
+        uf.union(1, 6);
+        uf.union(7, 6);
+
+        let groups = uf.groups();
+        assert_eq!(groups.len(), uf.count());
+
+        let mut partition: Vec<Vec<usize>> = groups.values().cloned().collect();
+        partition.sort();
+        assert_eq!(
+            partition,
+            vec![vec![0, 3, 5], vec![1, 6, 7], vec![2], vec![4]]
+        );
+
+        for (representative, members) in groups.iter() {
+            assert!(members.contains(representative));
+            assert!(members.iter().all(|&m| uf.find(m) == *representative));
+        }
+    }
+
+    #[test]
+    fn test_size_of_set_and_count() {
+        let mut uf = UnionFind::new(7);
+        assert_eq!(uf.count(), 7);
+        assert!((0..7).all(|x| uf.size_of_set(x) == 1));
+
+        uf.union(0, 1);
+        assert_eq!(uf.count(), 6);
+        assert_eq!(uf.size_of_set(0), 2);
+        assert_eq!(uf.size_of_set(1), 2);
+
+        uf.union(2, 3);
+        uf.union(3, 4);
+        assert_eq!(uf.count(), 4);
+        assert_eq!(uf.size_of_set(2), 3);
+        assert_eq!(uf.size_of_set(4), 3);
+
+        // already in the same set: nothing changes
+        assert!(!uf.union(2, 4));
+        assert_eq!(uf.count(), 4);
+        assert_eq!(uf.size_of_set(3), 3);
+
+        uf.union(1, 4);
+        assert_eq!(uf.count(), 3);
+        assert!((0..5).all(|x| uf.size_of_set(x) == 5));
+        assert_eq!(uf.size_of_set(5), 1);
+        assert_eq!(uf.size_of_set(6), 1);
+
+        uf.union(5, 6);
+        uf.union(6, 0);
+        assert_eq!(uf.count(), 1);
+        assert_eq!(uf.size_of_set(6), 7);
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut uf = UnionFind::new(5);
+        // build the chain 0 <- 1 <- 2 <- 3 <- 4 by hand
+        for x in 1..5 {
+            uf.id[x] = x - 1;
+        }
+
+        assert_eq!(uf.find(4), 0);
+        assert!(uf.id.iter().all(|&parent| parent == 0));
+    }
+
+    /// The number of links from x up to its root, without compressing the path.
+    fn depth(uf: &UnionFind, mut x: usize) -> usize {
+        let mut depth = 0;
+        while x != uf.id[x] {
+            x = uf.id[x];
+            depth += 1;
+        }
+        depth
+    }
+
+    #[test]
+    fn test_union_by_size_keeps_trees_short() {
+        let n = 1 << 12;
+        let mut uf = UnionFind::new(n);
+        // merge pairs, then pairs of pairs, ... so every union joins two equal trees
+        let mut width = 1;
+        while width < n {
+            for x in (0..n).step_by(2 * width) {
+                uf.union(x + width, x);
+            }
+            width *= 2;
+        }
+
+        assert_eq!(uf.count(), 1);
+        assert!((0..n).all(|x| depth(&uf, x) <= 12));
+        assert_eq!(uf.size_of_set(n - 1), n);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let n = 100_000;
+        let mut uf = UnionFind::new(n);
+        for x in 0..n - 1 {
+            assert!(uf.union(x, x + 1));
+        }
+        // a larger set always absorbs the next element, so nothing is far from its root
+        assert!((0..n).all(|x| depth(&uf, x) <= 1));
+
+        assert_eq!(uf.count(), 1);
+        assert!(uf.connected(0, n - 1));
+        for x in (0..n).step_by(997) {
+            assert!(uf.connected(x, n - 1 - x));
+        }
+    }
+
+    #[test]
+    fn test_connected() {
+        let mut uf = UnionFind::new(6);
+        uf.union(0, 1);
+        uf.union(2, 3);
+        assert!(uf.connected(1, 0));
+        assert!(!uf.connected(1, 2));
+        uf.union(1, 3);
+        assert!(uf.connected(0, 2));
+        assert!(!uf.connected(4, 5));
+        assert!(uf.connected(5, 5));
+    }
 }
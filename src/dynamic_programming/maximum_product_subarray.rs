@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+
+/// max_product_subarray(nums) finds the subarray (containing at least one number) which has the
+/// largest product and returns its product.
+///
+/// A subarray is a contiguous part of an array.
+///
+/// Unlike for sums, the best subarray ending at some position does not simply extend the best
+/// one ending right before it: a negative number turns the smallest product into the largest.
+/// So both the largest and the smallest products of subarrays ending at each position are kept.
+///
+/// The running products are kept as `i128` and saturate instead of overflowing, so the
+/// smallest products can grow far below `i32::MIN` without a panic. A saturated product
+/// keeps its sign and lies far outside `i32`. It panics if the largest product does not
+/// fit in an `i32`.
+///
+/// Arguments:
+///     * `nums` - a non-empty integer array
+/// Complexity
+///     - time complexity: O(nums.length),
+///     - space complexity: O(1),
+pub fn max_product_subarray(nums: &[i32]) -> i32 {
+    assert!(!nums.is_empty(), "nums must not be empty");
+
+    let mut max_ending_here = nums[0] as i128;
+    let mut min_ending_here = nums[0] as i128;
+    let mut result = nums[0] as i128;
+
+    for &num in &nums[1..] {
+        let num = num as i128;
+        let candidates = [
+            num,
+            max_ending_here.saturating_mul(num),
+            min_ending_here.saturating_mul(num),
+        ];
+        max_ending_here = *candidates.iter().max().unwrap();
+        min_ending_here = *candidates.iter().min().unwrap();
+        result = result.max(max_ending_here);
+    }
+
+    i32::try_from(result).expect("the largest product does not fit in an i32")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_signs() {
+        //the maximum value: 2 * 3 = 6
+        assert_eq!(max_product_subarray(&[2, 3, -2, 4]), 6);
+    }
+
+    #[test]
+    fn with_zero() {
+        //the maximum value: 0, as -2 and -1 are separated by a zero
+        assert_eq!(max_product_subarray(&[-2, 0, -1]), 0);
+    }
+
+    #[test]
+    fn all_negative() {
+        //the maximum value: (-2) * (-3) * (-4) * (-1) = 24
+        assert_eq!(max_product_subarray(&[-2, -3, -4, -1]), 24);
+        //an odd count drops the smallest end: (-3) * (-4) = 12
+        assert_eq!(max_product_subarray(&[-2, -3, -4]), 12);
+    }
+
+    #[test]
+    fn two_negatives_far_apart() {
+        //the maximum value: (-2) * 3 * 4 * (-1) * 5 = 120
+        assert_eq!(max_product_subarray(&[-2, 3, 4, -1, 5, 0, 7]), 120);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(max_product_subarray(&[6]), 6);
+        assert_eq!(max_product_subarray(&[-6]), -6);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = crate::math::PCG32::new_default(19);
+        for len in 1..16 {
+            let nums: Vec<i32> = (0..len).map(|_| (rng.get_u32() % 7) as i32 - 3).collect();
+            let brute = (0..len)
+                .flat_map(|l| (l..len).map(move |r| (l, r)))
+                .map(|(l, r)| nums[l..=r].iter().map(|&n| n as i64).product::<i64>())
+                .max()
+                .unwrap();
+            assert_eq!(max_product_subarray(&nums) as i64, brute, "{:?}", nums);
+        }
+    }
+
+    #[test]
+    fn huge_intermediate_products() {
+        //the smallest product i32::MAX * i32::MIN * i32::MAX overflows even an i64, but the
+        //maximum value is a single element: i32::MAX
+        assert_eq!(
+            max_product_subarray(&[i32::MAX, i32::MIN, i32::MAX, 0, 3]),
+            i32::MAX
+        );
+        //the maximum value: 46340 * 46340 = 2147395600, just below i32::MAX
+        assert_eq!(max_product_subarray(&[-3, 46340, 46340, 0, -1]), 2147395600);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in an i32")]
+    fn answer_beyond_i32() {
+        //the maximum value: i32::MAX * i32::MAX = 4611686014132420609, too large for an i32
+        max_product_subarray(&[i32::MAX, i32::MAX, i32::MIN]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in an i32")]
+    fn answer_beyond_i128() {
+        //the products saturate at i128::MAX
+        max_product_subarray(&[i32::MIN; 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be empty")]
+    fn empty() {
+        max_product_subarray(&[]);
+    }
+}
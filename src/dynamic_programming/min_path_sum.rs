@@ -0,0 +1,188 @@
+/// min_path_sum(grid) returns the smallest sum of the cells on a path from the top-left
+/// cell to the bottom-right cell of `grid`, moving only right or down.
+///
+/// The cheapest way to reach a cell comes either from the cell above it or from the cell to
+/// its left, so the costs are filled in row by row, keeping only the row before.
+///
+/// Arguments:
+///     * `grid` - a non-empty rectangular grid of costs
+/// Complexity
+///     - time complexity: O(rows * columns),
+///     - space complexity: O(columns),
+pub fn min_path_sum(grid: &[Vec<i32>]) -> i32 {
+    let columns = check_grid(grid);
+
+    let mut dp = vec![0; columns];
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cost) in row.iter().enumerate() {
+            dp[j] = cost
+                + match (i, j) {
+                    (0, 0) => 0,
+                    (0, _) => dp[j - 1],
+                    (_, 0) => dp[j],
+                    _ => dp[j].min(dp[j - 1]),
+                };
+        }
+    }
+
+    dp[columns - 1]
+}
+
+/// min_path_sum_path(grid) returns the cells, as `(row, column)` pairs, of a path with the
+/// smallest sum from the top-left cell to the bottom-right cell of `grid`, moving only right
+/// or down.
+///
+/// The whole table of costs is kept, and the path is walked back from the bottom-right cell
+/// by always stepping to the cheaper of the cells above and to the left. On a tie it steps
+/// up, so when all cells cost the same, the path runs along the top row and then down the
+/// last column.
+///
+/// Arguments:
+///     * `grid` - a non-empty rectangular grid of costs
+/// Complexity
+///     - time complexity: O(rows * columns),
+///     - space complexity: O(rows * columns),
+pub fn min_path_sum_path(grid: &[Vec<i32>]) -> Vec<(usize, usize)> {
+    let columns = check_grid(grid);
+    let rows = grid.len();
+
+    let mut dp = vec![vec![0; columns]; rows];
+    for i in 0..rows {
+        for j in 0..columns {
+            dp[i][j] = grid[i][j]
+                + match (i, j) {
+                    (0, 0) => 0,
+                    (0, _) => dp[i][j - 1],
+                    (_, 0) => dp[i - 1][j],
+                    _ => dp[i - 1][j].min(dp[i][j - 1]),
+                };
+        }
+    }
+
+    let (mut i, mut j) = (rows - 1, columns - 1);
+    let mut path = vec![(i, j)];
+    while (i, j) != (0, 0) {
+        if j == 0 || (i > 0 && dp[i - 1][j] <= dp[i][j - 1]) {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+        path.push((i, j));
+    }
+    path.reverse();
+
+    path
+}
+
+/// Returns the number of columns of `grid`, panicking unless it is non-empty and rectangular.
+fn check_grid(grid: &[Vec<i32>]) -> usize {
+    assert!(
+        !grid.is_empty() && !grid[0].is_empty(),
+        "grid must not be empty"
+    );
+    let columns = grid[0].len();
+    assert!(
+        grid.iter().all(|row| row.len() == columns),
+        "grid rows must all have the same length"
+    );
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_sum(grid: &[Vec<i32>], path: &[(usize, usize)]) -> i32 {
+        path.iter().map(|&(i, j)| grid[i][j]).sum()
+    }
+
+    #[test]
+    fn small_grid() {
+        let grid = vec![vec![1, 3, 1], vec![1, 5, 1], vec![4, 2, 1]];
+        //the minimum sum: 1 + 3 + 1 + 1 + 1 = 7
+        assert_eq!(min_path_sum(&grid), 7);
+        assert_eq!(
+            min_path_sum_path(&grid),
+            vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn single_cell() {
+        assert_eq!(min_path_sum(&[vec![5]]), 5);
+        assert_eq!(min_path_sum_path(&[vec![5]]), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn single_row_and_column() {
+        let row = vec![vec![1, 2, 3]];
+        assert_eq!(min_path_sum(&row), 6);
+        assert_eq!(min_path_sum_path(&row), vec![(0, 0), (0, 1), (0, 2)]);
+
+        let column = vec![vec![1], vec![2], vec![3]];
+        assert_eq!(min_path_sum(&column), 6);
+        assert_eq!(min_path_sum_path(&column), vec![(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn ties_step_up_first() {
+        assert_eq!(
+            min_path_sum_path(&[vec![0, 0], vec![0, 0]]),
+            vec![(0, 0), (0, 1), (1, 1)]
+        );
+        let grid = vec![vec![1; 3]; 3];
+        assert_eq!(min_path_sum(&grid), 5);
+        assert_eq!(
+            min_path_sum_path(&grid),
+            vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn negative_costs() {
+        let grid = vec![vec![1, -4, 2], vec![3, 0, -5], vec![-1, 2, 1]];
+        //the minimum sum: 1 + (-4) + 0 + (-5) + 1 = -7
+        assert_eq!(min_path_sum(&grid), -7);
+        assert_eq!(
+            min_path_sum_path(&grid),
+            vec![(0, 0), (0, 1), (1, 1), (1, 2), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn path_matches_sum() {
+        let mut rng = crate::math::PCG32::new_default(11);
+        for _ in 0..100 {
+            let rows = 1 + rng.get_u32() as usize % 6;
+            let columns = 1 + rng.get_u32() as usize % 6;
+            let grid: Vec<Vec<i32>> = (0..rows)
+                .map(|_| {
+                    (0..columns)
+                        .map(|_| (rng.get_u32() % 21) as i32 - 10)
+                        .collect()
+                })
+                .collect();
+
+            let path = min_path_sum_path(&grid);
+            assert_eq!(path.len(), rows + columns - 1);
+            assert_eq!(path[0], (0, 0));
+            for step in path.windows(2) {
+                let (a, b) = (step[0], step[1]);
+                assert!(b == (a.0 + 1, a.1) || b == (a.0, a.1 + 1));
+            }
+            assert_eq!(path_sum(&grid, &path), min_path_sum(&grid));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "grid must not be empty")]
+    fn empty_grid() {
+        min_path_sum(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn ragged_grid() {
+        min_path_sum_path(&[vec![1, 2], vec![3]]);
+    }
+}
@@ -9,7 +9,10 @@ mod knapsack;
 mod longest_common_subsequence;
 mod longest_continuous_increasing_subsequence;
 mod longest_increasing_subsequence;
+mod maximum_product_subarray;
+mod min_path_sum;
 mod rod_cutting;
+mod word_break;
 
 pub use self::coin_change::coin_change;
 pub use self::coin_problem::coin_problem;
@@ -22,5 +25,8 @@ pub use self::knapsack::knapsack;
 pub use self::longest_common_subsequence::longest_common_subsequence;
 pub use self::longest_continuous_increasing_subsequence::longest_continuous_increasing_subsequence;
 pub use self::longest_increasing_subsequence::longest_increasing_subsequence;
+pub use self::maximum_product_subarray::max_product_subarray;
+pub use self::min_path_sum::{min_path_sum, min_path_sum_path};
 pub use self::rod_cutting::rod_cutting;
 pub use self::rod_cutting::rod_cutting_recursive;
+pub use self::word_break::{word_break, word_break_all};
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+// Given a string s and a dictionary of words, return true if s can be segmented
+// into a space-separated sequence of one or more dictionary words.
+// The same dictionary word may be reused several times in the segmentation.
+//
+// breakable[i] tells whether the prefix made of the first i characters of s
+// can be segmented: it can if some shorter segmentable prefix is followed by a
+// dictionary word that ends exactly at i. The empty prefix is segmentable.
+// This takes O(n^2) substring lookups for a string of n characters.
+pub fn word_break(s: &str, dictionary: &HashSet<String>) -> bool {
+    let bounds = char_bounds(s);
+    let breakable = breakable_prefixes(s, &bounds, dictionary);
+    breakable[bounds.len() - 1]
+}
+
+// Returns every way to segment s into dictionary words, as sentences with the
+// words separated by single spaces, sorted.
+//
+// Along with the segmentable prefixes, the DP remembers, for every prefix end,
+// where the dictionary words ending there can start. Walking these links back
+// from the end of s then only follows paths that reach its beginning.
+// The empty string has a single segmentation, the empty sentence.
+pub fn word_break_all(s: &str, dictionary: &HashSet<String>) -> Vec<String> {
+    let bounds = char_bounds(s);
+    let breakable = breakable_prefixes(s, &bounds, dictionary);
+
+    // starts[end] lists the prefixes that a dictionary word extends up to end
+    let mut starts: Vec<Vec<usize>> = vec![Vec::new(); bounds.len()];
+    for end in 1..bounds.len() {
+        for start in 0..end {
+            if breakable[start] && dictionary.contains(&s[bounds[start]..bounds[end]]) {
+                starts[end].push(start);
+            }
+        }
+    }
+
+    let mut sentences = Vec::new();
+    if breakable[bounds.len() - 1] {
+        let mut words = Vec::new();
+        collect_sentences(
+            s,
+            &bounds,
+            &starts,
+            bounds.len() - 1,
+            &mut words,
+            &mut sentences,
+        );
+    }
+    sentences.sort_unstable();
+    sentences
+}
+
+// The byte offsets at which the characters of s start, followed by the length of s.
+fn char_bounds(s: &str) -> Vec<usize> {
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect()
+}
+
+fn breakable_prefixes(s: &str, bounds: &[usize], dictionary: &HashSet<String>) -> Vec<bool> {
+    let mut breakable = vec![false; bounds.len()];
+    breakable[0] = true;
+    for end in 1..bounds.len() {
+        breakable[end] = (0..end)
+            .any(|start| breakable[start] && dictionary.contains(&s[bounds[start]..bounds[end]]));
+    }
+    breakable
+}
+
+// Segments the prefix ending at end, given the words already chosen after it
+// in reverse order.
+fn collect_sentences<'a>(
+    s: &'a str,
+    bounds: &[usize],
+    starts: &[Vec<usize>],
+    end: usize,
+    words: &mut Vec<&'a str>,
+    sentences: &mut Vec<String>,
+) {
+    if end == 0 {
+        let sentence: Vec<&str> = words.iter().rev().copied().collect();
+        sentences.push(sentence.join(" "));
+        return;
+    }
+    for &start in starts[end].iter() {
+        words.push(&s[bounds[start]..bounds[end]]);
+        collect_sentences(s, bounds, starts, start, words, sentences);
+        words.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary(words: &[&str]) -> HashSet<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn leetcode() {
+        let dict = dictionary(&["leet", "code"]);
+        assert!(word_break("leetcode", &dict));
+        assert_eq!(word_break_all("leetcode", &dict), vec!["leet code"]);
+    }
+
+    #[test]
+    fn reused_words() {
+        let dict = dictionary(&["apple", "pen"]);
+        assert!(word_break("applepenapple", &dict));
+        assert_eq!(
+            word_break_all("applepenapple", &dict),
+            vec!["apple pen apple"]
+        );
+    }
+
+    #[test]
+    fn not_breakable() {
+        let dict = dictionary(&["cats", "dog", "sand", "and", "cat"]);
+        assert!(!word_break("catsandog", &dict));
+        assert!(word_break_all("catsandog", &dict).is_empty());
+        assert!(!word_break("leetcode", &dictionary(&[])));
+    }
+
+    #[test]
+    fn cats_and_dog() {
+        let dict = dictionary(&["cat", "cats", "and", "sand", "dog"]);
+        assert!(word_break("catsanddog", &dict));
+        assert_eq!(
+            word_break_all("catsanddog", &dict),
+            vec!["cat sand dog", "cats and dog"]
+        );
+    }
+
+    #[test]
+    fn pineapple() {
+        let dict = dictionary(&["apple", "pen", "applepen", "pine", "pineapple"]);
+        assert_eq!(
+            word_break_all("pineapplepenapple", &dict),
+            vec![
+                "pine apple pen apple",
+                "pine applepen apple",
+                "pineapple pen apple"
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_string() {
+        let dict = dictionary(&["a"]);
+        assert!(word_break("", &dict));
+        assert_eq!(word_break_all("", &dict), vec![""]);
+    }
+
+    #[test]
+    fn many_segmentations() {
+        let dict = dictionary(&["a", "aa", "aaa"]);
+        // compositions of 5 into parts of size at most 3
+        assert_eq!(word_break_all("aaaaa", &dict).len(), 13);
+        assert!(!word_break("aaaaab", &dict));
+        assert!(word_break_all("aaaaab", &dict).is_empty());
+    }
+
+    #[test]
+    fn unicode() {
+        let dict = dictionary(&["日本", "語", "日", "本語"]);
+        assert_eq!(word_break_all("日本語", &dict), vec!["日 本語", "日本 語"]);
+    }
+}
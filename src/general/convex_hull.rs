@@ -64,6 +64,37 @@ pub fn convex_hull_graham(pts: &[(f64, f64)]) -> Vec<(f64, f64)> {
     stack
 }
 
+/*
+    Computes the lower convex hull with the lower half of Andrew's monotone chain.
+
+    Points are sorted by x-coordinate and, among points sharing an x-coordinate, only the
+    lowest one is kept since the others lie above it. A point is then popped whenever it
+    does not make a strict left turn with its neighbours, so aligned points are dropped.
+
+    The hull is given from left to right, from the lowest leftmost point to the lowest
+    rightmost point. Its slopes are strictly increasing, which is what the convex hull
+    trick of slope-optimization DPs relies on.
+*/
+pub fn lower_hull(pts: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut points = pts.to_vec();
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
+    // sorted by y within a column, so the first point of each column is its lowest one
+    points.dedup_by(|b, a| a.0 == b.0);
+
+    let mut hull: Vec<(f64, f64)> = vec![];
+    for point in points {
+        while hull.len() > 1
+            && calc_z_coord_vector_product(&hull[hull.len() - 2], &hull[hull.len() - 1], &point)
+                <= 0.
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    hull
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,4 +202,78 @@ mod tests {
 
         assert_eq!(convex_hull_graham(&list), ans);
     }
+
+    #[test]
+    fn lower_hull_empty() {
+        assert_eq!(lower_hull(&[]), vec![]);
+        assert_eq!(lower_hull(&[(1., 2.)]), vec![(1., 2.)]);
+    }
+
+    #[test]
+    fn lower_hull_differs_from_upper_hull() {
+        // a pentagon with a peak at (2, 4) and a valley at (2, -1), plus inner points
+        let list = vec![
+            (2., 4.),
+            (0., 0.),
+            (4., 0.),
+            (2., -1.),
+            (1., 1.),
+            (3., 2.),
+            (2., 1.),
+            (1., 3.),
+        ];
+        assert_eq!(lower_hull(&list), vec![(0., 0.), (2., -1.), (4., 0.)]);
+    }
+
+    #[test]
+    fn lower_hull_is_part_of_convex_hull() {
+        let list = vec![
+            (4.4, 14.),
+            (6.7, 15.25),
+            (6.9, 12.8),
+            (2.1, 11.1),
+            (9.5, 14.9),
+            (13.2, 11.9),
+            (10.3, 12.3),
+            (6.8, 9.5),
+            (3.3, 7.7),
+            (0.6, 5.1),
+            (5.3, 2.4),
+            (8.45, 4.7),
+            (11.5, 9.6),
+            (13.8, 7.3),
+            (12.9, 3.1),
+            (11., 1.1),
+        ];
+        let ans = vec![(0.6, 5.1), (5.3, 2.4), (11., 1.1), (12.9, 3.1), (13.8, 7.3)];
+
+        let hull = lower_hull(&list);
+        assert_eq!(hull, ans);
+        let full = convex_hull_graham(&list);
+        assert!(hull.iter().all(|p| full.contains(p)));
+    }
+
+    #[test]
+    fn lower_hull_drops_aligned_and_vertical_points() {
+        let list = vec![
+            (0., 5.),
+            (0., 0.),
+            (1., 1.),
+            (2., 2.),
+            (3., 3.),
+            (3., 7.),
+            (1., 4.),
+        ];
+        assert_eq!(lower_hull(&list), vec![(0., 0.), (3., 3.)]);
+    }
+
+    #[test]
+    fn lower_hull_slopes_increase() {
+        // a convex parabola keeps every point, a concave one only its two ends
+        let convex: Vec<(f64, f64)> = (-5..=5).map(|x| (x as f64, (x * x) as f64)).collect();
+        assert_eq!(lower_hull(&convex), convex);
+
+        let concave: Vec<(f64, f64)> = (-5..=5).map(|x| (x as f64, -(x * x) as f64)).collect();
+        assert_eq!(lower_hull(&concave), vec![(-5., -25.), (5., -25.)]);
+    }
 }
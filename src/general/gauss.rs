@@ -0,0 +1,147 @@
+/// Solves the linear system `a * x = b` with Gaussian elimination and partial pivoting.
+///
+/// The rows of `a` are reduced to an upper triangular matrix, each column taking as pivot
+/// the row with the largest absolute value in it, which keeps the rounding errors small.
+/// The solution is then found by back substitution, in O(n^3) time overall.
+///
+/// Returns `None` if the system is singular, that is, if it has no solution or infinitely
+/// many. A pivot is considered zero when it is negligible next to the largest coefficient
+/// of `a`.
+///
+/// # Panics
+///
+/// Panics if `a` is not square or `b` does not have one value per row of `a`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::general::solve_linear_system;
+///
+/// // x + y = 3 and x - y = 1
+/// let a = vec![vec![1.0, 1.0], vec![1.0, -1.0]];
+/// let x = solve_linear_system(&a, &[3.0, 1.0]).unwrap();
+///
+/// assert!((x[0] - 2.0).abs() < 1e-12);
+/// assert!((x[1] - 1.0).abs() < 1e-12);
+/// ```
+pub fn solve_linear_system(a: &[Vec<f64>], b: &[f64]) -> Option<Vec<f64>> {
+    let n = a.len();
+    assert!(a.iter().all(|row| row.len() == n), "matrix must be square");
+    assert_eq!(b.len(), n, "right-hand side must have one value per row");
+
+    // the augmented matrix [a | b]
+    let mut m: Vec<Vec<f64>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| row.iter().copied().chain(Some(rhs)).collect())
+        .collect();
+
+    let scale = a.iter().flatten().fold(0.0, |acc: f64, v| acc.max(v.abs()));
+    let tolerance = scale * n as f64 * f64::EPSILON;
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| m[i][col].abs().total_cmp(&m[j][col].abs()))?;
+        if m[pivot][col].abs() <= tolerance {
+            return None;
+        }
+        m.swap(col, pivot);
+
+        let (upper, lower) = m.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            if factor == 0.0 {
+                continue;
+            }
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| m[row][k] * x[k]).sum();
+        x[row] = (m[row][n] - known) / m[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn three_by_three() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+        let a = vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ];
+        let x = solve_linear_system(&a, &[8.0, -11.0, -3.0]).unwrap();
+        assert_close(&x, &[2.0, 3.0, -1.0]);
+    }
+
+    #[test]
+    fn needs_pivoting() {
+        // the first coefficient is zero, so the rows have to be swapped
+        let a = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, 1.0, 1.0],
+            vec![3.0, 0.0, 2.0],
+        ];
+        let x = solve_linear_system(&a, &[7.0, 6.0, 9.0]).unwrap();
+        assert_close(&x, &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn singular() {
+        // the third row is the sum of the first two
+        let a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![5.0, 7.0, 9.0],
+        ];
+        assert_eq!(solve_linear_system(&a, &[1.0, 2.0, 3.0]), None);
+        assert_eq!(solve_linear_system(&a, &[1.0, 2.0, 4.0]), None);
+
+        let zero = vec![vec![0.0, 0.0], vec![0.0, 0.0]];
+        assert_eq!(solve_linear_system(&zero, &[0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn random_systems() {
+        let mut rng = crate::math::PCG32::new_default(37);
+        let mut value = || (rng.get_u32() % 2001) as f64 / 100.0 - 10.0;
+        for n in 1..8 {
+            let a: Vec<Vec<f64>> = (0..n).map(|_| (0..n).map(|_| value()).collect()).collect();
+            let expected: Vec<f64> = (0..n).map(|_| value()).collect();
+            let b: Vec<f64> = a
+                .iter()
+                .map(|row| row.iter().zip(&expected).map(|(c, x)| c * x).sum())
+                .collect();
+
+            let x = solve_linear_system(&a, &b).unwrap();
+            assert_close(&x, &expected);
+        }
+    }
+
+    #[test]
+    fn empty_system() {
+        assert_eq!(solve_linear_system(&[], &[]), Some(vec![]));
+    }
+
+    #[test]
+    #[should_panic(expected = "square")]
+    fn not_square() {
+        solve_linear_system(&[vec![1.0, 2.0]], &[1.0]);
+    }
+}
@@ -6,6 +6,39 @@ pub fn hanoi(n: i32, from: i32, to: i32, via: i32, moves: &mut Vec<(i32, i32)>)
     }
 }
 
+/// Returns the minimum number of moves needed to transfer `disks` disks using `pegs` pegs,
+/// following the Frame-Stewart algorithm.
+///
+/// The top `k` disks are moved to an intermediate peg using every peg, the remaining
+/// `disks - k` are moved to the target using one peg less, and the top `k` are moved back
+/// on top of them. The best `k` is found by trying all of them, bottom-up over the peg count.
+///
+/// Panics if `pegs < 3`.
+pub fn hanoi_frame_stewart(disks: usize, pegs: usize) -> usize {
+    assert!(
+        pegs >= 3,
+        "Frame-Stewart needs at least 3 pegs, got {}",
+        pegs
+    );
+
+    // moves[n] holds the answer for n disks with the peg count of the current iteration.
+    let mut moves = vec![0usize; disks + 1];
+    for n in 1..=disks {
+        moves[n] = moves[n - 1].saturating_mul(2).saturating_add(1);
+    }
+
+    for _ in 4..=pegs {
+        let fewer_pegs = moves.clone();
+        for n in 1..=disks {
+            moves[n] = (1..n)
+                .map(|k| moves[k].saturating_mul(2).saturating_add(fewer_pegs[n - k]))
+                .fold(fewer_pegs[n], usize::min);
+        }
+    }
+
+    moves[disks]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -18,4 +51,40 @@ mod tests {
         hanoi(3, 1, 3, 2, &mut our_solution);
         assert_eq!(correct_solution, our_solution);
     }
+
+    #[test]
+    fn frame_stewart_three_pegs() {
+        for n in 0..20 {
+            assert_eq!(hanoi_frame_stewart(n, 3), (1 << n) - 1);
+        }
+    }
+
+    #[test]
+    fn frame_stewart_four_pegs() {
+        let expected = [0, 1, 3, 5, 9, 13, 17, 25, 33, 41, 49, 65, 81, 97, 113, 129];
+        for (n, &moves) in expected.iter().enumerate() {
+            assert_eq!(hanoi_frame_stewart(n, 4), moves);
+        }
+    }
+
+    #[test]
+    fn frame_stewart_five_pegs() {
+        let expected = [0, 1, 3, 5, 7, 11, 15, 19, 23, 27, 31, 39, 47];
+        for (n, &moves) in expected.iter().enumerate() {
+            assert_eq!(hanoi_frame_stewart(n, 5), moves);
+        }
+    }
+
+    #[test]
+    fn frame_stewart_matches_move_list() {
+        let mut moves = Vec::new();
+        hanoi(6, 1, 3, 2, &mut moves);
+        assert_eq!(hanoi_frame_stewart(6, 3), moves.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn frame_stewart_too_few_pegs() {
+        hanoi_frame_stewart(3, 2);
+    }
 }
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Finds the element that appears more than `n / 2` times in `nums`, if there is one.
+///
+/// This is the Boyer-Moore majority vote algorithm. A candidate is kept together with a
+/// counter: an element equal to the candidate increments the counter and any other element
+/// decrements it, and the next element becomes the candidate when the counter is zero.
+/// Pairing up the occurrences of two different values cancels both out, and a strict
+/// majority has too many occurrences to be cancelled completely, so it is the candidate
+/// left at the end.
+///
+/// If there is no strict majority the candidate can be any value, so a second pass counts
+/// its occurrences. This takes O(n) time and O(1) extra space.
+pub fn majority_element<T: PartialEq + Clone>(nums: &[T]) -> Option<T> {
+    let mut candidate: Option<&T> = None;
+    let mut votes = 0usize;
+
+    for num in nums {
+        if votes == 0 {
+            candidate = Some(num);
+            votes = 1;
+        } else if candidate == Some(num) {
+            votes += 1;
+        } else {
+            votes -= 1;
+        }
+    }
+
+    let candidate = candidate?;
+    let occurrences = nums.iter().filter(|&num| num == candidate).count();
+    if occurrences > nums.len() / 2 {
+        Some(candidate.clone())
+    } else {
+        None
+    }
+}
+
+/// Finds every element that appears more than `n / k` times in `nums`, in the order of
+/// their first appearance. There are at most `k - 1` of them.
+///
+/// This generalizes the Boyer-Moore majority vote (it is the Misra-Gries algorithm): up to
+/// `k - 1` candidates are kept with a counter each. An element that is a candidate
+/// increments its counter, another element becomes a candidate if there is room for it,
+/// and otherwise every counter is decremented, dropping the candidates that reach zero.
+/// Each decrement cancels out `k` occurrences of different values, which can happen at
+/// most `n / k` times, so every element appearing more than `n / k` times is still a
+/// candidate at the end.
+///
+/// A second pass counts the occurrences of the candidates and keeps those that really
+/// appear often enough. This takes O(n * k) time and O(k) extra space.
+///
+/// # Panics
+///
+/// Panics if `k` is 0.
+pub fn elements_over_n_div_k<T: Eq + Hash + Clone>(nums: &[T], k: usize) -> Vec<T> {
+    assert!(k > 0, "k must be positive");
+    let mut candidates: HashMap<&T, usize> = HashMap::new();
+
+    for num in nums {
+        if let Some(votes) = candidates.get_mut(num) {
+            *votes += 1;
+        } else if candidates.len() < k - 1 {
+            candidates.insert(num, 1);
+        } else {
+            candidates.retain(|_, votes| {
+                *votes -= 1;
+                *votes > 0
+            });
+        }
+    }
+
+    let mut occurrences: HashMap<&T, usize> = candidates.keys().map(|&num| (num, 0)).collect();
+    for num in nums {
+        if let Some(count) = occurrences.get_mut(num) {
+            *count += 1;
+        }
+    }
+
+    let mut result = Vec::new();
+    for num in nums {
+        if occurrences
+            .get(num)
+            .is_some_and(|&count| count > nums.len() / k)
+        {
+            occurrences.remove(num);
+            result.push(num.clone());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_majority() {
+        assert_eq!(majority_element(&[2, 2, 1, 1, 1, 2, 2]), Some(2));
+        assert_eq!(majority_element(&[3, 1, 3, 2, 3]), Some(3));
+        assert_eq!(majority_element(&["a", "b", "a"]), Some("a"));
+    }
+
+    #[test]
+    fn majority_at_the_end() {
+        // the candidate changes several times before the majority takes over
+        assert_eq!(majority_element(&[1, 2, 3, 4, 5, 5, 5, 5, 5]), Some(5));
+    }
+
+    #[test]
+    fn exact_half_is_not_a_majority() {
+        assert_eq!(majority_element(&[1, 2, 1, 2]), None);
+        assert_eq!(majority_element(&[7, 7, 7, 1, 2, 3]), None);
+    }
+
+    #[test]
+    fn no_majority() {
+        assert_eq!(majority_element(&[1, 2, 3]), None);
+        // the last element is left as the candidate but appears only once
+        assert_eq!(majority_element(&[1, 1, 2, 2, 3]), None);
+    }
+
+    #[test]
+    fn empty_and_single() {
+        assert_eq!(majority_element::<i32>(&[]), None);
+        assert_eq!(majority_element(&[42]), Some(42));
+    }
+
+    #[test]
+    fn owned_values() {
+        let words: Vec<String> = "to be or not to be to"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        assert_eq!(majority_element(&words), None);
+
+        let words: Vec<String> = vec!["x".into(), "y".into(), "x".into()];
+        assert_eq!(majority_element(&words), Some("x".to_string()));
+    }
+
+    #[test]
+    fn over_a_third() {
+        assert_eq!(elements_over_n_div_k(&[3, 2, 3], 3), vec![3]);
+        assert_eq!(
+            elements_over_n_div_k(&[1, 1, 1, 3, 3, 2, 2, 2, 3], 3),
+            Vec::<i32>::new()
+        );
+        assert_eq!(
+            elements_over_n_div_k(&[1, 2, 2, 3, 2, 1, 1, 3], 3),
+            vec![1, 2]
+        );
+        assert_eq!(
+            elements_over_n_div_k(&["b", "a", "a", "b", "c"], 3),
+            vec!["b", "a"]
+        );
+    }
+
+    #[test]
+    fn none_over_a_third() {
+        assert!(elements_over_n_div_k(&[1, 2, 3, 4, 5, 6], 3).is_empty());
+        // exactly n / k occurrences are not enough
+        assert!(elements_over_n_div_k(&[1, 1, 2, 2, 3, 3], 3).is_empty());
+        assert!(elements_over_n_div_k::<i32>(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn over_half_matches_majority() {
+        let cases: [&[i32]; 4] = [&[2, 2, 1, 1, 1, 2, 2], &[1, 2, 1, 2], &[5], &[1, 2, 3, 3]];
+        for nums in cases.iter() {
+            let expected: Vec<i32> = majority_element(nums).into_iter().collect();
+            assert_eq!(elements_over_n_div_k(nums, 2), expected);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = crate::math::PCG32::new_default(99);
+        for k in 1..7 {
+            for _ in 0..20 {
+                let len = (rng.get_u32() % 40) as usize;
+                let nums: Vec<u32> = (0..len).map(|_| rng.get_u32() % 5).collect();
+
+                let mut expected: Vec<u32> = Vec::new();
+                for num in nums.iter() {
+                    let count = nums.iter().filter(|&x| x == num).count();
+                    if count > len / k && !expected.contains(num) {
+                        expected.push(*num);
+                    }
+                }
+                assert_eq!(elements_over_n_div_k(&nums, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "k must be positive")]
+    fn zero_k() {
+        elements_over_n_div_k(&[1], 0);
+    }
+}
@@ -1,16 +1,24 @@
 //! This module provides a variety of operations.
 mod convex_hull;
+mod gauss;
 mod graph_coloring;
 mod hanoi;
 mod huffman_encoding;
 mod kmeans;
+mod majority_element;
 mod nqueens;
+mod rng;
+mod shuffle;
 mod two_sum;
 
-pub use self::convex_hull::convex_hull_graham;
-pub use self::graph_coloring::color_graph;
-pub use self::hanoi::hanoi;
+pub use self::convex_hull::{convex_hull_graham, lower_hull};
+pub use self::gauss::solve_linear_system;
+pub use self::graph_coloring::{color_graph, color_graph_dsatur};
+pub use self::hanoi::{hanoi, hanoi_frame_stewart};
 pub use self::huffman_encoding::HuffmanDictionary;
 pub use self::kmeans::{f32, f64};
+pub use self::majority_element::{elements_over_n_div_k, majority_element};
 pub use self::nqueens::nqueens;
-pub use self::two_sum::two_sum;
+pub use self::rng::SplitMix64;
+pub use self::shuffle::shuffle;
+pub use self::two_sum::{has_pair_with_sum, two_sum};
@@ -0,0 +1,90 @@
+use super::SplitMix64;
+
+/// Shuffles `slice` in place with the Fisher-Yates algorithm.
+///
+/// Random indices come from a [`SplitMix64`] generator seeded with `seed`, so the
+/// same seed always yields the same permutation. This makes the shuffle handy
+/// for reproducible test data and randomized algorithms.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::general::shuffle;
+///
+/// let mut first = [1, 2, 3, 4, 5];
+/// let mut second = [1, 2, 3, 4, 5];
+/// shuffle(&mut first, 42);
+/// shuffle(&mut second, 42);
+///
+/// assert_eq!(first, second);
+/// ```
+pub fn shuffle<T>(slice: &mut [T], seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    for i in (1..slice.len()).rev() {
+        // pick uniformly among the first i + 1 positions
+        let j = rng.gen_range(0, i as u64 + 1) as usize;
+        slice.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_permutation() {
+        let mut first: Vec<u32> = (0..100).collect();
+        let mut second = first.clone();
+        shuffle(&mut first, 2024);
+        shuffle(&mut second, 2024);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        let mut first: Vec<u32> = (0..100).collect();
+        let mut second = first.clone();
+        shuffle(&mut first, 1);
+        shuffle(&mut second, 2);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn is_permutation() {
+        let original: Vec<u32> = (0..1000).map(|i| i % 37).collect();
+        let mut shuffled = original.clone();
+        shuffle(&mut shuffled, 7);
+        assert_ne!(shuffled, original);
+
+        let mut sorted_original = original;
+        let mut sorted_shuffled = shuffled;
+        sorted_original.sort_unstable();
+        sorted_shuffled.sort_unstable();
+        assert_eq!(sorted_original, sorted_shuffled);
+    }
+
+    #[test]
+    fn permutations_are_equally_likely() {
+        let mut counts = std::collections::HashMap::new();
+        for seed in 0..6000 {
+            let mut items = ['a', 'b', 'c'];
+            shuffle(&mut items, seed);
+            *counts.entry(items).or_insert(0) += 1;
+        }
+
+        // each of the 6 permutations is expected about 1000 times
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|&count| (850..1150).contains(&count)));
+    }
+
+    #[test]
+    fn small_slices() {
+        let mut empty: [u8; 0] = [];
+        shuffle(&mut empty, 3);
+        assert_eq!(empty, []);
+
+        let mut single = ["a"];
+        shuffle(&mut single, 3);
+        assert_eq!(single, ["a"]);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 
 // Given an array of integers nums and an integer target,
@@ -21,6 +21,25 @@ pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
     vec![]
 }
 
+// Returns whether two numbers at different positions of nums add up to target.
+// It only remembers the values seen so far, and stops at the first pair found.
+
+pub fn has_pair_with_sum(nums: &[i32], target: i32) -> bool {
+    let mut seen: HashSet<i32> = HashSet::new();
+
+    for item in nums {
+        // if the complement does not fit in an i32, no value seen can be it
+        if let Some(complement) = target.checked_sub(*item) {
+            if seen.contains(&complement) {
+                return true;
+            }
+        }
+        seen.insert(*item);
+    }
+
+    false
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -36,4 +55,33 @@ mod test {
         let nums = vec![3, 3];
         assert_eq!(two_sum(nums, 6), vec![1, 0]);
     }
+
+    #[test]
+    fn pair_present() {
+        assert!(has_pair_with_sum(&[2, 7, 11, 15], 9));
+        assert!(has_pair_with_sum(&[3, 2, 4], 6));
+        assert!(has_pair_with_sum(&[3, 3], 6));
+        assert!(has_pair_with_sum(&[-5, 10, 4, -1], -6));
+    }
+
+    #[test]
+    fn pair_absent() {
+        assert!(!has_pair_with_sum(&[2, 7, 11, 15], 10));
+        // a single 3 cannot be used twice
+        assert!(!has_pair_with_sum(&[3, 2, 5], 6));
+        assert!(!has_pair_with_sum(&[], 0));
+    }
+
+    #[test]
+    fn pair_single_element() {
+        assert!(!has_pair_with_sum(&[4], 8));
+        assert!(!has_pair_with_sum(&[4], 4));
+    }
+
+    #[test]
+    fn pair_without_overflow() {
+        assert!(has_pair_with_sum(&[i32::MIN, 5, i32::MAX], -1));
+        assert!(!has_pair_with_sum(&[i32::MIN, i32::MIN], 0));
+        assert!(!has_pair_with_sum(&[i32::MAX, 1], i32::MIN));
+    }
 }
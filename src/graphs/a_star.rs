@@ -0,0 +1,168 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::ops::Add;
+
+type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
+
+// Performs the A* search on the given directed `graph` from `start` to `goal`.
+// `graph` must be non-negatively weighted.
+//
+// `heuristic` estimates the remaining distance from a vertex to `goal`. The returned
+// path is only guaranteed to be a shortest one if the heuristic is admissible, that is,
+// if it never overestimates the real distance to `goal`. A heuristic that is always
+// `E::default()` (zero) is admissible and makes A* behave like Dijkstra's algorithm.
+//
+// Returns the vertices of the path, `start` and `goal` included, or `None` if `goal`
+// is not reachable from `start`.
+pub fn a_star<V, E, H>(graph: &Graph<V, E>, start: &V, goal: &V, heuristic: H) -> Option<Vec<V>>
+where
+    V: Ord + Copy,
+    E: Ord + Copy + Add<Output = E> + Default,
+    H: Fn(&V) -> E,
+{
+    // best known distance from start, and the predecessor on the matching path
+    let mut dist: BTreeMap<V, E> = BTreeMap::new();
+    let mut prev: BTreeMap<V, V> = BTreeMap::new();
+    // frontier ordered by estimated total cost, the heap is a max heap so we use Reverse
+    let mut frontier = BinaryHeap::new();
+
+    dist.insert(*start, E::default());
+    frontier.push(Reverse((heuristic(start), E::default(), *start)));
+
+    while let Some(Reverse((_, dist_u, u))) = frontier.pop() {
+        if u == *goal {
+            let mut path = vec![u];
+            let mut current = u;
+            while let Some(p) = prev.get(&current) {
+                path.push(*p);
+                current = *p;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        // a shorter path to u was found after this entry was pushed
+        if dist_u > dist[&u] {
+            continue;
+        }
+
+        for (v, weight) in graph.get(&u).into_iter().flatten() {
+            let dist_v = dist_u + *weight;
+            match dist.get(v) {
+                Some(d) if *d <= dist_v => {}
+                _ => {
+                    dist.insert(*v, dist_v);
+                    prev.insert(*v, u);
+                    frontier.push(Reverse((dist_v + heuristic(v), dist_v, *v)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{a_star, Graph};
+    use crate::graphs::dijkstra;
+    use std::collections::BTreeMap;
+
+    fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c: E) {
+        graph.entry(v1).or_default().insert(v2, c);
+        graph.entry(v2).or_default();
+    }
+
+    fn path_cost(graph: &Graph<(i32, i32), i32>, path: &[(i32, i32)]) -> i32 {
+        path.windows(2).map(|w| graph[&w[0]][&w[1]]).sum()
+    }
+
+    /// A `size` by `size` grid where moving into a cell costs that cell's weight,
+    /// except for the cells in `walls`, which cannot be entered.
+    fn grid(size: i32, walls: &[(i32, i32)]) -> Graph<(i32, i32), i32> {
+        let mut graph = BTreeMap::new();
+        for x in 0..size {
+            for y in 0..size {
+                graph.entry((x, y)).or_default();
+                for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)].iter() {
+                    let next = (x + dx, y + dy);
+                    if next.0 < 0 || next.0 >= size || next.1 < 0 || next.1 >= size {
+                        continue;
+                    }
+                    if walls.contains(&next) {
+                        continue;
+                    }
+                    let weight = 1 + (next.0 * 7 + next.1 * 3) % 4;
+                    add_edge(&mut graph, (x, y), next, weight);
+                }
+            }
+        }
+        graph
+    }
+
+    fn manhattan(goal: (i32, i32)) -> impl Fn(&(i32, i32)) -> i32 {
+        move |&(x, y)| (x - goal.0).abs() + (y - goal.1).abs()
+    }
+
+    #[test]
+    fn single_vertex() {
+        let mut graph: Graph<usize, usize> = BTreeMap::new();
+        graph.insert(0, BTreeMap::new());
+
+        assert_eq!(a_star(&graph, &0, &0, |_| 0), Some(vec![0]));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 1);
+        add_edge(&mut graph, 2, 3, 1);
+
+        assert_eq!(a_star(&graph, &0, &3, |_| 0), None);
+        assert_eq!(a_star(&graph, &1, &0, |_| 0), None);
+    }
+
+    #[test]
+    fn zero_heuristic_matches_dijkstra() {
+        let graph = grid(8, &[(3, 0), (3, 1), (3, 2), (3, 3), (3, 4), (3, 5), (5, 7)]);
+        let start = (0, 0);
+        let dists = dijkstra(&graph, &start);
+
+        for goal in graph.keys().filter(|&&v| v != start) {
+            match dists.get(goal) {
+                Some(Some((_, dist))) => {
+                    let path = a_star(&graph, &start, goal, |_| 0).unwrap();
+                    assert_eq!(path.first(), Some(&start));
+                    assert_eq!(path.last(), Some(goal));
+                    assert_eq!(path_cost(&graph, &path), *dist);
+                }
+                // walls cannot be entered
+                _ => assert_eq!(a_star(&graph, &start, goal, |_| 0), None),
+            }
+        }
+    }
+
+    #[test]
+    fn admissible_heuristic_is_optimal() {
+        let graph = grid(
+            10,
+            &[(4, 2), (4, 3), (4, 4), (4, 5), (4, 6), (4, 7), (4, 8)],
+        );
+        let start = (0, 5);
+        let dists = dijkstra(&graph, &start);
+
+        for &goal in [(9, 5), (9, 9), (5, 0), (4, 9)].iter() {
+            let path = a_star(&graph, &start, &goal, manhattan(goal)).unwrap();
+            assert_eq!(Some(path_cost(&graph, &path)), dists[&goal].map(|(_, d)| d));
+        }
+    }
+
+    #[test]
+    fn path_is_connected() {
+        let graph = grid(6, &[(2, 2), (2, 3), (3, 2)]);
+        let goal = (5, 5);
+        let path = a_star(&graph, &(0, 0), &goal, manhattan(goal)).unwrap();
+        for step in path.windows(2) {
+            assert!(graph[&step[0]].contains_key(&step[1]));
+        }
+    }
+}
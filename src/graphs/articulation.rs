@@ -0,0 +1,240 @@
+use crate::data_structures::{Graph, UndirectedGraph};
+use std::cmp::min;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Returns the articulation points of an `UndirectedGraph`, the nodes whose removal
+/// increases the number of connected components.
+pub fn articulation_points<'a, T>(graph: &UndirectedGraph<'a, T>) -> HashSet<&'a T>
+where
+    T: 'a + Eq + Hash,
+{
+    LowLink::run(graph).articulation_points
+}
+
+/// Returns the bridges of an `UndirectedGraph`, the edges whose removal increases the
+/// number of connected components.
+///
+/// Every bridge is given once, as the pair of its ends. The order of the bridges, and of
+/// the ends of each bridge, is unspecified. An edge that is duplicated in the graph is
+/// never a bridge, since the other copy keeps its ends connected.
+pub fn bridges<'a, T>(graph: &UndirectedGraph<'a, T>) -> Vec<(&'a T, &'a T)>
+where
+    T: 'a + Eq + Hash,
+{
+    LowLink::run(graph).bridges
+}
+
+/// The result of a depth-first search that records, for every node, the order in which
+/// it was discovered and the earliest discovered node its subtree has an edge back to.
+///
+/// A tree edge from `parent` to `child` is a bridge if nothing below `child` reaches back
+/// to `parent` or above it, that is, if `low[child] > discovery[parent]`. A non-root
+/// `parent` is an articulation point if some `child` has `low[child] >= discovery[parent]`,
+/// and the root of the search is one if it has more than one child in the search tree.
+struct LowLink<'a, T> {
+    articulation_points: HashSet<&'a T>,
+    bridges: Vec<(&'a T, &'a T)>,
+}
+
+/// A node being explored: its parent in the search tree, the index of the next
+/// neighbour to look at, and whether the edge back to the parent was already skipped.
+struct Frame<'a, T> {
+    node: &'a T,
+    parent: Option<&'a T>,
+    next: usize,
+    skipped_parent: bool,
+}
+
+impl<'a, T> LowLink<'a, T>
+where
+    T: 'a + Eq + Hash,
+{
+    fn run(graph: &UndirectedGraph<'a, T>) -> Self {
+        let mut result = LowLink {
+            articulation_points: HashSet::new(),
+            bridges: Vec::new(),
+        };
+        let mut discovery: HashMap<&'a T, usize> = HashMap::new();
+        let mut low: HashMap<&'a T, usize> = HashMap::new();
+
+        for root in graph.nodes() {
+            if discovery.contains_key(root) {
+                continue;
+            }
+
+            discovery.insert(root, discovery.len());
+            low.insert(root, discovery[root]);
+            let mut root_children = 0;
+            let mut stack = vec![Frame {
+                node: root,
+                parent: None,
+                next: 0,
+                skipped_parent: false,
+            }];
+
+            while let Some(frame) = stack.last_mut() {
+                let node = frame.node;
+                let neighbours = graph.neighbours(node).unwrap();
+
+                if let Some((neighbour, _)) = neighbours.get(frame.next) {
+                    frame.next += 1;
+                    // only one copy of the edge to the parent is the tree edge itself
+                    if Some(*neighbour) == frame.parent && !frame.skipped_parent {
+                        frame.skipped_parent = true;
+                        continue;
+                    }
+
+                    match discovery.get(neighbour) {
+                        Some(&seen) => {
+                            low.insert(node, min(low[node], seen));
+                        }
+                        None => {
+                            discovery.insert(neighbour, discovery.len());
+                            low.insert(neighbour, discovery[neighbour]);
+                            stack.push(Frame {
+                                node: neighbour,
+                                parent: Some(node),
+                                next: 0,
+                                skipped_parent: false,
+                            });
+                        }
+                    }
+                    continue;
+                }
+
+                let parent = frame.parent;
+                stack.pop();
+                if let Some(parent) = parent {
+                    low.insert(parent, min(low[parent], low[node]));
+                    if low[node] > discovery[parent] {
+                        result.bridges.push((parent, node));
+                    }
+                    if parent == root {
+                        root_children += 1;
+                    } else if low[node] >= discovery[parent] {
+                        result.articulation_points.insert(parent);
+                    }
+                }
+            }
+
+            if root_children > 1 {
+                result.articulation_points.insert(root);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{articulation_points, bridges};
+    use crate::data_structures::{Graph, UndirectedGraph};
+    use std::collections::HashSet;
+
+    fn graph_from<'a>(nodes: &'a [u32], edges: &[(usize, usize)]) -> UndirectedGraph<'a, u32> {
+        let mut graph = UndirectedGraph::new();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for &(from, to) in edges {
+            graph.add_edge((&nodes[from], &nodes[to], 1));
+        }
+        graph
+    }
+
+    fn sorted_points(graph: &UndirectedGraph<u32>) -> Vec<u32> {
+        let mut points: Vec<u32> = articulation_points(graph).into_iter().copied().collect();
+        points.sort_unstable();
+        points
+    }
+
+    fn sorted_bridges(graph: &UndirectedGraph<u32>) -> Vec<(u32, u32)> {
+        let mut edges: Vec<(u32, u32)> = bridges(graph)
+            .into_iter()
+            .map(|(a, b)| (*a.min(b), *a.max(b)))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: UndirectedGraph<u32> = UndirectedGraph::new();
+        assert!(articulation_points(&graph).is_empty());
+        assert!(bridges(&graph).is_empty());
+    }
+
+    #[test]
+    fn two_triangles_joined_by_an_edge() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let graph = graph_from(
+            &nodes,
+            &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)],
+        );
+
+        assert_eq!(sorted_bridges(&graph), vec![(2, 3)]);
+        assert_eq!(
+            articulation_points(&graph),
+            [&nodes[2], &nodes[3]]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn cycle_has_none() {
+        let nodes: Vec<u32> = (0..5).collect();
+        let graph = graph_from(&nodes, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+
+        assert!(articulation_points(&graph).is_empty());
+        assert!(bridges(&graph).is_empty());
+    }
+
+    #[test]
+    fn path_and_star() {
+        let nodes: Vec<u32> = (0..9).collect();
+        // a path 0 - 1 - 2 - 3 and a star centered on 4, in separate components
+        let graph = graph_from(
+            &nodes,
+            &[(0, 1), (1, 2), (2, 3), (4, 5), (4, 6), (4, 7), (4, 8)],
+        );
+
+        assert_eq!(sorted_points(&graph), vec![1, 2, 4]);
+        assert_eq!(
+            sorted_bridges(&graph),
+            vec![(0, 1), (1, 2), (2, 3), (4, 5), (4, 6), (4, 7), (4, 8)]
+        );
+    }
+
+    #[test]
+    fn root_with_a_single_child() {
+        let nodes: Vec<u32> = (0..4).collect();
+        // 0 hangs off a triangle, so whatever node the search starts from, 1 is the only cut
+        let graph = graph_from(&nodes, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+
+        assert_eq!(sorted_points(&graph), vec![1]);
+        assert_eq!(sorted_bridges(&graph), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn duplicated_edge_is_not_a_bridge() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let graph = graph_from(&nodes, &[(0, 1), (0, 1), (1, 2)]);
+
+        assert_eq!(sorted_bridges(&graph), vec![(1, 2)]);
+        assert_eq!(sorted_points(&graph), vec![1]);
+    }
+
+    #[test]
+    fn bowtie() {
+        let nodes: Vec<u32> = (0..5).collect();
+        // two triangles sharing node 2
+        let graph = graph_from(&nodes, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+
+        assert_eq!(sorted_points(&graph), vec![2]);
+        assert!(bridges(&graph).is_empty());
+    }
+}
@@ -1,14 +1,26 @@
 use std::collections::BTreeMap;
+use std::fmt;
 use std::ops::Add;
 
 use std::ops::Neg;
 
 type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle;
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph has a negative cycle reachable from the start")
+    }
+}
+
+impl std::error::Error for NegativeCycle {}
+
 // performs the Bellman-Ford algorithm on the given graph from the given start
 // the graph is an undirected graph
 //
-// if there is a negative weighted loop it returns None
+// if there is a negative weighted loop reachable from start it returns Err(NegativeCycle)
 // else it returns a map that for each reachable vertex associates the distance and the predecessor
 // since the start has no predecessor but is reachable, map[start] will be None
 pub fn bellman_ford<
@@ -17,7 +29,7 @@ pub fn bellman_ford<
 >(
     graph: &Graph<V, E>,
     start: &V,
-) -> Option<BTreeMap<V, Option<(V, E)>>> {
+) -> Result<BTreeMap<V, Option<(V, E)>>, NegativeCycle> {
     let mut ans: BTreeMap<V, Option<(V, E)>> = BTreeMap::new();
 
     ans.insert(*start, None);
@@ -46,7 +58,7 @@ pub fn bellman_ford<
                             // negative self edge or negative loop
                             _ => {
                                 if *d > *d + *d {
-                                    return None;
+                                    return Err(NegativeCycle);
                                 }
                             }
                         };
@@ -72,21 +84,23 @@ pub fn bellman_ford<
     for (u, edges) in graph {
         for (v, d) in edges {
             match (ans.get(u), ans.get(v)) {
-                (Some(None), Some(None)) if *d > *d + *d => return None,
-                (Some(None), Some(Some((_, dv)))) if d < dv => return None,
-                (Some(Some((_, du))), Some(None)) if *du < -*d => return None,
-                (Some(Some((_, du))), Some(Some((_, dv)))) if *du + *d < *dv => return None,
+                (Some(None), Some(None)) if *d > *d + *d => return Err(NegativeCycle),
+                (Some(None), Some(Some((_, dv)))) if d < dv => return Err(NegativeCycle),
+                (Some(Some((_, du))), Some(None)) if *du < -*d => return Err(NegativeCycle),
+                (Some(Some((_, du))), Some(Some((_, dv)))) if *du + *d < *dv => {
+                    return Err(NegativeCycle)
+                }
                 (_, _) => {}
             }
         }
     }
 
-    Some(ans)
+    Ok(ans)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{bellman_ford, Graph};
+    use super::{bellman_ford, Graph, NegativeCycle};
     use std::collections::BTreeMap;
 
     fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c: E) {
@@ -102,7 +116,7 @@ mod tests {
         let mut dists = BTreeMap::new();
         dists.insert(0, None);
 
-        assert_eq!(bellman_ford(&graph, &0), Some(dists));
+        assert_eq!(bellman_ford(&graph, &0), Ok(dists));
     }
 
     #[test]
@@ -114,12 +128,12 @@ mod tests {
         dists_0.insert(0, None);
         dists_0.insert(1, Some((0, 2)));
 
-        assert_eq!(bellman_ford(&graph, &0), Some(dists_0));
+        assert_eq!(bellman_ford(&graph, &0), Ok(dists_0));
 
         let mut dists_1 = BTreeMap::new();
         dists_1.insert(1, None);
 
-        assert_eq!(bellman_ford(&graph, &1), Some(dists_1));
+        assert_eq!(bellman_ford(&graph, &1), Ok(dists_1));
     }
 
     #[test]
@@ -143,7 +157,7 @@ mod tests {
             }
         }
 
-        assert_eq!(bellman_ford(&graph, &1), Some(dists));
+        assert_eq!(bellman_ford(&graph, &1), Ok(dists));
     }
 
     #[test]
@@ -161,25 +175,25 @@ mod tests {
         dists_a.insert('c', Some(('a', 12)));
         dists_a.insert('d', Some(('c', 44)));
         dists_a.insert('b', Some(('c', 32)));
-        assert_eq!(bellman_ford(&graph, &'a'), Some(dists_a));
+        assert_eq!(bellman_ford(&graph, &'a'), Ok(dists_a));
 
         let mut dists_b = BTreeMap::new();
         dists_b.insert('b', None);
         dists_b.insert('a', Some(('b', 10)));
         dists_b.insert('c', Some(('a', 22)));
         dists_b.insert('d', Some(('c', 54)));
-        assert_eq!(bellman_ford(&graph, &'b'), Some(dists_b));
+        assert_eq!(bellman_ford(&graph, &'b'), Ok(dists_b));
 
         let mut dists_c = BTreeMap::new();
         dists_c.insert('c', None);
         dists_c.insert('b', Some(('c', 20)));
         dists_c.insert('d', Some(('c', 32)));
         dists_c.insert('a', Some(('b', 30)));
-        assert_eq!(bellman_ford(&graph, &'c'), Some(dists_c));
+        assert_eq!(bellman_ford(&graph, &'c'), Ok(dists_c));
 
         let mut dists_d = BTreeMap::new();
         dists_d.insert('d', None);
-        assert_eq!(bellman_ford(&graph, &'d'), Some(dists_d));
+        assert_eq!(bellman_ford(&graph, &'d'), Ok(dists_d));
 
         let mut dists_e = BTreeMap::new();
         dists_e.insert('e', None);
@@ -187,7 +201,7 @@ mod tests {
         dists_e.insert('c', Some(('a', 19)));
         dists_e.insert('d', Some(('c', 51)));
         dists_e.insert('b', Some(('c', 39)));
-        assert_eq!(bellman_ford(&graph, &'e'), Some(dists_e));
+        assert_eq!(bellman_ford(&graph, &'e'), Ok(dists_e));
     }
 
     #[test]
@@ -210,7 +224,7 @@ mod tests {
         dists_0.insert(2, Some((3, 4)));
         dists_0.insert(3, Some((0, 7)));
         dists_0.insert(4, Some((1, -2)));
-        assert_eq!(bellman_ford(&graph, &0), Some(dists_0));
+        assert_eq!(bellman_ford(&graph, &0), Ok(dists_0));
 
         let mut dists_1 = BTreeMap::new();
         dists_1.insert(0, Some((4, -1)));
@@ -218,7 +232,7 @@ mod tests {
         dists_1.insert(2, Some((4, 3)));
         dists_1.insert(3, Some((0, 6)));
         dists_1.insert(4, Some((1, -4)));
-        assert_eq!(bellman_ford(&graph, &1), Some(dists_1));
+        assert_eq!(bellman_ford(&graph, &1), Ok(dists_1));
 
         let mut dists_2 = BTreeMap::new();
         dists_2.insert(0, Some((4, -3)));
@@ -226,7 +240,7 @@ mod tests {
         dists_2.insert(2, None);
         dists_2.insert(3, Some((0, 4)));
         dists_2.insert(4, Some((1, -6)));
-        assert_eq!(bellman_ford(&graph, &2), Some(dists_2));
+        assert_eq!(bellman_ford(&graph, &2), Ok(dists_2));
 
         let mut dists_3 = BTreeMap::new();
         dists_3.insert(0, Some((4, -6)));
@@ -234,7 +248,7 @@ mod tests {
         dists_3.insert(2, Some((3, -3)));
         dists_3.insert(3, None);
         dists_3.insert(4, Some((1, -9)));
-        assert_eq!(bellman_ford(&graph, &3), Some(dists_3));
+        assert_eq!(bellman_ford(&graph, &3), Ok(dists_3));
 
         let mut dists_4 = BTreeMap::new();
         dists_4.insert(0, Some((4, 3)));
@@ -242,7 +256,7 @@ mod tests {
         dists_4.insert(2, Some((4, 7)));
         dists_4.insert(3, Some((0, 10)));
         dists_4.insert(4, None);
-        assert_eq!(bellman_ford(&graph, &4), Some(dists_4));
+        assert_eq!(bellman_ford(&graph, &4), Ok(dists_4));
     }
 
     #[test]
@@ -259,10 +273,54 @@ mod tests {
         add_edge(&mut graph, 4, 0, 3);
         add_edge(&mut graph, 4, 2, 7);
 
-        assert_eq!(bellman_ford(&graph, &0), None);
-        assert_eq!(bellman_ford(&graph, &1), None);
-        assert_eq!(bellman_ford(&graph, &2), None);
-        assert_eq!(bellman_ford(&graph, &3), None);
-        assert_eq!(bellman_ford(&graph, &4), None);
+        assert_eq!(bellman_ford(&graph, &0), Err(NegativeCycle));
+        assert_eq!(bellman_ford(&graph, &1), Err(NegativeCycle));
+        assert_eq!(bellman_ford(&graph, &2), Err(NegativeCycle));
+        assert_eq!(bellman_ford(&graph, &3), Err(NegativeCycle));
+        assert_eq!(bellman_ford(&graph, &4), Err(NegativeCycle));
+    }
+
+    #[test]
+    fn negative_edge_without_cycle() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 's', 'a', 4);
+        add_edge(&mut graph, 's', 'b', 5);
+        add_edge(&mut graph, 'b', 'a', -3);
+        add_edge(&mut graph, 'a', 't', 2);
+
+        let mut dists = BTreeMap::new();
+        dists.insert('s', None);
+        dists.insert('a', Some(('b', 2)));
+        dists.insert('b', Some(('s', 5)));
+        dists.insert('t', Some(('a', 4)));
+        assert_eq!(bellman_ford(&graph, &'s'), Ok(dists));
+    }
+
+    #[test]
+    fn unreachable_negative_cycle() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 1);
+        add_edge(&mut graph, 2, 3, -2);
+        add_edge(&mut graph, 3, 2, 1);
+
+        let mut dists = BTreeMap::new();
+        dists.insert(0, None);
+        dists.insert(1, Some((0, 1)));
+        assert_eq!(bellman_ford(&graph, &0), Ok(dists));
+        assert_eq!(bellman_ford(&graph, &2), Err(NegativeCycle));
+    }
+
+    #[test]
+    fn negative_cycle_is_an_error() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, -1);
+        add_edge(&mut graph, 1, 0, -1);
+
+        let shortest =
+            || -> Result<usize, Box<dyn std::error::Error>> { Ok(bellman_ford(&graph, &0)?.len()) };
+        assert_eq!(
+            shortest().unwrap_err().to_string(),
+            "the graph has a negative cycle reachable from the start"
+        );
     }
 }
@@ -0,0 +1,138 @@
+use crate::data_structures::{Graph, UndirectedGraph};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Checks whether an `UndirectedGraph` is bipartite, that is, 2-colorable.
+///
+/// A breadth-first search is started from every node that is not colored yet,
+/// coloring it `false`. Every neighbour then gets the opposite color of the node
+/// it was reached from. An edge between two nodes of the same color closes an
+/// odd cycle, and no 2-coloring exists.
+///
+/// Returns the color of every node if the graph is bipartite, or `None` otherwise.
+/// A self-loop is an odd cycle of length one.
+pub fn is_bipartite<'a, T>(graph: &UndirectedGraph<'a, T>) -> Option<HashMap<&'a T, bool>>
+where
+    T: 'a + Eq + Hash,
+{
+    let mut colors: HashMap<&'a T, bool> = HashMap::new();
+
+    for start in graph.nodes() {
+        if colors.contains_key(start) {
+            continue;
+        }
+
+        colors.insert(start, false);
+        let mut queue = VecDeque::from(vec![start]);
+        while let Some(node) = queue.pop_front() {
+            let color = colors[node];
+            for (neighbour, _) in graph.neighbours(node).unwrap() {
+                match colors.get(neighbour) {
+                    Some(&other) if other == color => return None,
+                    Some(_) => {}
+                    None => {
+                        colors.insert(*neighbour, !color);
+                        queue.push_back(*neighbour);
+                    }
+                }
+            }
+        }
+    }
+
+    Some(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_bipartite;
+    use crate::data_structures::{Graph, UndirectedGraph};
+
+    /// Checks that no edge joins two nodes of the same color.
+    fn assert_proper_coloring(graph: &UndirectedGraph<u32>) {
+        let colors = is_bipartite(graph).unwrap();
+        assert_eq!(colors.len(), graph.nodes().len());
+        for (from, to, _) in graph.edges() {
+            assert_ne!(colors[from], colors[to]);
+        }
+    }
+
+    fn cycle(nodes: &[u32]) -> UndirectedGraph<'_, u32> {
+        let mut graph = UndirectedGraph::new();
+        for i in 0..nodes.len() {
+            graph.add_edge((&nodes[i], &nodes[(i + 1) % nodes.len()], 1));
+        }
+        graph
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: UndirectedGraph<u32> = UndirectedGraph::new();
+        assert_eq!(is_bipartite(&graph).map(|colors| colors.len()), Some(0));
+    }
+
+    #[test]
+    fn even_cycle() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let graph = cycle(&nodes);
+        assert_proper_coloring(&graph);
+
+        let colors = is_bipartite(&graph).unwrap();
+        for i in 0..nodes.len() {
+            assert_eq!(colors[&nodes[i]], colors[&nodes[0]] == (i % 2 == 0));
+        }
+    }
+
+    #[test]
+    fn triangle() {
+        let nodes: Vec<u32> = (0..3).collect();
+        assert!(is_bipartite(&cycle(&nodes)).is_none());
+    }
+
+    #[test]
+    fn odd_cycle_with_tail() {
+        let nodes: Vec<u32> = (0..8).collect();
+        let mut graph = cycle(&nodes[..5]);
+        graph.add_edge((&nodes[4], &nodes[5], 1));
+        graph.add_edge((&nodes[5], &nodes[6], 1));
+        graph.add_edge((&nodes[6], &nodes[7], 1));
+        assert!(is_bipartite(&graph).is_none());
+    }
+
+    #[test]
+    fn self_loop() {
+        let nodes: Vec<u32> = (0..2).collect();
+        let mut graph = UndirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[1], 1));
+        assert!(is_bipartite(&graph).is_none());
+    }
+
+    #[test]
+    fn complete_bipartite() {
+        let nodes: Vec<u32> = (0..7).collect();
+        let mut graph = UndirectedGraph::new();
+        for left in &nodes[..3] {
+            for right in &nodes[3..] {
+                graph.add_edge((left, right, 1));
+            }
+        }
+        assert_proper_coloring(&graph);
+    }
+
+    #[test]
+    fn several_components() {
+        let nodes: Vec<u32> = (0..10).collect();
+        // a square, a path and a lone node are all bipartite
+        let mut graph = cycle(&nodes[..4]);
+        graph.add_edge((&nodes[4], &nodes[5], 1));
+        graph.add_edge((&nodes[5], &nodes[6], 1));
+        graph.add_node(&nodes[7]);
+        assert_proper_coloring(&graph);
+
+        // a triangle in another component makes the whole graph non-bipartite
+        graph.add_edge((&nodes[8], &nodes[9], 1));
+        graph.add_edge((&nodes[9], &nodes[7], 1));
+        graph.add_edge((&nodes[7], &nodes[8], 1));
+        assert!(is_bipartite(&graph).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use super::representation::{Graph, Vertex};
-use std::collections::{HashSet, VecDeque};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub fn breadth_first_search(graph: &Graph, start: Vertex, end: Vertex) -> bool {
     let mut visited: HashSet<Vertex> = HashSet::new();
@@ -11,7 +12,7 @@ pub fn breadth_first_search(graph: &Graph, start: Vertex, end: Vertex) -> bool {
             return true;
         }
 
-        for neighbor in v.neighbors(graph).into_iter() {
+        for (neighbor, _) in v.neighbors(graph).into_iter() {
             if visited.insert(neighbor) {
                 queue.push_back(neighbor);
             }
@@ -21,6 +22,60 @@ pub fn breadth_first_search(graph: &Graph, start: Vertex, end: Vertex) -> bool {
     false
 }
 
+// Returns a shortest path from start to end, counted in edges, with both ends included.
+// Every vertex remembers the vertex it was first reached from, and the path is rebuilt
+// by walking those parents back from end.
+pub fn bfs_path(graph: &Graph, start: Vertex, end: Vertex) -> Option<Vec<Vertex>> {
+    let mut parents: HashMap<Vertex, Vertex> = HashMap::new();
+    let mut visited: HashSet<Vertex> = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(start);
+    queue.push_back(start);
+
+    while let Some(v) = queue.pop_front() {
+        if v == end {
+            let mut path = vec![v];
+            let mut current = v;
+            while let Some(parent) = parents.get(&current) {
+                path.push(*parent);
+                current = *parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for (neighbor, _) in v.neighbors(graph).into_iter() {
+            if visited.insert(neighbor) {
+                parents.insert(neighbor, v);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+// Returns the number of edges on a shortest path from start to every vertex reachable
+// from it. start is at distance 0, and unreachable vertices are left out of the map.
+pub fn bfs_distances(graph: &Graph, start: Vertex) -> HashMap<Vertex, u32> {
+    let mut distances: HashMap<Vertex, u32> = HashMap::new();
+    let mut queue = VecDeque::new();
+    distances.insert(start, 0);
+    queue.push_back(start);
+
+    while let Some(v) = queue.pop_front() {
+        let distance = distances[&v] + 1;
+        for (neighbor, _) in v.neighbors(graph).into_iter() {
+            if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                entry.insert(distance);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +184,179 @@ mod tests {
 
         assert!(!breadth_first_search(&graph, 1.into(), 10.into()));
     }
+
+    /// Checks that `path` goes from `start` to `end` along edges of `graph`.
+    fn assert_valid_path(graph: &Graph, path: &[Vertex], start: u32, end: u32) {
+        assert_eq!(path.first(), Some(&start.into()));
+        assert_eq!(path.last(), Some(&end.into()));
+        for step in path.windows(2) {
+            assert!(step[0].neighbors(graph).iter().any(|(v, _)| *v == step[1]));
+        }
+    }
+
+    #[test]
+    fn path_to_itself() {
+        let graph = Graph::new(vec![1.into()], vec![]);
+
+        assert_eq!(bfs_path(&graph, 1.into(), 1.into()), Some(vec![1.into()]));
+    }
+
+    #[test]
+    fn path_follows_direction() {
+        let vertices = vec![1, 2];
+        let edges = vec![(2, 1)];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        assert_eq!(bfs_path(&graph, 1.into(), 2.into()), None);
+        assert_eq!(
+            bfs_path(&graph, 2.into(), 1.into()),
+            Some(vec![2.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn path_is_shortest() {
+        // a long way round 1 -> 2 -> 3 -> 4 -> 5 -> 6 and shortcuts 1 -> 7 -> 5 and 2 -> 4
+        let vertices = vec![1, 2, 3, 4, 5, 6, 7];
+        let edges = vec![
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (1, 7),
+            (7, 5),
+            (2, 4),
+        ];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        let path = bfs_path(&graph, 1.into(), 6.into()).unwrap();
+        assert_valid_path(&graph, &path, 1, 6);
+        assert_eq!(path.len() - 1, 3);
+
+        let path = bfs_path(&graph, 2.into(), 5.into()).unwrap();
+        assert_eq!(path, vec![2.into(), 4.into(), 5.into()]);
+
+        let path = bfs_path(&graph, 3.into(), 6.into()).unwrap();
+        assert_eq!(path.len() - 1, 3);
+    }
+
+    #[test]
+    fn path_ignores_weights() {
+        let vertices = vec![1, 2, 3, 4];
+        let edges = vec![(1, 4, 100), (1, 2, 1), (2, 3, 1), (3, 4, 1)];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        assert_eq!(
+            bfs_path(&graph, 1.into(), 4.into()),
+            Some(vec![1.into(), 4.into()])
+        );
+        assert_eq!(bfs_distances(&graph, 1.into())[&4.into()], 1);
+    }
+
+    #[test]
+    fn path_in_island() {
+        let vertices = (0..15).collect::<Vec<u32>>();
+        let edges = vec![
+            (0, 1),
+            (0, 4),
+            (1, 2),
+            (2, 3),
+            (3, 7),
+            (4, 8),
+            (8, 9),
+            (9, 10),
+            (8, 12),
+        ];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        assert_eq!(bfs_path(&graph, 1.into(), 10.into()), None);
+        let path = bfs_path(&graph, 0.into(), 10.into()).unwrap();
+        assert_valid_path(&graph, &path, 0, 10);
+        assert_eq!(path.len() - 1, 4);
+    }
+
+    #[test]
+    fn distances_on_path() {
+        let vertices = vec![1, 2, 3, 4, 5, 6];
+        let edges = vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6)];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        let distances = bfs_distances(&graph, 1.into());
+        assert_eq!(distances.len(), 6);
+        for v in 1..=6 {
+            assert_eq!(distances[&v.into()], v - 1);
+        }
+
+        // the edges only go forward
+        let distances = bfs_distances(&graph, 4.into());
+        assert_eq!(distances.len(), 3);
+        assert_eq!(distances.get(&3.into()), None);
+        assert_eq!(distances[&6.into()], 2);
+    }
+
+    #[test]
+    fn distances_on_star() {
+        let vertices = vec![0, 1, 2, 3, 4, 5];
+        let mut edges = vec![];
+        for leaf in 1..vertices.len() as u32 {
+            edges.push((0, leaf));
+            edges.push((leaf, 0));
+        }
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        let distances = bfs_distances(&graph, 0.into());
+        assert_eq!(distances[&0.into()], 0);
+        for leaf in 1..=5 {
+            assert_eq!(distances[&leaf.into()], 1);
+        }
+
+        let distances = bfs_distances(&graph, 3.into());
+        assert_eq!(distances[&3.into()], 0);
+        assert_eq!(distances[&0.into()], 1);
+        for leaf in [1, 2, 4, 5].iter() {
+            assert_eq!(distances[&(*leaf).into()], 2);
+        }
+    }
+
+    #[test]
+    fn distances_skip_unreachable() {
+        let vertices = vec![1, 2, 3, 4, 5, 6];
+        let edges = vec![(1, 2), (2, 3), (4, 5), (5, 6)];
+
+        let graph = Graph::new(
+            vertices.into_iter().map(|v| v.into()).collect(),
+            edges.into_iter().map(|e| e.into()).collect(),
+        );
+
+        let distances = bfs_distances(&graph, 1.into());
+        assert_eq!(distances.len(), 3);
+        for v in 4..=6 {
+            assert!(!distances.contains_key(&v.into()));
+        }
+    }
 }
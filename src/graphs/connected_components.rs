@@ -0,0 +1,97 @@
+use crate::data_structures::{Graph, UndirectedGraph};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Splits an `UndirectedGraph` into its connected components.
+///
+/// A depth-first search is started from every node that is not part of a
+/// component yet, and collects every node it reaches. The order of the
+/// components, and of the nodes inside each of them, is unspecified.
+pub fn connected_components<'a, T>(graph: &UndirectedGraph<'a, T>) -> Vec<Vec<&'a T>>
+where
+    T: 'a + Eq + Hash,
+{
+    let mut visited: HashSet<&'a T> = HashSet::new();
+    let mut components = Vec::new();
+
+    for start in graph.nodes() {
+        if !visited.insert(start) {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            component.push(node);
+            for (neighbour, _) in graph.neighbours(node).unwrap() {
+                if visited.insert(*neighbour) {
+                    stack.push(*neighbour);
+                }
+            }
+        }
+        components.push(component);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::connected_components;
+    use crate::data_structures::{Graph, UndirectedGraph};
+
+    /// Sorts the components so they can be compared regardless of order.
+    fn normalize(components: Vec<Vec<&u32>>) -> Vec<Vec<u32>> {
+        let mut components: Vec<Vec<u32>> = components
+            .into_iter()
+            .map(|component| {
+                let mut component: Vec<u32> = component.into_iter().copied().collect();
+                component.sort_unstable();
+                component
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: UndirectedGraph<u32> = UndirectedGraph::new();
+        assert!(connected_components(&graph).is_empty());
+    }
+
+    #[test]
+    fn fully_connected() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let mut graph = UndirectedGraph::new();
+        for i in 0..nodes.len() {
+            for j in i + 1..nodes.len() {
+                graph.add_edge((&nodes[i], &nodes[j], 1));
+            }
+        }
+
+        assert_eq!(
+            normalize(connected_components(&graph)),
+            vec![vec![0, 1, 2, 3, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn three_islands() {
+        let nodes: Vec<u32> = (0..9).collect();
+        let mut graph = UndirectedGraph::new();
+        // a path, a triangle and a lone node
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[2], 1));
+        graph.add_edge((&nodes[2], &nodes[3], 1));
+        graph.add_edge((&nodes[4], &nodes[5], 1));
+        graph.add_edge((&nodes[5], &nodes[6], 1));
+        graph.add_edge((&nodes[6], &nodes[4], 1));
+        graph.add_node(&nodes[7]);
+
+        assert_eq!(
+            normalize(connected_components(&graph)),
+            vec![vec![0, 1, 2, 3], vec![4, 5, 6], vec![7]]
+        );
+    }
+}
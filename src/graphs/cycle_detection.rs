@@ -0,0 +1,132 @@
+use crate::data_structures::{DirectedGraph, Graph};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    // on the path currently being explored
+    Gray,
+    // fully explored, no cycle goes through it
+    Black,
+}
+
+/// Returns whether a `DirectedGraph` contains a directed cycle.
+///
+/// A depth-first search colors a node gray while it is on the current path and
+/// black once everything reachable from it has been explored. Reaching a gray
+/// node again means an edge leads back into the current path, which closes a
+/// cycle. A self-loop is such a back edge of length one.
+pub fn has_cycle<'a, T>(graph: &DirectedGraph<'a, T>) -> bool
+where
+    T: 'a + Eq + Hash,
+{
+    let mut colors: HashMap<&'a T, Color> = HashMap::new();
+
+    for start in graph.nodes() {
+        if colors.contains_key(start) {
+            continue;
+        }
+
+        // every frame holds a node and the index of the next neighbour to visit,
+        // so nodes are only colored black after all their neighbours are done
+        colors.insert(start, Color::Gray);
+        let mut stack = vec![(start, 0)];
+        while let Some((node, index)) = stack.pop() {
+            let neighbours = graph.neighbours(node).unwrap();
+            match neighbours.get(index) {
+                Some((next, _)) => {
+                    stack.push((node, index + 1));
+                    match colors.get(next) {
+                        Some(Color::Gray) => return true,
+                        Some(Color::Black) => {}
+                        None => {
+                            colors.insert(next, Color::Gray);
+                            stack.push((next, 0));
+                        }
+                    }
+                }
+                None => {
+                    colors.insert(node, Color::Black);
+                }
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::has_cycle;
+    use crate::data_structures::{DirectedGraph, Graph};
+
+    #[test]
+    fn empty_graph() {
+        let graph: DirectedGraph<u32> = DirectedGraph::new();
+        assert!(!has_cycle(&graph));
+    }
+
+    #[test]
+    fn acyclic_dag() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let mut graph = DirectedGraph::new();
+        // a diamond 0 -> {1, 2} -> 3 followed by 3 -> 4 -> 5, plus a shortcut 0 -> 5
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[0], &nodes[2], 1));
+        graph.add_edge((&nodes[1], &nodes[3], 1));
+        graph.add_edge((&nodes[2], &nodes[3], 1));
+        graph.add_edge((&nodes[3], &nodes[4], 1));
+        graph.add_edge((&nodes[4], &nodes[5], 1));
+        graph.add_edge((&nodes[0], &nodes[5], 1));
+
+        assert!(!has_cycle(&graph));
+    }
+
+    #[test]
+    fn two_cycle() {
+        let nodes: Vec<u32> = (0..2).collect();
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[0], 1));
+
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn self_loop() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[1], 1));
+        graph.add_node(&nodes[2]);
+
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn back_edge_deep_in_the_graph() {
+        let nodes: Vec<u32> = (0..100).collect();
+        let mut graph = DirectedGraph::new();
+        for i in 0..99 {
+            graph.add_edge((&nodes[i], &nodes[i + 1], 1));
+        }
+        assert!(!has_cycle(&graph));
+
+        graph.add_edge((&nodes[99], &nodes[50], 1));
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn cycle_in_second_component() {
+        let nodes: Vec<u32> = (0..7).collect();
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[2], 1));
+        graph.add_edge((&nodes[3], &nodes[4], 1));
+        graph.add_edge((&nodes[4], &nodes[5], 1));
+        graph.add_edge((&nodes[5], &nodes[6], 1));
+        graph.add_edge((&nodes[6], &nodes[4], 1));
+
+        assert!(has_cycle(&graph));
+    }
+}
@@ -10,7 +10,7 @@ pub fn depth_first_search(graph: &Graph, start: Vertex, end: Vertex) -> bool {
             return true;
         }
 
-        for neighbor in v.neighbors(graph).into_iter() {
+        for (neighbor, _) in v.neighbors(graph).into_iter() {
             if visited.insert(neighbor) {
                 stack.push(neighbor);
             }
@@ -50,9 +50,31 @@ pub fn dijkstra<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
     ans
 }
 
+// Returns the vertices on a shortest path from `start` to `end`, both included,
+// by walking back the predecessors computed by `dijkstra`.
+//
+// Returns `None` if `end` is not reachable from `start`.
+pub fn dijkstra_path<V: Ord + Copy, E: Ord + Copy + Add<Output = E>>(
+    graph: &Graph<V, E>,
+    start: &V,
+    end: &V,
+) -> Option<Vec<V>> {
+    let predecessors = dijkstra(graph, start);
+
+    let mut path = vec![*end];
+    let mut current = *end;
+    while let Some((prev, _)) = predecessors.get(&current)? {
+        path.push(*prev);
+        current = *prev;
+    }
+
+    path.reverse();
+    Some(path)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{dijkstra, Graph};
+    use super::{dijkstra, dijkstra_path, Graph};
     use std::collections::BTreeMap;
 
     fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c: E) {
@@ -155,4 +177,55 @@ mod tests {
         dists_e.insert('b', Some(('c', 39)));
         assert_eq!(dijkstra(&graph, &'e'), dists_e);
     }
+
+    #[test]
+    fn path_linear() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 3);
+        add_edge(&mut graph, 1, 2, 1);
+        add_edge(&mut graph, 2, 3, 4);
+
+        assert_eq!(dijkstra_path(&graph, &0, &3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(dijkstra_path(&graph, &1, &2), Some(vec![1, 2]));
+        assert_eq!(dijkstra_path(&graph, &2, &2), Some(vec![2]));
+    }
+
+    #[test]
+    fn path_takes_cheapest_route() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 'a', 'c', 12);
+        add_edge(&mut graph, 'a', 'd', 60);
+        add_edge(&mut graph, 'b', 'a', 10);
+        add_edge(&mut graph, 'c', 'b', 20);
+        add_edge(&mut graph, 'c', 'd', 32);
+        add_edge(&mut graph, 'e', 'a', 7);
+
+        assert_eq!(
+            dijkstra_path(&graph, &'e', &'d'),
+            Some(vec!['e', 'a', 'c', 'd'])
+        );
+        assert_eq!(dijkstra_path(&graph, &'c', &'a'), Some(vec!['c', 'b', 'a']));
+    }
+
+    #[test]
+    fn path_with_equal_cost_routes() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 's', 'a', 1);
+        add_edge(&mut graph, 's', 'b', 2);
+        add_edge(&mut graph, 'a', 't', 3);
+        add_edge(&mut graph, 'b', 't', 2);
+
+        let path = dijkstra_path(&graph, &'s', &'t').unwrap();
+        assert!(path == vec!['s', 'a', 't'] || path == vec!['s', 'b', 't']);
+    }
+
+    #[test]
+    fn path_to_disconnected_target() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 1);
+        add_edge(&mut graph, 2, 3, 1);
+
+        assert_eq!(dijkstra_path(&graph, &0, &3), None);
+        assert_eq!(dijkstra_path(&graph, &1, &0), None);
+    }
 }
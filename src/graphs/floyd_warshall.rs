@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
+
+// Performs the Floyd-Warshall algorithm on the given directed `graph`.
+// The graph may have negative edges, but no negative cycle.
+//
+// Returns, for every vertex `u`, a map associating each vertex reachable from `u`
+// with the length of a shortest path to it. Pairs with no path between them are
+// absent from the result. `E::default()` is used as the zero distance, so every
+// vertex is at distance zero from itself.
+pub fn floyd_warshall<V: Ord + Copy, E: Ord + Copy + Add<Output = E> + Default>(
+    graph: &Graph<V, E>,
+) -> Graph<V, E> {
+    let mut dist: Graph<V, E> = BTreeMap::new();
+
+    for (u, edges) in graph {
+        let row = dist.entry(*u).or_default();
+        row.insert(*u, E::default());
+        for (v, weight) in edges {
+            match row.get(v) {
+                Some(d) if d <= weight => {}
+                _ => {
+                    row.insert(*v, *weight);
+                }
+            }
+        }
+    }
+
+    for k in graph.keys() {
+        // the row of `k` does not change while `k` is the intermediate vertex,
+        // as long as there is no negative cycle
+        let through_k = dist[k].clone();
+        for row in dist.values_mut() {
+            let to_k = match row.get(k) {
+                Some(d) => *d,
+                None => continue,
+            };
+            for (v, from_k) in &through_k {
+                let candidate = to_k + *from_k;
+                match row.get(v) {
+                    Some(d) if *d <= candidate => {}
+                    _ => {
+                        row.insert(*v, candidate);
+                    }
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{floyd_warshall, Graph};
+    use crate::graphs::dijkstra;
+    use std::collections::BTreeMap;
+
+    fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, v1: V, v2: V, c: E) {
+        graph.entry(v1).or_default().insert(v2, c);
+        graph.entry(v2).or_default();
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: Graph<usize, i32> = BTreeMap::new();
+        assert!(floyd_warshall(&graph).is_empty());
+    }
+
+    #[test]
+    fn single_edge() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 2);
+
+        let dist = floyd_warshall(&graph);
+        assert_eq!(dist[&0][&0], 0);
+        assert_eq!(dist[&0][&1], 2);
+        assert_eq!(dist[&1][&1], 0);
+        // 0 is not reachable from 1
+        assert_eq!(dist[&1].get(&0), None);
+    }
+
+    #[test]
+    fn shortcut_through_intermediate() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 'a', 'b', 4);
+        add_edge(&mut graph, 'a', 'c', 1);
+        add_edge(&mut graph, 'c', 'b', 2);
+        add_edge(&mut graph, 'b', 'd', 1);
+        add_edge(&mut graph, 'c', 'd', 5);
+
+        let dist = floyd_warshall(&graph);
+        assert_eq!(dist[&'a'][&'b'], 3);
+        assert_eq!(dist[&'a'][&'d'], 4);
+        assert_eq!(dist[&'c'][&'d'], 3);
+        assert_eq!(dist[&'d'].len(), 1);
+    }
+
+    #[test]
+    fn triangle_inequality() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 7);
+        add_edge(&mut graph, 0, 2, 9);
+        add_edge(&mut graph, 0, 5, 14);
+        add_edge(&mut graph, 1, 2, 10);
+        add_edge(&mut graph, 1, 3, 15);
+        add_edge(&mut graph, 2, 3, 11);
+        add_edge(&mut graph, 2, 5, 2);
+        add_edge(&mut graph, 3, 4, 6);
+        add_edge(&mut graph, 5, 4, 9);
+        add_edge(&mut graph, 4, 0, 3);
+
+        let dist = floyd_warshall(&graph);
+        for (u, row) in &dist {
+            for (v, d_uv) in row {
+                for (w, d_vw) in &dist[v] {
+                    let d_uw = dist[u][w];
+                    assert!(d_uw <= *d_uv + *d_vw);
+                }
+            }
+            for (v, weight) in &graph[u] {
+                assert!(row[v] <= *weight);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 'a', 'c', 12);
+        add_edge(&mut graph, 'a', 'd', 60);
+        add_edge(&mut graph, 'b', 'a', 10);
+        add_edge(&mut graph, 'c', 'b', 20);
+        add_edge(&mut graph, 'c', 'd', 32);
+        add_edge(&mut graph, 'e', 'a', 7);
+
+        let dist = floyd_warshall(&graph);
+        for start in graph.keys() {
+            let expected: BTreeMap<char, i32> = dijkstra(&graph, start)
+                .into_iter()
+                .map(|(v, pred)| (v, pred.map_or(0, |(_, d)| d)))
+                .collect();
+            assert_eq!(dist[start], expected);
+        }
+    }
+
+    #[test]
+    fn negative_edges() {
+        let mut graph = BTreeMap::new();
+        add_edge(&mut graph, 0, 1, 4);
+        add_edge(&mut graph, 0, 2, 5);
+        add_edge(&mut graph, 2, 1, -3);
+        add_edge(&mut graph, 1, 3, 2);
+
+        let dist = floyd_warshall(&graph);
+        assert_eq!(dist[&0][&1], 2);
+        assert_eq!(dist[&0][&3], 4);
+        assert_eq!(dist[&2][&3], -1);
+    }
+}
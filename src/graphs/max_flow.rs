@@ -0,0 +1,204 @@
+use crate::data_structures::{DirectedGraph, Graph};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Computes the maximum flow from `source` to `sink` in a `DirectedGraph`, using the
+/// weight of every edge as its capacity.
+///
+/// The Edmonds-Karp algorithm keeps a residual graph, in which every edge is paired with
+/// a reverse edge, holding how much more flow can be pushed along each of them. A
+/// breadth-first search finds the shortest path from `source` to `sink` with room left
+/// on every edge, the bottleneck of that path is pushed along it, and the reverse edges
+/// get the same amount back so that later paths can cancel it. Once no such path exists,
+/// the flow is maximal. Since paths are found in order of length, this takes O(V * E^2)
+/// time and O(V + E) memory.
+///
+/// Parallel edges add up their capacities, and edges with a negative weight are
+/// treated as having no capacity. The flow is computed in `i64`, so it does not
+/// overflow even when many edges of capacity close to `i32::MAX` add up. The flow is 0
+/// if `source` and `sink` are the same node, or if either of them is not in the graph.
+pub fn edmonds_karp<'a, T>(graph: &DirectedGraph<'a, T>, source: &T, sink: &T) -> i64
+where
+    T: 'a + Eq + Hash,
+{
+    if source == sink || !graph.contains(source) || !graph.contains(sink) {
+        return 0;
+    }
+
+    let nodes: Vec<&T> = graph.nodes().into_iter().collect();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let (source, sink) = (index[source], index[sink]);
+
+    // edge `e` and its reverse `e ^ 1` are stored next to each other
+    let mut residual = ResidualGraph {
+        adjacency: vec![Vec::new(); nodes.len()],
+        to: Vec::new(),
+        capacity: Vec::new(),
+    };
+    for (from, to, capacity) in graph.edges() {
+        residual.add_edge(index[from], index[to], capacity.max(0) as i64);
+    }
+
+    let mut flow = 0;
+    while let Some(parent_edge) = residual.augmenting_path(source, sink) {
+        let mut bottleneck = i64::MAX;
+        let mut node = sink;
+        while node != source {
+            let edge = parent_edge[node];
+            bottleneck = bottleneck.min(residual.capacity[edge]);
+            node = residual.to[edge ^ 1];
+        }
+
+        let mut node = sink;
+        while node != source {
+            let edge = parent_edge[node];
+            residual.capacity[edge] -= bottleneck;
+            residual.capacity[edge ^ 1] += bottleneck;
+            node = residual.to[edge ^ 1];
+        }
+        flow += bottleneck;
+    }
+
+    flow
+}
+
+struct ResidualGraph {
+    /// The edges leaving every node
+    adjacency: Vec<Vec<usize>>,
+    /// The node every edge points to
+    to: Vec<usize>,
+    /// The capacity left on every edge
+    capacity: Vec<i64>,
+}
+
+impl ResidualGraph {
+    fn add_edge(&mut self, from: usize, to: usize, capacity: i64) {
+        self.adjacency[from].push(self.to.len());
+        self.to.push(to);
+        self.capacity.push(capacity);
+        self.adjacency[to].push(self.to.len());
+        self.to.push(from);
+        self.capacity.push(0);
+    }
+
+    /// Finds a shortest path from `source` to `sink` through edges with capacity left,
+    /// returning the edge each node was reached through.
+    fn augmenting_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut parent_edge = vec![usize::MAX; self.adjacency.len()];
+        let mut visited = vec![false; self.adjacency.len()];
+        visited[source] = true;
+        let mut queue = VecDeque::from(vec![source]);
+
+        while let Some(node) = queue.pop_front() {
+            for &edge in &self.adjacency[node] {
+                let next = self.to[edge];
+                if self.capacity[edge] > 0 && !visited[next] {
+                    visited[next] = true;
+                    parent_edge[next] = edge;
+                    if next == sink {
+                        return Some(parent_edge);
+                    }
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::edmonds_karp;
+    use crate::data_structures::{DirectedGraph, Graph};
+
+    fn graph_from<'a>(nodes: &'a [u32], edges: &[(usize, usize, i32)]) -> DirectedGraph<'a, u32> {
+        let mut graph = DirectedGraph::new();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for &(from, to, capacity) in edges {
+            graph.add_edge((&nodes[from], &nodes[to], capacity));
+        }
+        graph
+    }
+
+    #[test]
+    fn textbook_network() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let graph = graph_from(
+            &nodes,
+            &[
+                (0, 1, 16),
+                (0, 2, 13),
+                (1, 3, 12),
+                (2, 1, 4),
+                (2, 4, 14),
+                (3, 2, 9),
+                (3, 5, 20),
+                (4, 3, 7),
+                (4, 5, 4),
+            ],
+        );
+
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[5]), 23);
+        // nothing flows against the edges
+        assert_eq!(edmonds_karp(&graph, &nodes[5], &nodes[0]), 0);
+    }
+
+    #[test]
+    fn disconnected() {
+        let nodes: Vec<u32> = (0..4).collect();
+        let graph = graph_from(&nodes, &[(0, 1, 5), (2, 3, 5)]);
+
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[3]), 0);
+    }
+
+    #[test]
+    fn degenerate_endpoints() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let graph = graph_from(&nodes[..2], &[(0, 1, 5)]);
+
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[0]), 0);
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[2]), 0);
+    }
+
+    #[test]
+    fn diamond_with_cross_edge() {
+        let nodes: Vec<u32> = (0..4).collect();
+        // the edge 1 -> 2 adds nothing, as both of its ends already reach 3 directly
+        let graph = graph_from(
+            &nodes,
+            &[(0, 1, 1), (0, 2, 1), (1, 2, 1), (1, 3, 1), (2, 3, 1)],
+        );
+
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[3]), 2);
+    }
+
+    #[test]
+    fn parallel_edges_add_up() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let graph = graph_from(&nodes, &[(0, 1, 3), (0, 1, 4), (1, 2, 10), (0, 2, 1)]);
+
+        assert_eq!(edmonds_karp(&graph, &nodes[0], &nodes[2]), 8);
+    }
+
+    #[test]
+    fn flow_beyond_i32() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let graph = graph_from(
+            &nodes,
+            &[
+                (0, 1, i32::MAX),
+                (0, 1, i32::MAX),
+                (1, 2, i32::MAX),
+                (1, 2, i32::MAX),
+            ],
+        );
+
+        assert_eq!(
+            edmonds_karp(&graph, &nodes[0], &nodes[2]),
+            2 * i32::MAX as i64
+        );
+    }
+}
@@ -1,4 +1,7 @@
 use super::DisjointSetUnion;
+use crate::data_structures::{Graph, UndirectedGraph, UnionFind};
+use std::collections::HashMap;
+use std::hash::Hash;
 
 #[derive(Debug)]
 pub struct Edge {
@@ -52,6 +55,42 @@ pub fn kruskal(mut edges: Vec<Edge>, number_of_vertices: i64) -> (i64, Vec<Edge>
     (total_cost, final_edges)
 }
 
+/// Kruskal's algorithm on an `UndirectedGraph`, using `UnionFind` to skip the
+/// edges that would close a cycle.
+///
+/// Returns the edges of a minimum spanning forest, one tree per connected
+/// component, in the order they were picked. Every edge is given with its
+/// smaller endpoint first. Edges of equal weight are picked in the order of
+/// their endpoints, so the result does not depend on the graph's iteration order.
+pub fn kruskal_undirected_graph<'a, T>(graph: &UndirectedGraph<'a, T>) -> Vec<(&'a T, &'a T, i32)>
+where
+    T: 'a + Ord + Hash,
+{
+    let index: HashMap<&'a T, usize> = graph
+        .nodes()
+        .into_iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect();
+
+    // Every edge is stored in both directions, keep one copy of each.
+    let mut edges: Vec<(&'a T, &'a T, i32)> = graph
+        .edges()
+        .into_iter()
+        .filter(|(from, to, _)| from < to)
+        .collect();
+    edges.sort_unstable_by_key(|&(from, to, weight)| (weight, from, to));
+
+    let mut sets = UnionFind::new(index.len());
+    let mut forest = Vec::new();
+    for (from, to, weight) in edges {
+        if sets.union(index[from], index[to]) {
+            forest.push((from, to, weight));
+        }
+    }
+    forest
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +170,83 @@ mod tests {
         assert_eq!(actual_total_cost, expected_total_cost);
         assert_eq!(actual_final_edges, expected_used_edges);
     }
+
+    fn total_weight(edges: &[(&u32, &u32, i32)]) -> i32 {
+        edges.iter().map(|(_, _, weight)| weight).sum()
+    }
+
+    #[test]
+    fn undirected_graph_seven_vertices() {
+        let nodes: Vec<u32> = (0..7).collect();
+        let mut graph = UndirectedGraph::new();
+        for &(from, to, weight) in [
+            (0, 1, 7),
+            (0, 3, 5),
+            (1, 2, 8),
+            (1, 3, 9),
+            (1, 4, 7),
+            (2, 4, 5),
+            (3, 4, 15),
+            (3, 5, 6),
+            (4, 5, 8),
+            (4, 6, 9),
+            (5, 6, 11),
+        ]
+        .iter()
+        {
+            graph.add_edge((&nodes[from], &nodes[to], weight));
+        }
+
+        let tree = kruskal_undirected_graph(&graph);
+        assert_eq!(total_weight(&tree), 39);
+        assert_eq!(
+            tree,
+            vec![
+                (&0, &3, 5),
+                (&2, &4, 5),
+                (&3, &5, 6),
+                (&0, &1, 7),
+                (&1, &4, 7),
+                (&4, &6, 9)
+            ]
+        );
+    }
+
+    #[test]
+    fn undirected_graph_equal_weights() {
+        // A square with a diagonal, all of weight 1: any 3 edges without the
+        // cycle are optimal, ties are broken by the endpoints.
+        let nodes: Vec<u32> = (0..4).collect();
+        for _ in 0..5 {
+            let mut graph = UndirectedGraph::new();
+            graph.add_edge((&nodes[2], &nodes[3], 1));
+            graph.add_edge((&nodes[3], &nodes[0], 1));
+            graph.add_edge((&nodes[1], &nodes[2], 1));
+            graph.add_edge((&nodes[0], &nodes[1], 1));
+            graph.add_edge((&nodes[0], &nodes[2], 1));
+
+            assert_eq!(
+                kruskal_undirected_graph(&graph),
+                vec![(&0, &1, 1), (&0, &2, 1), (&0, &3, 1)]
+            );
+        }
+    }
+
+    #[test]
+    fn undirected_graph_forest() {
+        let nodes: Vec<u32> = (0..7).collect();
+        let mut graph = UndirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 4));
+        graph.add_edge((&nodes[1], &nodes[2], 2));
+        graph.add_edge((&nodes[0], &nodes[2], 3));
+        graph.add_edge((&nodes[3], &nodes[4], 10));
+        graph.add_edge((&nodes[4], &nodes[5], -2));
+        graph.add_edge((&nodes[3], &nodes[5], 1));
+        graph.add_node(&nodes[6]);
+
+        let forest = kruskal_undirected_graph(&graph);
+        // one edge less than the number of nodes per component
+        assert_eq!(forest.len(), (3 - 1) + (3 - 1));
+        assert_eq!(total_weight(&forest), 2 + 3 - 2 + 1);
+    }
 }
@@ -1,35 +1,53 @@
 //! This module provides graph based operations.
+mod a_star;
+mod articulation;
 mod bellman_ford;
+mod bipartite;
 mod breadth_first_search;
 mod centroid_decomposition;
+mod connected_components;
+mod cycle_detection;
 mod depth_first_search;
 mod depth_first_search_tic_tac_toe;
 mod dijkstra;
 mod dinic_maxflow;
 mod disjoint_set_union;
+mod floyd_warshall;
 mod graph_enumeration;
 mod heavy_light_decomposition;
 mod lowest_common_ancestor;
+mod max_flow;
 mod minimum_spanning_tree;
 mod prim;
 mod prufer_code;
 mod representation;
 mod strongly_connected_components;
 mod topological_sort;
+mod vertex_cover;
 
-pub use self::bellman_ford::bellman_ford;
-pub use self::breadth_first_search::breadth_first_search;
+pub use self::a_star::a_star;
+pub use self::articulation::{articulation_points, bridges};
+pub use self::bellman_ford::{bellman_ford, NegativeCycle};
+pub use self::bipartite::is_bipartite;
+pub use self::breadth_first_search::{bfs_distances, bfs_path, breadth_first_search};
 pub use self::centroid_decomposition::CentroidDecomposition;
+pub use self::connected_components::connected_components;
+pub use self::cycle_detection::has_cycle;
 pub use self::depth_first_search::depth_first_search;
 pub use self::depth_first_search_tic_tac_toe::minimax;
-pub use self::dijkstra::dijkstra;
+pub use self::dijkstra::{dijkstra, dijkstra_path};
 pub use self::dinic_maxflow::DinicMaxFlow;
 pub use self::disjoint_set_union::DisjointSetUnion;
+pub use self::floyd_warshall::floyd_warshall;
 pub use self::graph_enumeration::enumerate_graph;
 pub use self::heavy_light_decomposition::HeavyLightDecomposition;
 pub use self::lowest_common_ancestor::*;
-pub use self::minimum_spanning_tree::kruskal;
-pub use self::prim::{prim, prim_with_start};
+pub use self::max_flow::edmonds_karp;
+pub use self::minimum_spanning_tree::{kruskal, kruskal_undirected_graph};
+pub use self::prim::{prim, prim_undirected_graph, prim_with_start};
 pub use self::prufer_code::{prufer_decode, prufer_encode};
-pub use self::strongly_connected_components::StronglyConnectedComponents;
-pub use self::topological_sort::topological_sort;
+pub use self::strongly_connected_components::{
+    strongly_connected_components, StronglyConnectedComponents,
+};
+pub use self::topological_sort::{topological_sort, topological_sort_directed_graph, CycleError};
+pub use self::vertex_cover::vertex_cover_2approx;
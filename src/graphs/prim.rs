@@ -1,5 +1,7 @@
+use crate::data_structures::{Graph as _, UndirectedGraph};
 use std::cmp::Reverse;
-use std::collections::{BTreeMap, BinaryHeap};
+use std::collections::{BTreeMap, BinaryHeap, HashSet};
+use std::hash::Hash;
 use std::ops::Add;
 
 type Graph<V, E> = BTreeMap<V, BTreeMap<V, E>>;
@@ -57,9 +59,55 @@ pub fn prim_with_start<V: Ord + Copy, E: Ord + Add + Copy>(
     mst
 }
 
+// Prim's algorithm on an `UndirectedGraph`, growing the tree from `start`.
+// The frontier is a binary heap of the edges leaving the tree, cheapest first.
+//
+// Returns the edges of the minimum spanning tree of the component containing
+// `start`, in the order they were added, each as (tree vertex, new vertex, weight).
+// If `start` is not in the graph, no edge is returned.
+pub fn prim_undirected_graph<'a, T>(
+    graph: &UndirectedGraph<'a, T>,
+    start: &'a T,
+) -> Vec<(&'a T, &'a T, i32)>
+where
+    T: 'a + Ord + Hash,
+{
+    let mut tree = Vec::new();
+    if !graph.contains(start) {
+        return tree;
+    }
+
+    let mut visited: HashSet<&'a T> = HashSet::new();
+    let mut frontier = BinaryHeap::new();
+    let mut next = Some(start);
+
+    while let Some(node) = next.take() {
+        visited.insert(node);
+        for (neighbour, weight) in graph.neighbours(node).unwrap() {
+            if !visited.contains(neighbour) {
+                frontier.push(Reverse((*weight, node, *neighbour)));
+            }
+        }
+
+        while let Some(Reverse((weight, from, to))) = frontier.pop() {
+            // Both ends are already in the tree.
+            if visited.contains(to) {
+                continue;
+            }
+            tree.push((from, to, weight));
+            next = Some(to);
+            break;
+        }
+    }
+
+    tree
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{add_edge, prim, Graph};
+    use super::{add_edge, prim, prim_undirected_graph, Graph};
+    use crate::data_structures::{Graph as _, UndirectedGraph};
+    use crate::graphs::kruskal_undirected_graph;
     use std::collections::BTreeMap;
 
     #[test]
@@ -196,4 +244,147 @@ mod tests {
 
         assert_eq!(prim(&graph), ans);
     }
+
+    fn total_weight(edges: &[(&u32, &u32, i32)]) -> i32 {
+        edges.iter().map(|(_, _, w)| w).sum()
+    }
+
+    fn undirected_graph<'a>(
+        nodes: &'a [u32],
+        edges: &[(usize, usize, i32)],
+    ) -> UndirectedGraph<'a, u32> {
+        let mut graph = UndirectedGraph::new();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for &(from, to, weight) in edges {
+            graph.add_edge((&nodes[from], &nodes[to], weight));
+        }
+        graph
+    }
+
+    /// Checks that the result spans `expected` nodes, grows from `start`,
+    /// and weighs as much as the tree Kruskal finds.
+    fn check_against_kruskal(graph: &UndirectedGraph<u32>, start: &u32, expected: usize) {
+        let tree = prim_undirected_graph(graph, start);
+        assert_eq!(tree.len() + 1, expected);
+        if let Some((first, _, _)) = tree.first() {
+            assert_eq!(*first, start);
+        }
+        for (from, to, weight) in &tree {
+            assert!(graph.neighbours(from).unwrap().contains(&(to, *weight)));
+        }
+        assert_eq!(
+            total_weight(&tree),
+            total_weight(&kruskal_undirected_graph(graph))
+        );
+    }
+
+    #[test]
+    fn undirected_missing_start() {
+        let nodes = [0, 1, 2];
+        let graph = undirected_graph(&nodes, &[(0, 1, 4)]);
+        assert!(prim_undirected_graph(&graph, &7).is_empty());
+    }
+
+    #[test]
+    fn undirected_single_node() {
+        let nodes = [0];
+        let graph = undirected_graph(&nodes, &[]);
+        assert!(prim_undirected_graph(&graph, &nodes[0]).is_empty());
+    }
+
+    #[test]
+    fn undirected_matches_kruskal() {
+        let nodes: Vec<u32> = (0..9).collect();
+        // the classic nine vertex example, whose minimum spanning tree weighs 37
+        let graph = undirected_graph(
+            &nodes,
+            &[
+                (0, 1, 4),
+                (0, 7, 8),
+                (1, 2, 8),
+                (1, 7, 11),
+                (2, 3, 7),
+                (2, 8, 2),
+                (2, 5, 4),
+                (3, 4, 9),
+                (3, 5, 14),
+                (4, 5, 10),
+                (5, 6, 2),
+                (6, 7, 1),
+                (6, 8, 6),
+                (7, 8, 7),
+            ],
+        );
+        for start in &nodes {
+            check_against_kruskal(&graph, start, nodes.len());
+        }
+        assert_eq!(total_weight(&prim_undirected_graph(&graph, &nodes[0])), 37);
+    }
+
+    #[test]
+    fn undirected_negative_and_equal_weights() {
+        let nodes: Vec<u32> = (0..6).collect();
+        let graph = undirected_graph(
+            &nodes,
+            &[
+                (0, 1, -3),
+                (0, 2, 1),
+                (1, 2, 1),
+                (1, 3, 1),
+                (2, 4, -1),
+                (3, 4, 1),
+                (3, 5, 0),
+                (4, 5, 0),
+            ],
+        );
+        for start in &nodes {
+            check_against_kruskal(&graph, start, nodes.len());
+        }
+    }
+
+    #[test]
+    fn undirected_dense_graph() {
+        let nodes: Vec<u32> = (0..20).collect();
+        let mut edges = Vec::new();
+        for i in 0..nodes.len() {
+            for j in i + 1..nodes.len() {
+                edges.push((i, j, ((i * 31 + j * 17) % 23) as i32));
+            }
+        }
+        let graph = undirected_graph(&nodes, &edges);
+        check_against_kruskal(&graph, &nodes[0], nodes.len());
+        check_against_kruskal(&graph, &nodes[13], nodes.len());
+    }
+
+    #[test]
+    fn undirected_only_spans_start_component() {
+        let nodes: Vec<u32> = (0..7).collect();
+        let graph = undirected_graph(
+            &nodes,
+            &[
+                (0, 1, 5),
+                (1, 2, 1),
+                (0, 2, 2),
+                (3, 4, 1),
+                (4, 5, 3),
+                (3, 5, 2),
+            ],
+        );
+
+        let tree = prim_undirected_graph(&graph, &nodes[4]);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(total_weight(&tree), 3);
+        for (from, to, _) in tree {
+            assert!((3..=5).contains(from));
+            assert!((3..=5).contains(to));
+        }
+
+        let tree = prim_undirected_graph(&graph, &nodes[0]);
+        assert_eq!(total_weight(&tree), 3);
+
+        // an isolated node is a tree on its own
+        assert!(prim_undirected_graph(&graph, &nodes[6]).is_empty());
+    }
 }
@@ -1,8 +1,13 @@
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Vertex(u32);
 
+// An edge from the first vertex to the second one, with a weight.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub struct Edge(u32, u32);
+pub struct Edge(u32, u32, i32);
+
+// The weight given to edges built without one, so that unweighted graphs
+// measure distances in number of edges.
+pub const DEFAULT_WEIGHT: i32 = 1;
 
 #[derive(Debug, Clone)]
 pub struct Graph {
@@ -23,18 +28,62 @@ impl From<u32> for Vertex {
 }
 
 impl Vertex {
-    pub fn neighbors(&self, graph: &Graph) -> Vec<Vertex> {
+    // Returns the vertices that the edges leaving this one lead to, along with
+    // the weights of those edges.
+    pub fn neighbors(&self, graph: &Graph) -> Vec<(Vertex, i32)> {
         graph
             .edges
             .iter()
             .filter(|e| e.0 == self.0)
-            .map(|e| e.1.into())
+            .map(|e| (e.1.into(), e.2))
             .collect()
     }
 }
 
+impl Edge {
+    pub fn weight(&self) -> i32 {
+        self.2
+    }
+}
+
 impl From<(u32, u32)> for Edge {
     fn from(item: (u32, u32)) -> Self {
-        Edge(item.0, item.1)
+        Edge(item.0, item.1, DEFAULT_WEIGHT)
+    }
+}
+
+impl From<(u32, u32, i32)> for Edge {
+    fn from(item: (u32, u32, i32)) -> Self {
+        Edge(item.0, item.1, item.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_weight() {
+        let edge: Edge = (1, 2).into();
+        assert_eq!(edge.weight(), DEFAULT_WEIGHT);
+        assert_eq!(edge, (1, 2, 1).into());
+    }
+
+    #[test]
+    fn weighted_neighbors() {
+        let vertices = vec![1, 2, 3, 4];
+        let edges: Vec<Edge> = vec![(1, 2, 5).into(), (1, 3, -2).into(), (3, 4).into()];
+
+        let graph = Graph::new(vertices.into_iter().map(|v| v.into()).collect(), edges);
+
+        assert_eq!(
+            Vertex::from(1).neighbors(&graph),
+            vec![(2.into(), 5), (3.into(), -2)]
+        );
+        assert_eq!(
+            Vertex::from(3).neighbors(&graph),
+            vec![(4.into(), DEFAULT_WEIGHT)]
+        );
+        assert!(Vertex::from(4).neighbors(&graph).is_empty());
     }
 }
@@ -8,6 +8,10 @@ and its vertices are numbered from 1 to n. If this is not the case, one
 can use `src/graph/graph_enumeration.rs` to convert their graph.
 */
 
+use crate::data_structures::{DirectedGraph, Graph};
+use std::collections::HashMap;
+use std::hash::Hash;
+
 pub struct StronglyConnectedComponents {
     // The number of the SCC the vertex is in, starting from 1
     pub component: Vec<usize>,
@@ -102,6 +106,33 @@ impl StronglyConnectedComponents {
     }
 }
 
+// Finds the SCCs of a `DirectedGraph` by numbering its nodes from 1 to n and
+// running `StronglyConnectedComponents` on the resulting adjacency lists.
+// Components are returned in reverse topological order: no edge goes from a
+// component to one that comes after it. The order of the nodes inside a
+// component is unspecified.
+pub fn strongly_connected_components<'a, T>(graph: &DirectedGraph<'a, T>) -> Vec<Vec<&'a T>>
+where
+    T: 'a + Eq + Hash,
+{
+    let nodes: Vec<&'a T> = graph.nodes().into_iter().collect();
+    let index: HashMap<&'a T, usize> = nodes.iter().enumerate().map(|(i, u)| (*u, i + 1)).collect();
+
+    let mut adj = vec![vec![]; nodes.len() + 1];
+    for (u, v, _) in graph.edges() {
+        adj[index[u]].push(index[v]);
+    }
+
+    let mut sccs = StronglyConnectedComponents::new(nodes.len());
+    sccs.find_components(&adj);
+
+    let mut components = vec![vec![]; sccs.num_components];
+    for (i, node) in nodes.into_iter().enumerate() {
+        components[sccs.component[i + 1] - 1].push(node);
+    }
+    components
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +192,70 @@ mod tests {
         assert_eq!(sccs.state, vec![0, 1, 2, 3, 4, 5, 6]);
         assert_eq!(sccs.num_components, 1);
     }
+
+    /// Sorts the components so they can be compared regardless of order.
+    fn normalize(components: &[Vec<&char>]) -> Vec<Vec<char>> {
+        let mut components: Vec<Vec<char>> = components
+            .iter()
+            .map(|component| {
+                let mut component: Vec<char> = component.iter().copied().copied().collect();
+                component.sort_unstable();
+                component
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn directed_graph_two_cycles_and_sink() {
+        let nodes = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+        let mut graph = DirectedGraph::new();
+        // a -> b -> c -> a and d -> e -> d, the first reaching the second
+        // through c -> f -> d, which in turn reaches the sink g through e -> g
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[2], 1));
+        graph.add_edge((&nodes[2], &nodes[0], 1));
+        graph.add_edge((&nodes[3], &nodes[4], 1));
+        graph.add_edge((&nodes[4], &nodes[3], 1));
+        graph.add_edge((&nodes[2], &nodes[5], 1));
+        graph.add_edge((&nodes[5], &nodes[3], 1));
+        graph.add_edge((&nodes[4], &nodes[6], 1));
+
+        let components = strongly_connected_components(&graph);
+        assert_eq!(
+            normalize(&components),
+            vec![vec!['a', 'b', 'c'], vec!['d', 'e'], vec!['f'], vec!['g']]
+        );
+        // reverse topological order: the sink comes first, the source cycle last
+        assert_eq!(components[0], vec![&'g']);
+        assert_eq!(normalize(&components[3..]), vec![vec!['a', 'b', 'c']]);
+
+        // closing the loop back to the first cycle merges everything but the sink
+        graph.add_edge((&nodes[4], &nodes[0], 1));
+        assert_eq!(
+            normalize(&strongly_connected_components(&graph)),
+            vec![vec!['a', 'b', 'c', 'd', 'e', 'f'], vec!['g']]
+        );
+    }
+
+    #[test]
+    fn directed_graph_self_loops_and_single_nodes() {
+        let nodes = ['a', 'b', 'c'];
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[0], 1));
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_node(&nodes[2]);
+
+        assert_eq!(
+            normalize(&strongly_connected_components(&graph)),
+            vec![vec!['a'], vec!['b'], vec!['c']]
+        );
+    }
+
+    #[test]
+    fn directed_graph_empty() {
+        let graph: DirectedGraph<char> = DirectedGraph::new();
+        assert!(strongly_connected_components(&graph).is_empty());
+    }
 }
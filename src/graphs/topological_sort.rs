@@ -1,7 +1,21 @@
-use std::collections::{BTreeMap, VecDeque};
+use crate::data_structures::{DirectedGraph, Graph as _};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
 
 type Graph<V, E> = BTreeMap<V, Vec<(V, E)>>;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError;
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph contains a cycle")
+    }
+}
+
+impl std::error::Error for CycleError {}
+
 /// returns topological sort of the graph using Kahn's algorithm
 pub fn topological_sort<V: Ord + Copy, E: Ord>(graph: &Graph<V, E>) -> Vec<V> {
     let mut visited = BTreeMap::new();
@@ -36,11 +50,50 @@ pub fn topological_sort<V: Ord + Copy, E: Ord>(graph: &Graph<V, E>) -> Vec<V> {
     ret
 }
 
+/// returns a topological sort of a `DirectedGraph` using Kahn's algorithm,
+/// or `CycleError` if the graph has a cycle and so no such order exists
+pub fn topological_sort_directed_graph<'a, T>(
+    graph: &DirectedGraph<'a, T>,
+) -> Result<Vec<&'a T>, CycleError>
+where
+    T: 'a + Eq + Hash,
+{
+    let mut in_degree: HashMap<&'a T, usize> = graph.nodes().into_iter().map(|u| (u, 0)).collect();
+    for (_, v, _) in graph.edges() {
+        *in_degree.get_mut(v).unwrap() += 1;
+    }
+
+    let mut queue: VecDeque<&'a T> = in_degree
+        .iter()
+        .filter(|(_, d)| **d == 0)
+        .map(|(u, _)| *u)
+        .collect();
+    let mut order = Vec::with_capacity(in_degree.len());
+    while let Some(u) = queue.pop_front() {
+        order.push(u);
+        for (v, _) in graph.neighbours(u).unwrap() {
+            let degree = in_degree.get_mut(v).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(*v);
+            }
+        }
+    }
+
+    // the nodes of a cycle never reach an in-degree of zero
+    if order.len() == in_degree.len() {
+        Ok(order)
+    } else {
+        Err(CycleError)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
-    use super::{topological_sort, Graph};
+    use super::{topological_sort, topological_sort_directed_graph, CycleError, Graph};
+    use crate::data_structures::{DirectedGraph, Graph as _};
     fn add_edge<V: Ord + Copy, E: Ord>(graph: &mut Graph<V, E>, from: V, to: V, weight: E) {
         let edges = graph.entry(from).or_insert(Vec::new());
         edges.push((to, weight));
@@ -59,4 +112,64 @@ mod tests {
 
         assert_eq!(topological_sort(&graph), vec![1, 2, 3, 4, 5, 6, 7]);
     }
+
+    /// Checks that `order` contains every node once and puts every edge forwards.
+    fn assert_topological(graph: &DirectedGraph<char>, order: &[&char]) {
+        assert_eq!(order.len(), graph.nodes().len());
+        let position: BTreeMap<&char, usize> =
+            order.iter().enumerate().map(|(i, u)| (*u, i)).collect();
+        assert_eq!(position.len(), order.len());
+        for (u, v, _) in graph.edges() {
+            assert!(position[u] < position[v], "{} must come before {}", u, v);
+        }
+    }
+
+    #[test]
+    fn directed_graph_dag() {
+        let nodes = ['a', 'b', 'c', 'd', 'e', 'f'];
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[0], &nodes[2], 1));
+        graph.add_edge((&nodes[1], &nodes[3], 1));
+        graph.add_edge((&nodes[2], &nodes[3], 1));
+        graph.add_edge((&nodes[3], &nodes[4], 1));
+        graph.add_node(&nodes[5]);
+
+        let order = topological_sort_directed_graph(&graph).unwrap();
+        assert_topological(&graph, &order);
+    }
+
+    #[test]
+    fn directed_graph_empty() {
+        let graph: DirectedGraph<char> = DirectedGraph::new();
+        assert_eq!(topological_sort_directed_graph(&graph), Ok(vec![]));
+    }
+
+    #[test]
+    fn directed_graph_three_cycle() {
+        let nodes = ['a', 'b', 'c', 'd'];
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[3], &nodes[0], 1));
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[2], 1));
+        graph.add_edge((&nodes[2], &nodes[0], 1));
+
+        assert_eq!(topological_sort_directed_graph(&graph), Err(CycleError));
+    }
+
+    #[test]
+    fn cycle_is_an_error() {
+        let nodes = ['a', 'b'];
+        let mut graph = DirectedGraph::new();
+        graph.add_edge((&nodes[0], &nodes[1], 1));
+        graph.add_edge((&nodes[1], &nodes[0], 1));
+
+        let order = || -> Result<usize, Box<dyn std::error::Error>> {
+            Ok(topological_sort_directed_graph(&graph)?.len())
+        };
+        assert_eq!(
+            order().unwrap_err().to_string(),
+            "the graph contains a cycle"
+        );
+    }
 }
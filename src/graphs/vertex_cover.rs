@@ -0,0 +1,125 @@
+use crate::data_structures::{Graph, UndirectedGraph};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns a vertex cover of an `UndirectedGraph`, a set of nodes touching every edge,
+/// at most twice as large as the smallest one.
+///
+/// Every edge with neither end in the cover yet gets both of its ends added. The edges
+/// picked this way share no node, so they form a matching, and any cover needs a
+/// distinct node for each of them. The cover holds two nodes per picked edge, hence at
+/// most twice the optimum. Finding the smallest cover is NP-hard, while this takes
+/// linear time in the number of edges.
+pub fn vertex_cover_2approx<'a, T>(graph: &UndirectedGraph<'a, T>) -> HashSet<&'a T>
+where
+    T: 'a + Eq + Hash,
+{
+    let mut cover: HashSet<&'a T> = HashSet::new();
+    for (from, to, _) in graph.edges() {
+        if !cover.contains(from) && !cover.contains(to) {
+            cover.insert(from);
+            cover.insert(to);
+        }
+    }
+    cover
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vertex_cover_2approx;
+    use crate::data_structures::{Graph, UndirectedGraph};
+    use std::collections::HashSet;
+
+    fn graph_from<'a>(nodes: &'a [u32], edges: &[(usize, usize)]) -> UndirectedGraph<'a, u32> {
+        let mut graph = UndirectedGraph::new();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for &(from, to) in edges {
+            graph.add_edge((&nodes[from], &nodes[to], 1));
+        }
+        graph
+    }
+
+    fn assert_covers(graph: &UndirectedGraph<u32>, cover: &HashSet<&u32>) {
+        for (from, to, _) in graph.edges() {
+            assert!(
+                cover.contains(from) || cover.contains(to),
+                "edge {} - {} is not covered",
+                from,
+                to
+            );
+        }
+    }
+
+    /// The size of a smallest vertex cover, trying every subset of the nodes.
+    fn optimal_cover_size(nodes: &[u32], edges: &[(usize, usize)]) -> usize {
+        (0u32..1 << nodes.len())
+            .filter(|mask| {
+                edges
+                    .iter()
+                    .all(|&(a, b)| mask & (1 << a) != 0 || mask & (1 << b) != 0)
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    fn check(nodes: &[u32], edges: &[(usize, usize)]) {
+        let graph = graph_from(nodes, edges);
+        let cover = vertex_cover_2approx(&graph);
+        assert_covers(&graph, &cover);
+        assert!(cover.len() <= 2 * optimal_cover_size(nodes, edges));
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: UndirectedGraph<u32> = UndirectedGraph::new();
+        assert!(vertex_cover_2approx(&graph).is_empty());
+    }
+
+    #[test]
+    fn no_edges() {
+        let nodes: Vec<u32> = (0..4).collect();
+        let graph = graph_from(&nodes, &[]);
+        assert!(vertex_cover_2approx(&graph).is_empty());
+    }
+
+    #[test]
+    fn path() {
+        let nodes: Vec<u32> = (0..6).collect();
+        check(&nodes, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+    }
+
+    #[test]
+    fn triangle() {
+        let nodes: Vec<u32> = (0..3).collect();
+        let edges = [(0, 1), (1, 2), (2, 0)];
+        check(&nodes, &edges);
+        // one edge is picked, and its two ends cover the third node's edges too
+        assert_eq!(vertex_cover_2approx(&graph_from(&nodes, &edges)).len(), 2);
+    }
+
+    #[test]
+    fn star_and_self_loop() {
+        let nodes: Vec<u32> = (0..6).collect();
+        check(&nodes, &[(0, 1), (0, 2), (0, 3), (0, 4), (5, 5)]);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = crate::math::PCG32::new_default(3);
+        let nodes: Vec<u32> = (0..10).collect();
+        for _ in 0..50 {
+            let edges: Vec<(usize, usize)> = (0..12)
+                .map(|_| {
+                    (
+                        rng.get_u32() as usize % nodes.len(),
+                        rng.get_u32() as usize % nodes.len(),
+                    )
+                })
+                .collect();
+            check(&nodes, &edges);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// Chinese Remainder Theorem
+///
+/// Solves the system of congruences x = residues[i] (mod moduli[i]) by folding
+/// the congruences one at a time: if x = a (mod m) already holds, the next
+/// congruence x = r (mod n) is satisfied by x = a + m * k, where
+/// k = (r - a) * m^-1 (mod n). The inverse comes from the extended Euclidean
+/// algorithm.
+///
+/// Wikipedia reference: https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+///
+/// Returns the unique solution in [0, product of the moduli), or `None` if the
+/// moduli are not positive and pairwise coprime, if `residues` and `moduli` have
+/// different lengths, or if the product of the moduli does not fit in an `i64`.
+/// An empty system is solved by 0.
+use super::extended_gcd;
+
+pub fn crt(residues: &[i64], moduli: &[i64]) -> Option<i64> {
+    if residues.len() != moduli.len() {
+        return None;
+    }
+
+    // x = solution (mod product), both kept in [0, product)
+    let mut solution: i64 = 0;
+    let mut product: i64 = 1;
+    for (&residue, &modulus) in residues.iter().zip(moduli) {
+        if modulus <= 0 {
+            return None;
+        }
+        let (g, inverse, _) = extended_gcd(product, modulus);
+        if g != 1 {
+            return None;
+        }
+
+        // i128 keeps the intermediate products from overflowing
+        let modulus_wide = modulus as i128;
+        let k = ((residue as i128 - solution as i128) * inverse as i128).rem_euclid(modulus_wide);
+        let next_product = product.checked_mul(modulus)?;
+        solution = (solution as i128 + product as i128 * k) as i64;
+        product = next_product;
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crt;
+
+    #[test]
+    fn classic_example() {
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some(23));
+    }
+
+    #[test]
+    fn solution_satisfies_every_congruence() {
+        let moduli = [4, 9, 25, 49, 11];
+        let residues = [3, 8, 1, 40, 0];
+        let x = crt(&residues, &moduli).unwrap();
+        assert!((0..4 * 9 * 25 * 49 * 11).contains(&x));
+        for (r, m) in residues.iter().zip(moduli.iter()) {
+            assert_eq!(x % m, *r);
+        }
+    }
+
+    #[test]
+    fn negative_and_large_residues() {
+        // -1 = 2 (mod 3) and 13 = 3 (mod 5)
+        assert_eq!(crt(&[-1, 13, 2], &[3, 5, 7]), Some(23));
+    }
+
+    #[test]
+    fn single_congruence() {
+        assert_eq!(crt(&[17], &[5]), Some(2));
+        assert_eq!(crt(&[0], &[1]), Some(0));
+    }
+
+    #[test]
+    fn empty_system() {
+        assert_eq!(crt(&[], &[]), Some(0));
+    }
+
+    #[test]
+    fn inconsistent_system() {
+        // x = 1 (mod 4) makes x odd, x = 0 (mod 6) makes it even
+        assert_eq!(crt(&[1, 0], &[4, 6]), None);
+    }
+
+    #[test]
+    fn non_coprime_moduli() {
+        assert_eq!(crt(&[1, 1], &[4, 6]), None);
+        assert_eq!(crt(&[2, 2], &[7, 7]), None);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(crt(&[1, 2], &[3]), None);
+        assert_eq!(crt(&[1], &[0]), None);
+        assert_eq!(crt(&[1], &[-5]), None);
+    }
+
+    #[test]
+    fn large_moduli() {
+        let moduli = [1_000_000_007, 998_244_353];
+        let residues = [123_456_789, 987_654_321];
+        let x = crt(&residues, &moduli).unwrap();
+        assert_eq!(x % moduli[0], residues[0]);
+        assert_eq!(x % moduli[1], residues[1]);
+
+        // the product no longer fits in an i64
+        assert_eq!(
+            crt(&[1, 2, 3], &[1_000_000_007, 998_244_353, 1_000_000_009]),
+            None
+        );
+    }
+}
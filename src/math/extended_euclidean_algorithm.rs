@@ -1,4 +1,7 @@
-fn update_step(a: &mut i32, old_a: &mut i32, quotient: i32) {
+use std::convert::TryFrom;
+use std::ops::{Mul, Sub};
+
+fn update_step<T: Copy + Sub<Output = T> + Mul<Output = T>>(a: &mut T, old_a: &mut T, quotient: T) {
     let temp = *a;
     *a = *old_a - quotient * temp;
     *old_a = temp;
@@ -20,6 +23,40 @@ pub fn extended_euclidean_algorithm(a: i32, b: i32) -> (i32, i32, i32) {
     (old_r, old_s, old_t)
 }
 
+/// Returns `(g, x, y)` where `g` is the non-negative greatest common divisor
+/// of `a` and `b`, and `x` and `y` are Bézout coefficients: `a * x + b * y == g`.
+///
+/// `extended_gcd(0, 0)` is `(0, 1, 0)`. The steps are computed in `i128`, so inputs
+/// such as `i64::MIN` do not overflow along the way.
+///
+/// # Panics
+///
+/// Panics if the greatest common divisor is `2^63`, which does not fit in an `i64`.
+/// That only happens when one input is `i64::MIN` and the other is `0` or `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut rem) = (a as i128, b as i128);
+    let (mut old_s, mut coeff_s) = (1i128, 0i128);
+    let (mut old_t, mut coeff_t) = (0i128, 1i128);
+
+    while rem != 0 {
+        let quotient = old_r / rem;
+
+        update_step(&mut rem, &mut old_r, quotient);
+        update_step(&mut coeff_s, &mut old_s, quotient);
+        update_step(&mut coeff_t, &mut old_t, quotient);
+    }
+
+    if old_r < 0 {
+        old_r = -old_r;
+        old_s = -old_s;
+        old_t = -old_t;
+    }
+    let g = i64::try_from(old_r).expect("the gcd 2^63 does not fit in an i64");
+    // the coefficients are at most half of the inputs divided by g, except when one
+    // input is zero and the other coefficient is 1 or -1
+    (g, old_s as i64, old_t as i64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,4 +71,76 @@ mod tests {
         assert_eq!(extended_euclidean_algorithm(33, 44), (11, -1, 1));
         assert_eq!(extended_euclidean_algorithm(50, 70), (10, 3, -2));
     }
+
+    fn assert_bezout(a: i64, b: i64) {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(a * x + b * y, g, "Bézout identity for ({}, {})", a, b);
+        assert!(g >= 0);
+        if g != 0 {
+            assert_eq!(a % g, 0);
+            assert_eq!(b % g, 0);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_positive() {
+        assert_eq!(extended_gcd(101, 13), (1, 4, -31));
+        assert_eq!(extended_gcd(69, 54), (3, -7, 9));
+        assert_eq!(extended_gcd(240, 46).0, 2);
+        for &(a, b) in [
+            (50, 70),
+            (33, 44),
+            (1, 1),
+            (17, 17),
+            (1_000_000_007, 998_244_353),
+        ]
+        .iter()
+        {
+            assert_bezout(a, b);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_negative() {
+        assert_eq!(extended_gcd(-12, 18).0, 6);
+        assert_eq!(extended_gcd(12, -18).0, 6);
+        assert_eq!(extended_gcd(-12, -18).0, 6);
+        for &(a, b) in [(-101, 13), (101, -13), (-69, -54), (-1, 1), (-7, 0)].iter() {
+            assert_bezout(a, b);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_with_zero() {
+        assert_eq!(extended_gcd(0, 5), (5, 0, 1));
+        assert_eq!(extended_gcd(5, 0), (5, 1, 0));
+        assert_eq!(extended_gcd(0, -5), (5, 0, -1));
+        assert_eq!(extended_gcd(-5, 0), (5, -1, 0));
+        assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+    }
+
+    #[test]
+    fn extended_gcd_extremes() {
+        assert_eq!(extended_gcd(i64::MIN, 1), (1, 0, 1));
+        assert_eq!(extended_gcd(i64::MIN, -1), (1, 0, -1));
+        assert_eq!(extended_gcd(i64::MAX, i64::MIN).0, 1);
+        assert_eq!(extended_gcd(i64::MIN, 1 << 40).0, 1 << 40);
+        for &(a, b) in [
+            (i64::MIN, 3),
+            (i64::MIN + 1, i64::MIN),
+            (i64::MAX, i64::MAX - 1),
+            (i64::MAX, -(1 << 62)),
+        ]
+        .iter()
+        {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in an i64")]
+    fn extended_gcd_of_min_and_zero() {
+        extended_gcd(i64::MIN, 0);
+    }
 }
@@ -1,6 +1,7 @@
 //! This module provides many mathematical operations.
 mod armstrong_number;
 mod baby_step_giant_step;
+mod chinese_remainder_theorem;
 mod derivative_method;
 mod extended_euclidean_algorithm;
 mod fast_fourier_transform;
@@ -32,8 +33,9 @@ mod zellers_congruence_algorithm;
 
 pub use self::armstrong_number::is_armstrong_number;
 pub use self::baby_step_giant_step::baby_step_giant_step;
+pub use self::chinese_remainder_theorem::crt;
 pub use self::derivative_method::derivative_method;
-pub use self::extended_euclidean_algorithm::extended_euclidean_algorithm;
+pub use self::extended_euclidean_algorithm::{extended_euclidean_algorithm, extended_gcd};
 pub use self::fast_fourier_transform::{
     fast_fourier_transform, fast_fourier_transform_input_permutation,
     inverse_fast_fourier_transform,
@@ -5,6 +5,23 @@ pub fn binary_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
     if arr.len() > 1 {
         is_asc = arr[0] < arr[arr.len() - 1];
     }
+    search(arr, is_asc, |x| item.cmp(x))
+}
+
+/// Searches `arr`, sorted by the key extracted with `f`, for an element whose key is `key`.
+///
+/// Like `binary_search`, it works on both ascending and descending arrays. The key is
+/// extracted only once per probe.
+pub fn binary_search_by_key<T, K: Ord, F: Fn(&T) -> K>(arr: &[T], key: &K, f: F) -> Option<usize> {
+    let mut is_asc = true;
+    if arr.len() > 1 {
+        is_asc = f(&arr[0]) < f(&arr[arr.len() - 1]);
+    }
+    search(arr, is_asc, |x| key.cmp(&f(x)))
+}
+
+/// Binary search where `cmp` compares the searched item against an element of `arr`.
+fn search<T, F: Fn(&T) -> Ordering>(arr: &[T], is_asc: bool, cmp: F) -> Option<usize> {
     let mut left = 0;
     let mut right = arr.len();
 
@@ -12,13 +29,13 @@ pub fn binary_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
         let mid = left + (right - left) / 2;
 
         if is_asc {
-            match item.cmp(&arr[mid]) {
+            match cmp(&arr[mid]) {
                 Ordering::Less => right = mid,
                 Ordering::Equal => return Some(mid),
                 Ordering::Greater => left = mid + 1,
             }
         } else {
-            match item.cmp(&arr[mid]) {
+            match cmp(&arr[mid]) {
                 Ordering::Less => left = mid + 1,
                 Ordering::Equal => return Some(mid),
                 Ordering::Greater => right = mid,
@@ -28,6 +45,84 @@ pub fn binary_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
     None
 }
 
+/// Returns the index of the first element for which `pred` is false.
+///
+/// `pred` must be true for a (possibly empty) prefix of `array` and false for
+/// the rest of it. If it holds for every element, `array.len()` is returned.
+pub fn partition_point<T, F: Fn(&T) -> bool>(array: &[T], pred: F) -> usize {
+    let mut left = 0;
+    let mut right = array.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if pred(&array[mid]) {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    left
+}
+
+/// Returns the index of the first element of the sorted `arr` that is not less than `item`,
+/// or `arr.len()` if there is none. Same semantics as C++'s `std::lower_bound`.
+pub fn lower_bound<T: Ord>(arr: &[T], item: &T) -> usize {
+    partition_point(arr, |x| x < item)
+}
+
+/// Returns the index of the first element of the sorted `arr` that is greater than `item`,
+/// or `arr.len()` if there is none. Same semantics as C++'s `std::upper_bound`.
+///
+/// `upper_bound(arr, item) - lower_bound(arr, item)` is the number of occurrences of `item`.
+pub fn upper_bound<T: Ord>(arr: &[T], item: &T) -> usize {
+    partition_point(arr, |x| x <= item)
+}
+
+/// Returns the integer square root of `n`, the largest `r` such that `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    integer_nth_root(n, 2)
+}
+
+/// Returns the integer `k`-th root of `n`, the largest `r` such that `r^k <= n`, by
+/// bisecting over `[0, n]`.
+///
+/// Powers of the probed value are computed with checked multiplications, so no
+/// overflow happens even for `n = u64::MAX`.
+///
+/// Panics if `k` is 0.
+pub fn integer_nth_root(n: u64, k: u32) -> u64 {
+    assert!(k > 0, "the 0-th root is undefined");
+
+    // Invariant: lo^k <= n, and every value above hi has a k-th power greater than n.
+    let mut lo = 0;
+    let mut hi = n;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2 + 1;
+        if power_at_most(mid, k, n) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    lo
+}
+
+/// Returns whether `base^exp <= limit`, stopping as soon as the power exceeds `limit`.
+fn power_at_most(base: u64, exp: u32, limit: u64) -> bool {
+    if base <= 1 {
+        return base <= limit;
+    }
+    let mut power: u64 = 1;
+    for _ in 0..exp {
+        power = match power.checked_mul(base) {
+            Some(power) if power <= limit => power,
+            _ => return false,
+        };
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +198,163 @@ mod tests {
         let index = binary_search(&5, &vec![4, 3, 2, 1]);
         assert_eq!(index, None);
     }
+
+    #[test]
+    fn partition_point_boundary() {
+        let array = [true, true, true, false, false];
+        assert_eq!(partition_point(&array, |&x| x), 3);
+
+        let array = [1, 2, 3, 4, 5, 6];
+        assert_eq!(partition_point(&array, |&x| x < 4), 3);
+        assert_eq!(partition_point(&array, |&x| x <= 4), 4);
+    }
+
+    #[test]
+    fn partition_point_extremes() {
+        let array = [true, true, true];
+        assert_eq!(partition_point(&array, |&x| x), 3);
+
+        let array = [false, false, false];
+        assert_eq!(partition_point(&array, |&x| x), 0);
+
+        let array: [bool; 0] = [];
+        assert_eq!(partition_point(&array, |&x| x), 0);
+    }
+
+    #[test]
+    fn partition_point_matches_std() {
+        let array = [1, 1, 2, 3, 3, 3, 5, 8, 8];
+        for value in 0..10 {
+            assert_eq!(
+                partition_point(&array, |&x| x < value),
+                array.partition_point(|&x| x < value)
+            );
+        }
+    }
+
+    #[test]
+    fn bounds_with_duplicates() {
+        let array = [1, 2, 2, 2, 3, 5, 5, 8];
+        assert_eq!(lower_bound(&array, &2), 1);
+        assert_eq!(upper_bound(&array, &2), 4);
+        assert_eq!(lower_bound(&array, &5), 5);
+        assert_eq!(upper_bound(&array, &5), 7);
+        assert_eq!(upper_bound(&array, &5) - lower_bound(&array, &5), 2);
+    }
+
+    #[test]
+    fn bounds_all_equal() {
+        let array = [7, 7, 7, 7];
+        assert_eq!(lower_bound(&array, &7), 0);
+        assert_eq!(upper_bound(&array, &7), 4);
+        assert_eq!(lower_bound(&array, &6), 0);
+        assert_eq!(upper_bound(&array, &6), 0);
+        assert_eq!(lower_bound(&array, &8), 4);
+        assert_eq!(upper_bound(&array, &8), 4);
+    }
+
+    #[test]
+    fn bounds_absent_item() {
+        let array = ["a", "c", "e"];
+        assert_eq!(lower_bound(&array, &"b"), 1);
+        assert_eq!(upper_bound(&array, &"b"), 1);
+        assert_eq!(lower_bound(&array, &"z"), array.len());
+        assert_eq!(upper_bound(&array, &"z"), array.len());
+
+        let empty: [i32; 0] = [];
+        assert_eq!(lower_bound(&empty, &1), 0);
+        assert_eq!(upper_bound(&empty, &1), 0);
+    }
+
+    #[test]
+    fn search_by_key_asc() {
+        let people = [(1, "ada"), (4, "grace"), (7, "alan"), (9, "barbara")];
+        assert_eq!(binary_search_by_key(&people, &1, |&(id, _)| id), Some(0));
+        assert_eq!(binary_search_by_key(&people, &7, |&(id, _)| id), Some(2));
+        assert_eq!(binary_search_by_key(&people, &9, |&(id, _)| id), Some(3));
+        assert_eq!(binary_search_by_key(&people, &5, |&(id, _)| id), None);
+    }
+
+    #[test]
+    fn search_by_key_desc() {
+        let people = [(9, "barbara"), (7, "alan"), (4, "grace"), (1, "ada")];
+        assert_eq!(binary_search_by_key(&people, &9, |&(id, _)| id), Some(0));
+        assert_eq!(binary_search_by_key(&people, &4, |&(id, _)| id), Some(2));
+        assert_eq!(binary_search_by_key(&people, &0, |&(id, _)| id), None);
+    }
+
+    #[test]
+    fn search_by_key_extracts_once_per_probe() {
+        use std::cell::Cell;
+
+        let ids: Vec<(u32, &str)> = (0..1024).map(|id| (id, "name")).collect();
+        let calls = Cell::new(0);
+        let index = binary_search_by_key(&ids, &1000, |&(id, _)| {
+            calls.set(calls.get() + 1);
+            id
+        });
+        assert_eq!(index, Some(1000));
+        // Two extractions for the order detection plus one per probe.
+        assert!(calls.get() <= 2 + 11);
+    }
+
+    #[test]
+    fn search_by_key_empty() {
+        let people: [(u32, &str); 0] = [];
+        assert_eq!(binary_search_by_key(&people, &1, |&(id, _)| id), None);
+    }
+
+    #[test]
+    fn isqrt_perfect_squares() {
+        for r in 0..1000u64 {
+            assert_eq!(isqrt(r * r), r);
+        }
+        assert_eq!(isqrt(4_294_967_295 * 4_294_967_295), 4_294_967_295);
+    }
+
+    #[test]
+    fn isqrt_around_perfect_squares() {
+        for r in 1..1000u64 {
+            assert_eq!(isqrt(r * r - 1), r - 1);
+            assert_eq!(isqrt(r * r + 1), r);
+        }
+        assert_eq!(isqrt(2), 1);
+        assert_eq!(isqrt(3), 1);
+    }
+
+    #[test]
+    fn isqrt_max() {
+        assert_eq!(isqrt(u64::MAX), 4_294_967_295);
+    }
+
+    #[test]
+    fn nth_root_perfect_cubes() {
+        for r in 0..1000u64 {
+            let cube = r * r * r;
+            assert_eq!(integer_nth_root(cube, 3), r);
+            if r > 0 {
+                assert_eq!(integer_nth_root(cube - 1, 3), r - 1);
+                assert_eq!(integer_nth_root(cube + 1, 3), r);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_root_extremes() {
+        assert_eq!(integer_nth_root(u64::MAX, 1), u64::MAX);
+        assert_eq!(integer_nth_root(u64::MAX, 3), 2_642_245);
+        assert_eq!(integer_nth_root(u64::MAX, 63), 2);
+        assert_eq!(integer_nth_root(u64::MAX, 64), 1);
+        assert_eq!(integer_nth_root(u64::MAX, u32::MAX), 1);
+        assert_eq!(integer_nth_root(1 << 63, 63), 2);
+        assert_eq!(integer_nth_root((1 << 63) - 1, 63), 1);
+        assert_eq!(integer_nth_root(0, 5), 0);
+        assert_eq!(integer_nth_root(1, 5), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_root_zero_degree() {
+        integer_nth_root(10, 0);
+    }
 }
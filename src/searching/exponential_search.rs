@@ -1,5 +1,10 @@
-use std::cmp::Ordering;
+use crate::searching::binary_search;
 
+/// Searches the ascending `arr` for `item` by doubling an upper bound until it
+/// brackets the target, then running `binary_search` on that window.
+///
+/// This takes `O(log i)` comparisons, where `i` is the position of `item`, which
+/// makes it handy for very large slices when the match is likely near the front.
 pub fn exponential_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
     let len = arr.len();
     if len == 0 {
@@ -13,18 +18,8 @@ pub fn exponential_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
         upper = len
     }
 
-    // binary search
-    let mut lower = upper / 2;
-    while lower < upper {
-        let mid = lower + (upper - lower) / 2;
-
-        match item.cmp(&arr[mid]) {
-            Ordering::Less => upper = mid,
-            Ordering::Equal => return Some(mid),
-            Ordering::Greater => lower = mid + 1,
-        }
-    }
-    None
+    let lower = upper / 2;
+    binary_search(item, &arr[lower..upper]).map(|index| lower + index)
 }
 
 #[cfg(test)]
@@ -69,4 +64,29 @@ mod tests {
         let index = exponential_search(&5, &vec![1, 2, 3, 4]);
         assert_eq!(index, None);
     }
+
+    #[test]
+    fn match_at_front() {
+        let arr: Vec<u32> = (0..1000).collect();
+        assert_eq!(exponential_search(&0, &arr), Some(0));
+    }
+
+    #[test]
+    fn match_at_end() {
+        let arr: Vec<u32> = (0..1000).collect();
+        assert_eq!(exponential_search(&999, &arr), Some(999));
+
+        let arr: Vec<u32> = (0..1024).collect();
+        assert_eq!(exponential_search(&1023, &arr), Some(1023));
+    }
+
+    #[test]
+    fn every_position() {
+        let arr: Vec<i32> = (0..100).map(|i| i * 2).collect();
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(exponential_search(value, &arr), Some(i));
+            assert_eq!(exponential_search(&(value + 1), &arr), None);
+        }
+        assert_eq!(exponential_search(&-1, &arr), None);
+    }
 }
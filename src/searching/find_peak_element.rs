@@ -0,0 +1,90 @@
+/// Finds the index of a peak of `array`, an element greater than its neighbours, where
+/// the neighbours outside of the array count as negative infinity.
+///
+/// Every non-empty array has a peak: walking from any element towards a greater
+/// neighbour must stop somewhere, at the latest at one end of the array. A binary search
+/// keeps a range that contains a peak, and compares its middle element with the next one.
+/// If the next one is greater, a peak lies to its right; otherwise the middle element
+/// itself or one on its left is a peak. This takes O(log n) comparisons.
+///
+/// When several peaks exist, any one of them may be returned. If adjacent elements can
+/// be equal, the returned element is only guaranteed not to be smaller than its
+/// neighbours. Returns `None` for an empty array.
+pub fn find_peak_element<T: Ord>(array: &[T]) -> Option<usize> {
+    if array.is_empty() {
+        return None;
+    }
+
+    // a peak always lies within [low, high]
+    let mut low = 0;
+    let mut high = array.len() - 1;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if array[mid] < array[mid + 1] {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_peak<T: Ord>(array: &[T], index: usize) -> bool {
+        let left = index == 0 || array[index - 1] < array[index];
+        let right = index == array.len() - 1 || array[index + 1] < array[index];
+        left && right
+    }
+
+    #[test]
+    fn single_peak() {
+        assert_eq!(find_peak_element(&[1, 2, 3, 1]), Some(2));
+        assert_eq!(find_peak_element(&["a", "c", "b"]), Some(1));
+    }
+
+    #[test]
+    fn monotonic() {
+        assert_eq!(find_peak_element(&[1, 2, 3, 4, 5]), Some(4));
+        assert_eq!(find_peak_element(&[5, 4, 3, 2, 1]), Some(0));
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(find_peak_element(&[7]), Some(0));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(find_peak_element::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn several_peaks() {
+        let array = [1, 2, 1, 3, 5, 6, 4];
+        let index = find_peak_element(&array).unwrap();
+        assert!(index == 1 || index == 5);
+        assert!(is_peak(&array, index));
+    }
+
+    #[test]
+    fn returns_a_peak() {
+        let mut rng = crate::math::PCG32::new_default(17);
+        for len in 1..60 {
+            let mut array: Vec<u32> = (0..len).collect();
+            crate::general::shuffle(&mut array, rng.get_u64());
+            let index = find_peak_element(&array).unwrap();
+            assert!(is_peak(&array, index), "{:?} at {}", array, index);
+        }
+    }
+
+    #[test]
+    fn plateau() {
+        let array = [3, 3, 3, 3];
+        let index = find_peak_element(&array).unwrap();
+        assert!(index < array.len());
+    }
+}
@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+/// Searches the sorted `arr` for `item` by estimating where it should be from the
+/// values at the ends of the current range, instead of always probing the middle.
+///
+/// On uniformly distributed data this takes `O(log log n)` probes on average, but it
+/// degrades to `O(n)` on skewed data. Returns `None` as soon as `item` falls outside of
+/// the range of values still under consideration.
+pub fn interpolation_search(arr: &[i64], item: &i64) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = arr.len() - 1;
+    while low <= high && arr[low] <= *item && *item <= arr[high] {
+        // All the values in range are equal, so there is nothing to interpolate.
+        if arr[high] == arr[low] {
+            return if arr[low] == *item { Some(low) } else { None };
+        }
+
+        // Widen to i128 so neither the differences nor the product can overflow.
+        let offset = (*item as i128 - arr[low] as i128) * (high - low) as i128
+            / (arr[high] as i128 - arr[low] as i128);
+        let pos = low + offset as usize;
+
+        match arr[pos].cmp(item) {
+            Ordering::Equal => return Some(pos),
+            Ordering::Less => low = pos + 1,
+            // `arr[low] <= item < arr[pos]` guarantees `pos > low`.
+            Ordering::Greater => high = pos - 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(interpolation_search(&[], &1), None);
+    }
+
+    #[test]
+    fn one_item() {
+        assert_eq!(interpolation_search(&[5], &5), Some(0));
+        assert_eq!(interpolation_search(&[5], &4), None);
+    }
+
+    #[test]
+    fn dense_uniform() {
+        let arr: Vec<i64> = (0..1000).map(|i| i * 3 - 500).collect();
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(&arr, value), Some(i));
+        }
+        assert_eq!(interpolation_search(&arr, &-499), None);
+        assert_eq!(interpolation_search(&arr, &0), None);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let arr = [10, 20, 30, 40];
+        assert_eq!(interpolation_search(&arr, &9), None);
+        assert_eq!(interpolation_search(&arr, &41), None);
+    }
+
+    #[test]
+    fn equal_elements() {
+        let arr = [7, 7, 7, 7, 7];
+        assert_eq!(interpolation_search(&arr, &7), Some(0));
+        assert_eq!(interpolation_search(&arr, &6), None);
+        assert_eq!(interpolation_search(&arr, &8), None);
+    }
+
+    #[test]
+    fn runs_of_duplicates() {
+        let arr = [1, 1, 1, 2, 2, 9, 9, 9, 9];
+        assert_eq!(arr[interpolation_search(&arr, &1).unwrap()], 1);
+        assert_eq!(arr[interpolation_search(&arr, &2).unwrap()], 2);
+        assert_eq!(arr[interpolation_search(&arr, &9).unwrap()], 9);
+        assert_eq!(interpolation_search(&arr, &5), None);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let arr = [i64::MIN, -1, 0, 1, i64::MAX];
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(&arr, value), Some(i));
+        }
+    }
+}
@@ -3,6 +3,8 @@ mod binary_search;
 mod binary_search_recursive;
 mod exponential_search;
 mod fibonacci_search;
+mod find_peak_element;
+mod interpolation_search;
 mod jump_search;
 mod kth_smallest;
 mod kth_smallest_heap;
@@ -13,15 +15,20 @@ mod ternary_search_min_max;
 mod ternary_search_min_max_recursive;
 mod ternary_search_recursive;
 
-pub use self::binary_search::binary_search;
+pub use self::binary_search::{
+    binary_search, binary_search_by_key, integer_nth_root, isqrt, lower_bound, partition_point,
+    upper_bound,
+};
 pub use self::binary_search_recursive::binary_search_rec;
 pub use self::exponential_search::exponential_search;
 pub use self::fibonacci_search::fibonacci_search;
+pub use self::find_peak_element::find_peak_element;
+pub use self::interpolation_search::interpolation_search;
 pub use self::jump_search::jump_search;
 pub use self::kth_smallest::kth_smallest;
 pub use self::kth_smallest_heap::kth_smallest_heap;
 pub use self::linear_search::linear_search;
-pub use self::quick_select::quick_select;
+pub use self::quick_select::{quick_select, quickselect};
 pub use self::ternary_search::ternary_search;
 pub use self::ternary_search_min_max::ternary_search_max;
 pub use self::ternary_search_min_max::ternary_search_min;
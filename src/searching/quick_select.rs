@@ -1,5 +1,7 @@
 // https://en.wikipedia.org/wiki/Quickselect
 
+use crate::sorting::partition as quick_sort_partition;
+
 pub fn partition<T: PartialOrd + Copy>(
     list: &mut [T],
     left: usize,
@@ -34,9 +36,41 @@ pub fn quick_select(list: &mut [i32], left: usize, right: usize, index: usize) -
     }
 }
 
+/// Returns the `k`-th smallest (0-indexed) element of `arr`, or `None` if `k >= arr.len()`.
+///
+/// It partitions a copy of `arr` with the same scheme as `quick_sort`, but only keeps
+/// working on the side that contains position `k`. Takes O(n) time on average.
+///
+/// `quick_select` runs the same algorithm on `i32` only: it reorders the given list in
+/// place and takes the bounds and index to search, which must be valid. This function
+/// works on any `Ord` type, leaves `arr` untouched, and checks `k` instead.
+pub fn quickselect<T: Ord + Clone>(arr: &[T], k: usize) -> Option<T> {
+    if k >= arr.len() {
+        return None;
+    }
+
+    let mut values = arr.to_vec();
+    let mut slice = &mut values[..];
+    let mut k = k;
+    while slice.len() > 1 {
+        let pivot = quick_sort_partition(slice);
+        let (left, right) = slice.split_at_mut(pivot);
+        if k == pivot {
+            return Some(right[0].clone());
+        } else if k < pivot {
+            slice = left;
+        } else {
+            k -= pivot + 1;
+            slice = &mut right[1..];
+        }
+    }
+    Some(slice[0].clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::math::PCG32;
     #[test]
     fn it_works() {
         let mut arr1 = [2, 3, 4, 5];
@@ -46,4 +80,42 @@ mod tests {
         let mut arr2 = [0, 3, 8];
         assert_eq!(quick_select(&mut arr2, 0, 0, 0), 0);
     }
+
+    #[test]
+    fn quickselect_out_of_range() {
+        assert_eq!(quickselect::<i32>(&[], 0), None);
+        assert_eq!(quickselect(&[1, 2, 3], 3), None);
+    }
+
+    #[test]
+    fn quickselect_matches_sorting() {
+        let mut rng = PCG32::new_default(99);
+        for len in 1..60 {
+            let arr: Vec<u32> = (0..len).map(|_| rng.get_u32() % 1000).collect();
+            let mut sorted = arr.clone();
+            sorted.sort_unstable();
+            for (k, expected) in sorted.iter().enumerate() {
+                assert_eq!(quickselect(&arr, k), Some(*expected));
+            }
+        }
+    }
+
+    #[test]
+    fn quickselect_duplicates() {
+        let arr = [3, 1, 3, 3, 2, 1, 3, 2, 1];
+        let expected = [1, 1, 1, 2, 2, 3, 3, 3, 3];
+        for (k, value) in expected.iter().enumerate() {
+            assert_eq!(quickselect(&arr, k), Some(*value));
+        }
+
+        let same = [7; 10];
+        assert_eq!(quickselect(&same, 5), Some(7));
+    }
+
+    #[test]
+    fn quickselect_non_copy() {
+        let words = ["pear", "apple", "fig", "kiwi"].map(String::from);
+        assert_eq!(quickselect(&words, 0), Some(String::from("apple")));
+        assert_eq!(quickselect(&words, 3), Some(String::from("pear")));
+    }
 }
@@ -5,8 +5,8 @@ pub fn bitonic_sort(up: bool, x: &mut [i32]) {
 
     let mid = x.len() / 2;
     let (first, second) = x.split_at_mut(mid);
-    bitonic_sort(true, first);
-    bitonic_sort(false, second);
+    bitonic_sort(!up, first);
+    bitonic_sort(up, second);
 
     bitonic_merge(up, x);
 }
@@ -18,8 +18,10 @@ fn bitonic_merge(up: bool, x: &mut [i32]) {
         return;
     }
 
-    let mid = x.len() / 2;
-    for i in 0..mid {
+    // Comparing against the greatest power of two below the length keeps the
+    // network correct for lengths that are not powers of two.
+    let mid = x.len().next_power_of_two() / 2;
+    for i in 0..x.len() - mid {
         if up == (x[i] > x[mid + i]) {
             x.swap(i, mid + i);
         }
@@ -62,6 +64,41 @@ mod tests {
         assert_eq!(numbers, vec![10, 30]);
     }
 
+    #[test]
+    fn test_bitonic_sort_three_elements() {
+        let mut numbers = vec![3, 1, 2];
+        bitonic_sort(true, &mut numbers);
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bitonic_sort_five_elements() {
+        let mut numbers = vec![5, 3, 1, 4, 2];
+        bitonic_sort(true, &mut numbers);
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_bitonic_sort_six_elements() {
+        let mut numbers = vec![6, -2, 11, 0, 6, 3];
+        bitonic_sort(true, &mut numbers);
+        assert_eq!(numbers, vec![-2, 0, 3, 6, 6, 11]);
+    }
+
+    #[test]
+    fn test_bitonic_sort_seven_elements() {
+        let mut numbers = vec![7, 1, 6, 2, 5, 3, 4];
+        bitonic_sort(true, &mut numbers);
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_bitonic_sort_descending_five_elements() {
+        let mut numbers = vec![5, 3, 1, 4, 2];
+        bitonic_sort(false, &mut numbers);
+        assert_eq!(numbers, vec![5, 4, 3, 2, 1]);
+    }
+
     #[test]
     fn test_error_bitonic_sort() {
         let mut numbers = vec![10, 30, 11, 20, 4, 330, 21, 110];
@@ -1,4 +1,4 @@
-use crate::sorting::insertion_sort::InsertionSort;
+use crate::sorting::insertion_sort::{insertion_sort, InsertionSort};
 use crate::sorting::traits::Sorter;
 
 fn bucket_sort<T: Ord + Copy + Into<usize>>(arr: &mut [T]) {
@@ -27,6 +27,103 @@ fn bucket_sort<T: Ord + Copy + Into<usize>>(arr: &mut [T]) {
     }
 }
 
+/// Sorts floating-point values drawn from the unit interval using bucket sort.
+///
+/// The values are distributed into `n` buckets by value (`x` goes to bucket
+/// `floor(n * x)`), each bucket is insertion sorted and the buckets are
+/// concatenated. On uniformly distributed input this takes `O(n)` on average.
+///
+/// # Panics
+///
+/// Panics if any value is outside of `[0, 1)`, including `NaN`.
+pub fn bucket_sort_f64(arr: &mut [f64]) {
+    if let Some(x) = arr.iter().find(|x| !(0.0..1.0).contains(*x)) {
+        panic!("bucket_sort_f64 expects values in [0, 1), found {}", x);
+    }
+
+    let len = arr.len();
+    let mut buckets: Vec<Vec<f64>> = vec![vec![]; len];
+    for &x in arr.iter() {
+        buckets[(x * len as f64) as usize].push(x);
+    }
+
+    let mut i = 0;
+    for mut bucket in buckets {
+        insertion_sort_f64(&mut bucket);
+        for x in bucket {
+            arr[i] = x;
+            i += 1;
+        }
+    }
+}
+
+fn insertion_sort_f64(arr: &mut [f64]) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j - 1] > arr[j] {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Sorts a slice with bucket sort, letting the caller choose the buckets.
+///
+/// `bucketer` maps every value to one of `num_buckets` buckets. The buckets are
+/// laid out in order, each of them is insertion sorted, so values that compare
+/// equal keep their relative order, and the buckets are concatenated. The result
+/// is only sorted if `bucketer` is monotonic, that is, if `a < b` implies
+/// `bucketer(a) <= bucketer(b)`.
+///
+/// Values are moved around with swaps, so `T` does not need to be `Copy` or `Clone`.
+///
+/// # Panics
+///
+/// Panics if `bucketer` returns a bucket that is not below `num_buckets`.
+pub fn bucket_sort_by<T, F>(array: &mut [T], num_buckets: usize, bucketer: F)
+where
+    T: Ord,
+    F: Fn(&T) -> usize,
+{
+    let buckets: Vec<usize> = array.iter().map(&bucketer).collect();
+
+    // starts[b] is where bucket b begins once the buckets are laid out in order
+    let mut starts = vec![0; num_buckets + 1];
+    for &bucket in &buckets {
+        assert!(
+            bucket < num_buckets,
+            "bucket {} is out of range for {} buckets",
+            bucket,
+            num_buckets
+        );
+        starts[bucket + 1] += 1;
+    }
+    for b in 0..num_buckets {
+        starts[b + 1] += starts[b];
+    }
+
+    // positions[i] is where the value currently at i has to go
+    let mut next = starts.clone();
+    let mut positions: Vec<usize> = buckets
+        .iter()
+        .map(|&bucket| {
+            next[bucket] += 1;
+            next[bucket] - 1
+        })
+        .collect();
+    for i in 0..array.len() {
+        while positions[i] != i {
+            let j = positions[i];
+            array.swap(i, j);
+            positions.swap(i, j);
+        }
+    }
+
+    for bucket in starts.windows(2) {
+        insertion_sort(&mut array[bucket[0]..bucket[1]]);
+    }
+}
+
 /// Sort a slice using bucket sort algorithm.
 ///
 /// Time complexity is `O(n + k)` on average, where `n` is the number of elements,
@@ -51,4 +148,189 @@ mod tests {
 
     sorting_tests!(BucketSort::sort, bucket_sort);
     sorting_tests!(BucketSort::sort_inplace, bucket_sort, inplace);
+
+    use super::{bucket_sort_by, bucket_sort_f64};
+    use crate::math::PCG32;
+    use std::cmp::Ordering;
+
+    fn unit_values(count: usize, seed: u64) -> Vec<f64> {
+        let mut rng = PCG32::new_default(seed);
+        (0..count)
+            .map(|_| rng.get_u32() as f64 / (u32::MAX as f64 + 1.0))
+            .collect()
+    }
+
+    fn sorted_copy(arr: &[f64]) -> Vec<f64> {
+        let mut expected = arr.to_vec();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expected
+    }
+
+    #[test]
+    fn f64_uniform() {
+        let mut arr = unit_values(1000, 11);
+        let expected = sorted_copy(&arr);
+        bucket_sort_f64(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn f64_clustered() {
+        // Everything lands in a handful of buckets around 0.3 and 0.9.
+        let mut arr: Vec<f64> = unit_values(500, 3)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                if i % 2 == 0 {
+                    0.3 + x / 100.0
+                } else {
+                    0.9 + x / 100.0
+                }
+            })
+            .collect();
+        let expected = sorted_copy(&arr);
+        bucket_sort_f64(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn f64_edge_cases() {
+        let mut empty: [f64; 0] = [];
+        bucket_sort_f64(&mut empty);
+        assert_eq!(empty, []);
+
+        let mut arr = [0.5, 0.0, 0.999, 0.5, 0.25];
+        bucket_sort_f64(&mut arr);
+        assert_eq!(arr, [0.0, 0.25, 0.5, 0.5, 0.999]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expects values in [0, 1)")]
+    fn f64_out_of_range() {
+        bucket_sort_f64(&mut [0.2, 1.0, 0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expects values in [0, 1)")]
+    fn f64_negative() {
+        bucket_sort_f64(&mut [0.2, -0.1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expects values in [0, 1)")]
+    fn f64_nan() {
+        bucket_sort_f64(&mut [f64::NAN]);
+    }
+
+    fn first_letter(word: &&str) -> usize {
+        (word.as_bytes()[0] - b'a') as usize
+    }
+
+    #[test]
+    fn by_first_letter() {
+        let mut words = [
+            "pear",
+            "apple",
+            "zucchini",
+            "banana",
+            "papaya",
+            "avocado",
+            "blueberry",
+            "plum",
+            "apricot",
+            "zest",
+        ];
+        let mut expected = words;
+        expected.sort();
+
+        bucket_sort_by(&mut words, 26, first_letter);
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn by_few_buckets() {
+        let mut arr: Vec<u32> = {
+            let mut rng = PCG32::new_default(21);
+            (0..1000).map(|_| rng.get_u32() % 10_000).collect()
+        };
+        let mut expected = arr.clone();
+        expected.sort();
+
+        bucket_sort_by(&mut arr, 3, |x| (*x / 3334) as usize);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn by_single_bucket() {
+        let mut arr = [5, 3, 9, 1, 3];
+        bucket_sort_by(&mut arr, 1, |_| 0);
+        assert_eq!(arr, [1, 3, 3, 5, 9]);
+    }
+
+    #[test]
+    fn by_empty() {
+        let mut arr: [u32; 0] = [];
+        bucket_sort_by(&mut arr, 0, |_| 0);
+        assert_eq!(arr, []);
+    }
+
+    /// A word that is only compared by its text, so the id can tell equal words apart.
+    #[derive(Debug)]
+    struct Tagged(&'static str, usize);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(other.0)
+        }
+    }
+
+    #[test]
+    fn by_is_stable_within_buckets() {
+        let words = [
+            "cherry", "apple", "cherry", "banana", "apple", "cherry", "avocado", "banana", "apple",
+        ];
+        let mut tagged: Vec<Tagged> = words
+            .iter()
+            .enumerate()
+            .map(|(id, word)| Tagged(word, id))
+            .collect();
+
+        bucket_sort_by(&mut tagged, 26, |t| (t.0.as_bytes()[0] - b'a') as usize);
+
+        let sorted: Vec<(&str, usize)> = tagged.iter().map(|t| (t.0, t.1)).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                ("apple", 1),
+                ("apple", 4),
+                ("apple", 8),
+                ("avocado", 6),
+                ("banana", 3),
+                ("banana", 7),
+                ("cherry", 0),
+                ("cherry", 2),
+                ("cherry", 5),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket 3 is out of range for 3 buckets")]
+    fn by_bucket_out_of_range() {
+        bucket_sort_by(&mut [0, 1, 2, 3], 3, |x| *x);
+    }
 }
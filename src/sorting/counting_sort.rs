@@ -23,6 +23,8 @@ fn counting_sort<T: Ord + Copy + Default + Into<usize>>(arr: &[T]) -> Vec<T> {
     output
 }
 
+/// Counting sort places every element by the count of keys not greater than its own.
+/// Elements are placed from the back of the input, so the sort is stable.
 pub struct CountingSort;
 
 impl<T> Sorter<T> for CountingSort
@@ -46,4 +48,7 @@ mod tests {
 
     sorting_tests!(CountingSort::sort, counting_sort);
     sorting_tests!(CountingSort::sort_inplace, counting_sort, inplace);
+
+    stability_tests!(CountingSort::sort, counting_sort);
+    stability_tests!(CountingSort::sort_inplace, counting_sort, inplace);
 }
@@ -0,0 +1,165 @@
+use crate::sorting::traits::Sorter;
+
+fn dual_pivot_quick_sort<T: Ord>(mut array: &mut [T]) {
+    // Only the two smaller regions are sorted recursively, while the loop carries on
+    // with the largest one. Neither of the smaller ones can hold more than half of the
+    // elements, which bounds the recursion depth by O(log n).
+    while array.len() >= 2 {
+        let (lt, gt) = partition(array);
+        // When both pivots are equal, every element of the middle region equals
+        // them too, so runs of duplicates are not partitioned again.
+        let pivots_differ = array[lt] < array[gt];
+
+        let (left, rest) = array.split_at_mut(lt);
+        let (middle, right) = rest.split_at_mut(gt - lt);
+        let middle = if pivots_differ {
+            &mut middle[1..]
+        } else {
+            &mut []
+        };
+
+        let mut regions = [left, middle, &mut right[1..]];
+        regions.sort_by_key(|region| region.len());
+        let [smallest, second, largest] = regions;
+        dual_pivot_quick_sort(smallest);
+        dual_pivot_quick_sort(second);
+        array = largest;
+    }
+}
+
+/// Partitions `array`, which holds at least two elements, around two pivots and
+/// returns their final positions `lt <= gt`.
+fn partition<T: Ord>(array: &mut [T]) -> (usize, usize) {
+    let len = array.len();
+    choose_pivots(array);
+
+    // Invariant while partitioning:
+    // - array[1..lt] < left pivot
+    // - array[lt..i] between the pivots (inclusive)
+    // - array[gt + 1..len - 1] > right pivot
+    let mut lt = 1;
+    let mut gt = len - 2;
+    let mut i = 1;
+    while i <= gt {
+        if array[i] < array[0] {
+            array.swap(i, lt);
+            lt += 1;
+            i += 1;
+        } else if array[i] > array[len - 1] {
+            array.swap(i, gt);
+            gt -= 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    // Move the pivots into their final positions.
+    lt -= 1;
+    gt += 1;
+    array.swap(0, lt);
+    array.swap(len - 1, gt);
+    (lt, gt)
+}
+
+/// Moves the pivots to the ends of `array`, with `array[0] <= array[len - 1]`.
+///
+/// Like Java's `Arrays.sort`, five evenly spaced elements around the middle are
+/// sorted, and the second and fourth of them become the pivots. This splits sorted,
+/// reversed and otherwise patterned inputs into balanced regions. Arrays too short
+/// to sample use their first and last elements.
+fn choose_pivots<T: Ord>(array: &mut [T]) {
+    let len = array.len();
+    if len >= 7 {
+        let seventh = len / 7;
+        let mid = len / 2;
+        let samples = [
+            mid - 2 * seventh,
+            mid - seventh,
+            mid,
+            mid + seventh,
+            mid + 2 * seventh,
+        ];
+        for i in 1..samples.len() {
+            let mut j = i;
+            while j > 0 && array[samples[j]] < array[samples[j - 1]] {
+                array.swap(samples[j], samples[j - 1]);
+                j -= 1;
+            }
+        }
+        array.swap(0, samples[1]);
+        array.swap(len - 1, samples[3]);
+    } else if array[0] > array[len - 1] {
+        array.swap(0, len - 1);
+    }
+}
+
+/// Dual-Pivot QuickSort picks two pivots and partitions the array into three
+/// regions: elements smaller than the left pivot, elements between the pivots
+/// and elements greater than the right pivot. Each region is then sorted
+/// recursively. As in Java's `Arrays.sort` for primitives, the pivots are picked
+/// from five sampled elements, so sorted and reversed inputs stay O(n*logn), and
+/// the largest region is handled by a loop to keep the recursion depth O(logn).
+/// The three-way split keeps many-duplicate inputs cheap, since the middle
+/// region is skipped when both pivots are equal.
+/// Average time complexity is O(n*logn), with fewer swaps than single-pivot QuickSort.
+/// It is not stable.
+pub struct DualPivotQuickSort;
+
+impl<T> Sorter<T> for DualPivotQuickSort
+where
+    T: Ord + Copy,
+{
+    fn sort_inplace(array: &mut [T]) {
+        dual_pivot_quick_sort(array);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sorting::traits::Sorter;
+    use crate::sorting::DualPivotQuickSort;
+
+    sorting_tests!(DualPivotQuickSort::sort, dual_pivot_quick_sort);
+    sorting_tests!(
+        DualPivotQuickSort::sort_inplace,
+        dual_pivot_quick_sort,
+        inplace
+    );
+
+    #[test]
+    fn heavy_duplicates() {
+        let mut array = [3, 3, 3, 1, 1, 2, 2, 3, 1];
+        DualPivotQuickSort::sort_inplace(&mut array);
+        assert_eq!(array, [1, 1, 1, 2, 2, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn all_equal() {
+        let mut array = [7; 100];
+        DualPivotQuickSort::sort_inplace(&mut array);
+        assert_eq!(array, [7; 100]);
+    }
+
+    #[test]
+    fn larger_input() {
+        let array: Vec<i64> = (0..1000).map(|i| (i * 7919 + 13) % 211 - 100).collect();
+        let output = DualPivotQuickSort::sort(&array);
+        let mut expected = array.clone();
+        expected.sort();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn large_sorted_and_reversed() {
+        let sorted: Vec<u32> = (0..1_000_000).collect();
+        assert_eq!(DualPivotQuickSort::sort(&sorted), sorted);
+
+        let mut reversed: Vec<u32> = sorted.iter().rev().copied().collect();
+        DualPivotQuickSort::sort_inplace(&mut reversed);
+        assert_eq!(reversed, sorted);
+
+        let mut organ_pipe: Vec<u32> = (0..500_000).chain((0..500_000).rev()).collect();
+        DualPivotQuickSort::sort_inplace(&mut organ_pipe);
+        assert!(organ_pipe.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
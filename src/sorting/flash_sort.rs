@@ -0,0 +1,164 @@
+/// Sorts floating-point values with flash sort, a distribution sort that needs only
+/// `O(m)` extra memory for `m` classes.
+///
+/// 1. Classification: the values are split into `m = 0.43 * n` classes of equal width
+///    between the minimum and the maximum, and every class is given the region of the
+///    array its values will occupy.
+/// 2. Permutation: every value is moved into the region of its class by following
+///    cycles, as in cycle sort. A value is taken out of the array, dropped at the top of
+///    the unfilled part of its region, and the value it displaces is moved next, until
+///    the cycle comes back to the slot it started from.
+/// 3. Cleanup: the regions are now in order, so an insertion sort over the whole array
+///    only has to sort within each region.
+///
+/// On roughly uniform data every class holds a few values and the sort takes `O(n)`.
+/// Data concentrated in a few classes falls back to the quadratic insertion sort.
+///
+/// # Panics
+///
+/// Panics if any value is `NaN`.
+pub fn flash_sort(array: &mut [f64]) {
+    assert!(
+        array.iter().all(|x| !x.is_nan()),
+        "flash_sort cannot sort NaN values"
+    );
+
+    let n = array.len();
+    if n < 2 {
+        return;
+    }
+    let min = array.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = array.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if min == max {
+        return;
+    }
+
+    let classes = ((0.43 * n as f64) as usize).max(1);
+    // when max - min overflows, or with infinite values, the ratio is NaN or 0 and every
+    // value lands in the first class, which insertion sort still handles correctly
+    let class_of = |x: f64| {
+        let class = ((x - min) / (max - min) * (classes - 1) as f64) as usize;
+        class.min(classes - 1)
+    };
+
+    // end[k] starts as the end of the region of class k, and moves down as it fills up
+    let mut end = vec![0; classes];
+    for &x in array.iter() {
+        end[class_of(x)] += 1;
+    }
+    for k in 1..classes {
+        end[k] += end[k - 1];
+    }
+
+    let mut moved = 0;
+    let mut start = 0;
+    while moved < n {
+        // every slot before start is filled, so the values still to move belong to its
+        // region or a later one, and a slot holds a placed value exactly when it lies in
+        // the filled part of the region of its value
+        while start >= end[class_of(array[start])] {
+            start += 1;
+        }
+
+        let mut flash = array[start];
+        loop {
+            let class = class_of(flash);
+            end[class] -= 1;
+            let slot = end[class];
+            std::mem::swap(&mut flash, &mut array[slot]);
+            moved += 1;
+            if slot == start {
+                break;
+            }
+        }
+    }
+
+    for i in 1..n {
+        let mut j = i;
+        while j > 0 && array[j - 1] > array[j] {
+            array.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::PCG32;
+
+    fn check(mut array: Vec<f64>) {
+        let mut expected = array.clone();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        flash_sort(&mut array);
+        assert_eq!(array, expected);
+    }
+
+    fn uniform(rng: &mut PCG32, len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|_| rng.get_u64() as f64 / u64::MAX as f64)
+            .collect()
+    }
+
+    #[test]
+    fn uniform_random() {
+        let mut rng = PCG32::new_default(42);
+        for len in [2, 3, 10, 100, 1000, 5000].iter() {
+            check(uniform(&mut rng, *len));
+        }
+        let scaled: Vec<f64> = uniform(&mut rng, 500)
+            .into_iter()
+            .map(|x| x * 2000.0 - 1000.0)
+            .collect();
+        check(scaled);
+    }
+
+    #[test]
+    fn empty_and_single() {
+        check(vec![]);
+        check(vec![3.5]);
+    }
+
+    #[test]
+    fn all_equal() {
+        check(vec![1.25; 50]);
+        check(vec![-0.0, 0.0, -0.0]);
+    }
+
+    #[test]
+    fn sorted_and_reversed() {
+        check((0..300).map(|i| i as f64).collect());
+        check((0..300).rev().map(|i| i as f64 / 7.0).collect());
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut rng = PCG32::new_default(7);
+        check((0..400).map(|_| (rng.get_u32() % 5) as f64).collect());
+    }
+
+    #[test]
+    fn skewed() {
+        // a single outlier squeezes every other value into the first class
+        let mut rng = PCG32::new_default(3);
+        let mut array = uniform(&mut rng, 200);
+        array.push(1e12);
+        check(array);
+
+        let exponential: Vec<f64> = (0..60).map(|i| 2f64.powi(i % 40) * 1.5).collect();
+        check(exponential);
+    }
+
+    #[test]
+    fn extreme_values() {
+        check(vec![f64::MAX, -f64::MAX, 0.0, 1.0, -1.0, f64::MIN_POSITIVE]);
+        check(vec![f64::INFINITY, 1.0, f64::NEG_INFINITY, -3.0, 2.0, 1.0]);
+        check(vec![f64::INFINITY, f64::INFINITY, 0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "flash_sort cannot sort NaN values")]
+    fn nan() {
+        flash_sort(&mut [1.0, f64::NAN, 0.0]);
+    }
+}
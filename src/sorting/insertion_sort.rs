@@ -1,6 +1,6 @@
 use crate::sorting::traits::Sorter;
 
-fn insertion_sort<T: Ord>(arr: &mut [T]) {
+pub(crate) fn insertion_sort<T: Ord>(arr: &mut [T]) {
     for i in 0..arr.len() {
         let mut j = i;
         // Move elements of arr[0..i-1],
@@ -18,6 +18,7 @@ fn insertion_sort<T: Ord>(arr: &mut [T]) {
 /// Values from the unsorted parts are placed in the correct position in the sorted part.
 /// Time complexity is O(N^2)
 /// Auxiliary space is O(1)
+/// The sort is stable: an element never moves past an equal one.
 pub struct InsertionSort;
 
 impl<T> Sorter<T> for InsertionSort
@@ -36,4 +37,7 @@ mod tests {
 
     sorting_tests!(InsertionSort::sort, insertion_sort);
     sorting_tests!(InsertionSort::sort_inplace, insertion_sort, inplace);
+
+    stability_tests!(InsertionSort::sort, insertion_sort);
+    stability_tests!(InsertionSort::sort_inplace, insertion_sort, inplace);
 }
@@ -134,3 +134,125 @@ macro_rules! sorting_tests {
         }
     };
 }
+
+/// An element that is ordered by `key` only, so that sorting a slice of them leaves
+/// the `tag`s of equal keys in whatever order the sorter put them.
+///
+/// `stability_tests!` tags every element with its input position: a stable sorter
+/// must keep the tags of equal keys increasing. It converts into its `key`, which
+/// lets key-based sorters like `CountingSort` sort it too.
+#[cfg(test)]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StabilityProbe {
+    pub key: usize,
+    pub tag: usize,
+}
+
+#[cfg(test)]
+impl PartialEq for StabilityProbe {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+#[cfg(test)]
+impl Eq for StabilityProbe {}
+
+#[cfg(test)]
+impl PartialOrd for StabilityProbe {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+impl Ord for StabilityProbe {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[cfg(test)]
+impl From<StabilityProbe> for usize {
+    fn from(probe: StabilityProbe) -> usize {
+        probe.key
+    }
+}
+
+#[cfg(test)]
+impl StabilityProbe {
+    /// Tags every key with its position in `keys`.
+    pub fn tagged(keys: &[usize]) -> Vec<StabilityProbe> {
+        keys.iter()
+            .enumerate()
+            .map(|(tag, &key)| StabilityProbe { key, tag })
+            .collect()
+    }
+
+    /// Checks that `probes` is sorted by key and that equal keys kept their input order.
+    pub fn is_stably_sorted(probes: &[StabilityProbe]) -> bool {
+        probes
+            .windows(2)
+            .all(|w| w[0].key < w[1].key || (w[0].key == w[1].key && w[0].tag < w[1].tag))
+    }
+}
+
+/// Checks that a sorter is stable, i.e. that elements with equal keys keep their
+/// relative input order. Only apply it to sorters that are meant to be stable.
+#[macro_export]
+macro_rules! stability_tests {
+    ($sorter: expr, $mod_name: ident, inplace) => {
+        paste::paste! {
+            #[test]
+            fn [< stable_repeated_keys_ $mod_name _inplace>]() {
+                let keys: Vec<usize> = (0..64).map(|i| (i * 7 + 3) % 5).collect();
+                let mut array = $crate::sorting::StabilityProbe::tagged(&keys);
+                $sorter(&mut array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&array));
+            }
+
+            #[test]
+            fn [< stable_descending_keys_ $mod_name _inplace>]() {
+                let keys: Vec<usize> = (0..40).rev().map(|i| i / 4).collect();
+                let mut array = $crate::sorting::StabilityProbe::tagged(&keys);
+                $sorter(&mut array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&array));
+            }
+
+            #[test]
+            fn [< stable_all_equal_keys_ $mod_name _inplace>]() {
+                let mut array = $crate::sorting::StabilityProbe::tagged(&[2; 20]);
+                $sorter(&mut array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&array));
+            }
+        }
+    };
+
+    ($sorter: expr, $mod_name: ident) => {
+        paste::paste! {
+            #[test]
+            fn [< stable_repeated_keys_ $mod_name >]() {
+                let keys: Vec<usize> = (0..64).map(|i| (i * 7 + 3) % 5).collect();
+                let array = $crate::sorting::StabilityProbe::tagged(&keys);
+                let output = $sorter(&array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&output));
+            }
+
+            #[test]
+            fn [< stable_descending_keys_ $mod_name >]() {
+                let keys: Vec<usize> = (0..40).rev().map(|i| i / 4).collect();
+                let array = $crate::sorting::StabilityProbe::tagged(&keys);
+                let output = $sorter(&array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&output));
+            }
+
+            #[test]
+            fn [< stable_all_equal_keys_ $mod_name >]() {
+                let array = $crate::sorting::StabilityProbe::tagged(&[2; 20]);
+                let output = $sorter(&array);
+                assert!($crate::sorting::StabilityProbe::is_stably_sorted(&output));
+            }
+        }
+    };
+}
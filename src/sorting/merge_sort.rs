@@ -1,4 +1,8 @@
 use crate::sorting::traits::Sorter;
+use std::thread;
+
+/// Below this length `parallel_merge_sort` stops spawning threads.
+const PARALLEL_CUTOFF: usize = 10_000;
 
 pub fn merge_sort<T: Ord + Copy>(array: &[T]) -> Vec<T> {
     if array.len() < 2 {
@@ -33,9 +37,74 @@ fn merge<T: Ord + Copy>(left: &mut Vec<T>, right: &mut Vec<T>) -> Vec<T> {
     result
 }
 
+/// Sorts `arr` with iterative, width-doubling merges instead of recursion.
+///
+/// Runs of width 1, 2, 4, ... are merged pairwise into a single scratch buffer
+/// allocated once up front, so the stack depth does not grow with the input.
+pub fn merge_sort_bottom_up<T: Ord + Clone>(arr: &mut [T]) {
+    let len = arr.len();
+    if len < 2 {
+        return;
+    }
+    let mut scratch = arr.to_vec();
+
+    let mut width = 1;
+    while width < len {
+        let mut lo = 0;
+        while lo + width < len {
+            let mid = lo + width;
+            let hi = (mid + width).min(len);
+            merge_into(&arr[lo..mid], &arr[mid..hi], &mut scratch[lo..hi]);
+            arr[lo..hi].clone_from_slice(&scratch[lo..hi]);
+            lo = hi;
+        }
+        width *= 2;
+    }
+}
+
+/// Sorts `arr` by sorting its halves on separate threads, down to
+/// `PARALLEL_CUTOFF` elements, and then merging them sequentially.
+///
+/// Only `std` threads are used. Merges are stable, so the result is identical
+/// to the one produced by `merge_sort`.
+pub fn parallel_merge_sort<T: Ord + Send + Clone>(arr: &mut [T]) {
+    let len = arr.len();
+    if len <= PARALLEL_CUTOFF {
+        merge_sort_bottom_up(arr);
+        return;
+    }
+
+    let middle = len / 2;
+    {
+        let (left, right) = arr.split_at_mut(middle);
+        thread::scope(|scope| {
+            scope.spawn(|| parallel_merge_sort(left));
+            parallel_merge_sort(right);
+        });
+    }
+
+    let mut merged = arr.to_vec();
+    merge_into(&arr[..middle], &arr[middle..], &mut merged);
+    arr.clone_from_slice(&merged);
+}
+
+fn merge_into<T: Ord + Clone>(left: &[T], right: &[T], out: &mut [T]) {
+    let (mut i, mut j) = (0, 0);
+    for slot in out.iter_mut() {
+        if j >= right.len() || (i < left.len() && left[i] <= right[j]) {
+            *slot = left[i].clone();
+            i += 1;
+        } else {
+            *slot = right[j].clone();
+            j += 1;
+        }
+    }
+}
+
 // The Merge Sort algorithm is a sorting algorithm that is based on the Divide and Conquer paradigm.
 // The Time complexity is `O(nlog(n))` where n is the length of the array.
 // Auxillary Space required is `O(n)` Since all the elements are copied to the auxillary space.
+// The sort is stable: equal elements keep their relative order.
 pub struct MergeSort;
 
 impl<T> Sorter<T> for MergeSort
@@ -52,11 +121,132 @@ where
     }
 }
 
+// Bottom-up variant of Merge Sort: same `O(nlog(n))` time and `O(n)` auxiliary space,
+// but iterative, so it does not recurse on large inputs. Also stable.
+pub struct BottomUpMergeSort;
+
+impl<T> Sorter<T> for BottomUpMergeSort
+where
+    T: Ord + Copy,
+{
+    fn sort_inplace(array: &mut [T]) {
+        merge_sort_bottom_up(array);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::sorting::traits::Sorter;
-    use crate::sorting::MergeSort;
+    use crate::sorting::{BottomUpMergeSort, MergeSort};
 
     sorting_tests!(MergeSort::sort, merge_sort);
     sorting_tests!(MergeSort::sort_inplace, merge_sort, inplace);
+
+    sorting_tests!(BottomUpMergeSort::sort, merge_sort_bottom_up);
+    sorting_tests!(
+        BottomUpMergeSort::sort_inplace,
+        merge_sort_bottom_up,
+        inplace
+    );
+
+    stability_tests!(MergeSort::sort, merge_sort);
+    stability_tests!(MergeSort::sort_inplace, merge_sort, inplace);
+
+    stability_tests!(BottomUpMergeSort::sort, merge_sort_bottom_up);
+    stability_tests!(
+        BottomUpMergeSort::sort_inplace,
+        merge_sort_bottom_up,
+        inplace
+    );
+
+    #[test]
+    fn bottom_up_is_stable() {
+        let mut array = [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e'), (3, 'f')];
+        super::merge_sort_bottom_up(&mut array);
+        assert_eq!(
+            array,
+            [(1, 'b'), (1, 'e'), (2, 'd'), (3, 'a'), (3, 'c'), (3, 'f')]
+        );
+    }
+
+    #[test]
+    fn bottom_up_sorts_non_copy_values() {
+        let mut array = vec![
+            String::from("pear"),
+            String::from("apple"),
+            String::from("fig"),
+        ];
+        super::merge_sort_bottom_up(&mut array);
+        assert_eq!(array, vec!["apple", "fig", "pear"]);
+    }
+
+    #[test]
+    fn parallel_sorts_shuffled_million() {
+        let mut array: Vec<u32> = (0..1_000_000).collect();
+        crate::general::shuffle(&mut array, 17);
+        super::parallel_merge_sort(&mut array);
+        assert_sorted!(&array);
+        assert!(array.iter().enumerate().all(|(i, &v)| i as u32 == v));
+    }
+
+    /// Ordered by `key` only, so ties expose any difference in stability.
+    #[derive(Clone, Copy, Debug)]
+    struct Keyed {
+        key: u32,
+        id: u32,
+    }
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn parallel_matches_merge_sort() {
+        let mut array: Vec<Keyed> = (0..25_000)
+            .map(|id| Keyed {
+                key: (id * 7919) % 101,
+                id,
+            })
+            .collect();
+        crate::general::shuffle(&mut array, 5);
+
+        let expected = MergeSort::sort(&array);
+        super::parallel_merge_sort(&mut array);
+
+        let ids = |v: &[Keyed]| v.iter().map(|k| (k.key, k.id)).collect::<Vec<_>>();
+        assert_eq!(ids(&array), ids(&expected));
+    }
+
+    #[test]
+    fn bottom_up_large_input_on_small_stack() {
+        // The iterative version needs constant stack space, so it can sort a
+        // large input on a thread with a deliberately tiny stack.
+        let handle = std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let mut array: Vec<u64> = (0..500_000u64)
+                    .map(|i| i.wrapping_mul(2_654_435_761) % 1_000_003)
+                    .collect();
+                super::merge_sort_bottom_up(&mut array);
+                assert_sorted!(&array);
+            })
+            .unwrap();
+        handle.join().unwrap();
+    }
 }
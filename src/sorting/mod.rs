@@ -31,7 +31,9 @@ mod cocktail_shaker_sort;
 mod comb_sort;
 mod counting_sort;
 mod cycle_sort;
+mod dual_pivot_quick_sort;
 mod exchange_sort;
+mod flash_sort;
 mod gnome_sort;
 mod heap_sort;
 mod insertion_sort;
@@ -44,6 +46,7 @@ mod radix_sort;
 mod selection_sort;
 mod shell_sort;
 mod sleep_sort;
+mod spread_sort;
 mod stooge_sort;
 mod strand_sort;
 mod tim_sort;
@@ -57,24 +60,30 @@ pub use self::bitonic_sort::bitonic_sort;
 pub use self::bogo_bogo_sort::BogoBogoSort;
 pub use self::bogo_sort::BogoSort;
 pub use self::bubble_sort::BubbleSort;
-pub use self::bucket_sort::BucketSort;
+pub use self::bucket_sort::{bucket_sort_by, bucket_sort_f64, BucketSort};
 pub use self::cocktail_shaker_sort::CocktailShakerSort;
 pub use self::comb_sort::CombSort;
 pub use self::counting_sort::CountingSort;
 pub use self::cycle_sort::CycleSort;
+pub use self::dual_pivot_quick_sort::DualPivotQuickSort;
 pub use self::exchange_sort::ExchangeSort;
+pub use self::flash_sort::flash_sort;
 pub use self::gnome_sort::GnomeSort;
-pub use self::heap_sort::HeapSort;
+pub use self::heap_sort::{BottomUpHeapSort, HeapSort};
 pub use self::insertion_sort::InsertionSort;
-pub use self::merge_sort::MergeSort;
+pub use self::merge_sort::{
+    merge_sort_bottom_up, parallel_merge_sort, BottomUpMergeSort, MergeSort,
+};
 pub use self::odd_even_sort::OddEvenSort;
-pub use self::pancake_sort::PancakeSort;
+pub use self::pancake_sort::{pancake_sort_flips, PancakeSort};
 pub use self::pigeonhole_sort::pigeonhole_sort;
-pub use self::quick_sort::QuickSort;
+pub(crate) use self::quick_sort::partition;
+pub use self::quick_sort::{PatternDefeatingQuickSort, QuickSort};
 pub use self::radix_sort::RadixSort;
 pub use self::selection_sort::SelectionSort;
 pub use self::shell_sort::ShellSort;
 pub use self::sleep_sort::sleep_sort;
+pub use self::spread_sort::spread_sort;
 pub use self::stooge_sort::StoogeSort;
 pub use self::strand_sort::strand_sort;
 pub use self::tim_sort::TimSort;
@@ -4,11 +4,17 @@ fn pancake_sort<T: Ord + Clone>(arr: &mut [T]) -> Vec<T>
 where
     T: Ord + Clone,
 {
-    let len = arr.len();
-    if len < 2 {
-        arr.to_vec();
-    }
-    for i in (0..len).rev() {
+    pancake_sort_flips(arr);
+    arr.to_vec()
+}
+
+/// Sorts `arr` in place and returns the flips that were performed.
+///
+/// Each entry is the length of the prefix that was reversed, in the order the
+/// flips were applied, so replaying them on the original slice sorts it.
+pub fn pancake_sort_flips<T: Ord>(arr: &mut [T]) -> Vec<usize> {
+    let mut flips = Vec::new();
+    for i in (0..arr.len()).rev() {
         let max_index = arr
             .iter()
             .take(i + 1)
@@ -17,11 +23,15 @@ where
             .map(|(idx, _)| idx)
             .unwrap();
         if max_index != i {
-            arr[0..max_index + 1].reverse();
+            if max_index > 0 {
+                arr[0..max_index + 1].reverse();
+                flips.push(max_index + 1);
+            }
             arr[0..i + 1].reverse();
+            flips.push(i + 1);
         }
     }
-    arr.to_vec()
+    flips
 }
 
 pub struct PancakeSort;
@@ -46,4 +56,44 @@ mod tests {
 
     sorting_tests!(PancakeSort::sort, pancake_sort);
     sorting_tests!(PancakeSort::sort_inplace, pancake_sort, inplace);
+
+    fn replay(original: &[i32], flips: &[usize]) -> Vec<i32> {
+        let mut arr = original.to_vec();
+        for &flip in flips {
+            arr[..flip].reverse();
+        }
+        arr
+    }
+
+    #[test]
+    fn flips_reproduce_sorted_array() {
+        let inputs: [&[i32]; 6] = [
+            &[],
+            &[1],
+            &[2, 1],
+            &[3, 1, 2],
+            &[1, 2, 3, 4, 5],
+            &[5, -1, 3, 3, 0, 12, -7, 4],
+        ];
+        for input in inputs.iter() {
+            let mut sorted = input.to_vec();
+            let flips = super::pancake_sort_flips(&mut sorted);
+            assert_sorted!(&sorted);
+            assert_eq!(replay(input, &flips), sorted);
+        }
+    }
+
+    #[test]
+    fn flips_are_valid_prefix_lengths() {
+        let input = [4, 8, 1, 9, 2, 7];
+        let mut sorted = input.to_vec();
+        let flips = super::pancake_sort_flips(&mut sorted);
+        assert!(flips.iter().all(|&flip| flip >= 2 && flip <= input.len()));
+    }
+
+    #[test]
+    fn sorted_input_needs_no_flips() {
+        let mut array = [1, 2, 3, 4];
+        assert!(super::pancake_sort_flips(&mut array).is_empty());
+    }
 }
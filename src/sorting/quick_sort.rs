@@ -1,11 +1,34 @@
+use crate::sorting::heap_sort::heap_sort;
+use crate::sorting::insertion_sort::insertion_sort;
 use crate::sorting::traits::Sorter;
+use std::cmp::Ordering;
 
-fn quick_sort<T: Ord>(array: &mut [T]) {
-    match array.len() {
-        0 | 1 => return,
-        _ => {}
+/// Slices up to this length are handed to insertion sort by `pdq_sort`.
+const INSERTION_SORT_THRESHOLD: usize = 20;
+
+/// Slices longer than this pick their pivot with Tukey's ninther instead of a
+/// plain median of three.
+const NINTHER_THRESHOLD: usize = 128;
+
+fn quick_sort<T: Ord>(mut array: &mut [T]) {
+    // Recurse into the smaller partition and loop on the larger one, so the
+    // recursion depth stays O(log n) no matter how the pivots turn out.
+    while array.len() > 1 {
+        let pivot = partition(array);
+        let (left, right) = std::mem::take(&mut array).split_at_mut(pivot);
+        let right = &mut right[1..];
+        if left.len() < right.len() {
+            quick_sort(left);
+            array = right;
+        } else {
+            quick_sort(right);
+            array = left;
+        }
     }
+}
 
+/// Partitions `array` around its first element and returns the pivot's final index.
+pub(crate) fn partition<T: Ord>(array: &mut [T]) -> usize {
     let (pivot, rest) = array.split_first_mut().expect("array is non-empty");
     let mut left = 0;
     let mut right = rest.len() - 1;
@@ -28,10 +51,118 @@ fn quick_sort<T: Ord>(array: &mut [T]) {
     }
 
     array.swap(0, left);
+    left
+}
+
+/// Pattern-defeating quicksort: a quicksort that recognizes sorted and
+/// reverse-sorted slices, and cannot degrade to quadratic time.
+fn pdq_sort<T: Ord>(array: &mut [T]) {
+    // Allow about log2(len) badly unbalanced partitions before giving up.
+    let limit = (usize::BITS - array.len().leading_zeros()) as usize;
+    pdq_sort_with_limit(array, limit);
+}
+
+fn pdq_sort_with_limit<T: Ord>(mut array: &mut [T], mut limit: usize) {
+    loop {
+        let len = array.len();
+        if len <= INSERTION_SORT_THRESHOLD {
+            insertion_sort(array);
+            return;
+        }
+        // Too many bad pivots: the input is adversarial, heapsort is O(n log(n)) regardless.
+        if limit == 0 {
+            heap_sort(array);
+            return;
+        }
+
+        // Sorted and reverse-sorted runs need no partitioning at all.
+        if array.windows(2).all(|w| w[0] <= w[1]) {
+            return;
+        }
+        if array.windows(2).all(|w| w[0] >= w[1]) {
+            array.reverse();
+            return;
+        }
+
+        let pivot = choose_pivot(array);
+        array.swap(0, pivot);
+        let (less, greater) = partition_three_way(array);
+        if less.min(len - greater) < len / 8 {
+            limit -= 1;
+        }
+
+        // The elements equal to the pivot are already in place. Recurse into the
+        // smaller side and loop on the larger one to keep the stack shallow.
+        let (left, rest) = std::mem::take(&mut array).split_at_mut(less);
+        let right = &mut rest[greater - less..];
+        if left.len() < right.len() {
+            pdq_sort_with_limit(left, limit);
+            array = right;
+        } else {
+            pdq_sort_with_limit(right, limit);
+            array = left;
+        }
+    }
+}
+
+/// Returns the index of a pivot: the median of the first, middle and last
+/// elements, or for long slices the median of three such medians.
+fn choose_pivot<T: Ord>(array: &[T]) -> usize {
+    let len = array.len();
+    let (a, b, c) = (len / 4, len / 2, len / 4 * 3);
+    if len > NINTHER_THRESHOLD {
+        let a = median_of_three(array, a - 1, a, a + 1);
+        let b = median_of_three(array, b - 1, b, b + 1);
+        let c = median_of_three(array, c - 1, c, c + 1);
+        median_of_three(array, a, b, c)
+    } else {
+        median_of_three(array, a, b, c)
+    }
+}
+
+fn median_of_three<T: Ord>(array: &[T], i: usize, j: usize, k: usize) -> usize {
+    if array[i] < array[j] {
+        if array[j] < array[k] {
+            j
+        } else if array[i] < array[k] {
+            k
+        } else {
+            i
+        }
+    } else if array[i] < array[k] {
+        i
+    } else if array[j] < array[k] {
+        k
+    } else {
+        j
+    }
+}
 
-    let (left, right) = array.split_at_mut(left);
-    quick_sort(left);
-    quick_sort(&mut right[1..]);
+/// Partitions `array` around its first element into the elements smaller than it,
+/// those equal to it and those greater than it. Returns `(less, greater)` such that
+/// `array[..less]` are smaller, `array[less..greater]` are equal and `array[greater..]`
+/// are greater than the pivot. Grouping the equal elements keeps inputs with many
+/// duplicates from producing unbalanced partitions.
+fn partition_three_way<T: Ord>(array: &mut [T]) -> (usize, usize) {
+    // array[less..i] holds the elements equal to the pivot, so array[less] is one of them.
+    let mut less = 0;
+    let mut i = 1;
+    let mut greater = array.len();
+    while i < greater {
+        match array[i].cmp(&array[less]) {
+            Ordering::Less => {
+                array.swap(less, i);
+                less += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                greater -= 1;
+                array.swap(i, greater);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (less, greater)
 }
 
 /// QuickSort is a Divide and Conquer algorithm. It picks an element as
@@ -43,6 +174,7 @@ fn quick_sort<T: Ord>(array: &mut [T]) {
 /// put x at its correct position in a sorted array and put all smaller elements (smaller than x) before x,
 /// and put all greater elements (greater than x) after x. All this should be done in linear time.
 /// Quicksort's  time complexity is O(n*logn) .
+/// It is not stable: partitioning can swap equal elements past each other.
 pub struct QuickSort;
 
 impl<T> Sorter<T> for QuickSort
@@ -54,11 +186,136 @@ where
     }
 }
 
+/// Pattern-defeating quicksort (pdqsort) keeps the average speed of quicksort
+/// while avoiding its worst cases:
+/// - sorted and reverse-sorted slices are detected in linear time,
+/// - pivots are picked with a median of three (a ninther on long slices),
+/// - elements equal to the pivot are grouped, so duplicates are handled in one pass,
+/// - after too many unbalanced partitions it switches to heapsort, which bounds
+///   the time complexity by O(n*logn) on any input.
+///
+/// Small slices are finished with insertion sort. Like QuickSort, it is not stable.
+pub struct PatternDefeatingQuickSort;
+
+impl<T> Sorter<T> for PatternDefeatingQuickSort
+where
+    T: Ord + Copy,
+{
+    fn sort_inplace(array: &mut [T]) {
+        pdq_sort(array);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::sorting::traits::Sorter;
-    use crate::sorting::QuickSort;
+    use crate::sorting::{PatternDefeatingQuickSort, QuickSort};
+    use std::cell::Cell;
+    use std::cmp::Ordering;
 
     sorting_tests!(QuickSort::sort, quick_sort);
     sorting_tests!(QuickSort::sort_inplace, quick_sort, inplace);
+
+    sorting_tests!(PatternDefeatingQuickSort::sort, pdq_sort);
+    sorting_tests!(PatternDefeatingQuickSort::sort_inplace, pdq_sort, inplace);
+
+    thread_local! {
+        static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts every comparison made between two values.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Counted(u32);
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted {
+        fn cmp(&self, other: &Self) -> Ordering {
+            COMPARISONS.with(|c| c.set(c.get() + 1));
+            self.0.cmp(&other.0)
+        }
+    }
+
+    /// Sorts `input` and checks it does not take more than a few n*log2(n) comparisons.
+    fn assert_pdq_sorts_fast(input: Vec<u32>) {
+        let n = input.len();
+        let mut array: Vec<Counted> = input.into_iter().map(Counted).collect();
+        COMPARISONS.with(|c| c.set(0));
+        PatternDefeatingQuickSort::sort_inplace(&mut array);
+        let comparisons = COMPARISONS.with(|c| c.get());
+
+        assert_sorted!(&array);
+        let log_n = (usize::BITS - n.leading_zeros()) as usize;
+        assert!(
+            comparisons <= 4 * n * log_n,
+            "{} comparisons for {} elements",
+            comparisons,
+            n
+        );
+    }
+
+    const N: u32 = 100_000;
+
+    #[test]
+    fn pdq_sorted() {
+        assert_pdq_sorts_fast((0..N).collect());
+    }
+
+    #[test]
+    fn pdq_reverse_sorted() {
+        assert_pdq_sorts_fast((0..N).rev().collect());
+    }
+
+    #[test]
+    fn pdq_organ_pipe() {
+        assert_pdq_sorts_fast((0..N / 2).chain((0..N / 2).rev()).collect());
+    }
+
+    #[test]
+    fn pdq_sawtooth() {
+        assert_pdq_sorts_fast((0..N).map(|i| i % 1000).collect());
+    }
+
+    #[test]
+    fn pdq_many_duplicates() {
+        assert_pdq_sorts_fast((0..N).map(|i| i.wrapping_mul(2_654_435_761) % 4).collect());
+        assert_pdq_sorts_fast(vec![7; N as usize]);
+    }
+
+    #[test]
+    fn pdq_random() {
+        let mut array: Vec<u32> = (0..N).collect();
+        crate::general::shuffle(&mut array, 42);
+        assert_pdq_sorts_fast(array);
+    }
+
+    #[test]
+    fn large_reverse_sorted_on_small_stack() {
+        // Reverse-sorted input makes every first-element pivot a worst case,
+        // which used to recurse once per element.
+        let handle = std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let mut array: Vec<u32> = (0..20_000).rev().collect();
+                QuickSort::sort_inplace(&mut array);
+                assert_sorted!(&array);
+            })
+            .unwrap();
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn pdq_heap_sort_fallback() {
+        // With no unbalanced partition allowed, the first bad pivot switches to heapsort.
+        for limit in 0..3 {
+            let mut array: Vec<u32> = (0..5000).map(|i| i % 97).collect();
+            crate::general::shuffle(&mut array, limit as u64);
+            super::pdq_sort_with_limit(&mut array, limit);
+            assert_sorted!(&array);
+        }
+    }
 }
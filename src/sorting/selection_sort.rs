@@ -16,6 +16,8 @@ fn selection_sort<T: Ord>(array: &mut [T]) {
     }
 }
 
+/// Selection sort is not stable: swapping the minimum into place can move an
+/// element past an equal one.
 pub struct SelectionSort;
 
 impl<T> Sorter<T> for SelectionSort
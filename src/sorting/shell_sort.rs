@@ -1,6 +1,18 @@
 use crate::sorting::traits::Sorter;
 
+/// Ciura's empirically derived gap sequence, in ascending order.
+const CIURA_GAPS: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+
 pub fn shell_sort<T: Ord + Copy>(values: &mut [T]) {
+    shell_sort_with_gaps(values, &default_gaps(values.len()));
+}
+
+/// Sorts `values` with the given gaps, applied in the order they are given.
+///
+/// Gaps of zero or not smaller than the length of `values` are skipped. If the
+/// sequence does not end with a gap of 1, a final pass with gap 1 is run so the
+/// result is always fully sorted.
+pub fn shell_sort_with_gaps<T: Ord + Copy>(values: &mut [T], gaps: &[usize]) {
     // shell sort works by swiping the value at a given gap and decreasing the gap to 1
     fn insertion<T: Ord + Copy>(values: &mut [T], start: usize, gap: usize) {
         for i in ((start + gap)..values.len()).step_by(gap) {
@@ -15,17 +27,62 @@ pub fn shell_sort<T: Ord + Copy>(values: &mut [T]) {
         }
     }
 
-    let mut count_sublist = values.len() / 2; // makes gap as long as half of the array
-    while count_sublist > 0 {
-        for pos_start in 0..count_sublist {
-            insertion(values, pos_start, count_sublist);
+    let len = values.len();
+    let mut gaps: Vec<usize> = gaps
+        .iter()
+        .copied()
+        .filter(|&gap| gap > 0 && gap < len)
+        .collect();
+    if gaps.last() != Some(&1) {
+        gaps.push(1);
+    }
+
+    for gap in gaps {
+        for pos_start in 0..gap {
+            insertion(values, pos_start, gap);
         }
-        count_sublist /= 2; // makes gap as half of previous
     }
 }
 
+/// Builds the descending gap sequence used by default: Ciura's sequence,
+/// extended by a factor of 2.25 for longer inputs.
+fn default_gaps(len: usize) -> Vec<usize> {
+    let mut gaps = CIURA_GAPS.to_vec();
+    while let Some(&last) = gaps.last() {
+        let next = last * 9 / 4;
+        if next >= len {
+            break;
+        }
+        gaps.push(next);
+    }
+    gaps.retain(|&gap| gap < len);
+    gaps.reverse();
+    gaps
+}
+
+/// Shell sort is not stable: gapped insertion passes move elements past equal ones.
 pub struct ShellSort;
 
+impl ShellSort {
+    /// Sorts `arr` in place using a custom gap sequence, so different
+    /// sequences (Ciura, Knuth, Sedgewick, ...) can be compared.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// use rust_algorithms::sorting::ShellSort;
+    ///
+    /// let mut array = [5, 2, 9, 1, 5, 6];
+    /// // Knuth's sequence: (3^k - 1) / 2
+    /// ShellSort::sort_with_gaps(&mut array, &[13, 4, 1]);
+    ///
+    /// assert_eq!(array, [1, 2, 5, 5, 6, 9]);
+    /// ```
+    pub fn sort_with_gaps<T: Ord + Copy>(arr: &mut [T], gaps: &[usize]) {
+        shell_sort_with_gaps(arr, gaps);
+    }
+}
+
 impl<T> Sorter<T> for ShellSort
 where
     T: Ord + Copy,
@@ -42,4 +99,47 @@ mod test {
 
     sorting_tests!(ShellSort::sort, shell_sort);
     sorting_tests!(ShellSort::sort_inplace, shell_sort, inplace);
+
+    fn shuffled(len: usize) -> Vec<i32> {
+        (0..len as i32)
+            .map(|i| (i * 7919 + 31) % 503 - 250)
+            .collect()
+    }
+
+    #[test]
+    fn default_gaps() {
+        assert_eq!(super::default_gaps(0), Vec::<usize>::new());
+        assert_eq!(super::default_gaps(5), vec![4, 1]);
+        assert_eq!(super::default_gaps(100), vec![57, 23, 10, 4, 1]);
+        assert_eq!(
+            super::default_gaps(2000),
+            vec![1577, 701, 301, 132, 57, 23, 10, 4, 1]
+        );
+    }
+
+    #[test]
+    fn custom_gap_sequences() {
+        let sequences: [&[usize]; 4] = [
+            &[701, 301, 132, 57, 23, 10, 4, 1],
+            &[364, 121, 40, 13, 4, 1],
+            &[281, 77, 23, 8, 1],
+            &[1],
+        ];
+        for gaps in sequences.iter() {
+            let mut array = shuffled(1000);
+            ShellSort::sort_with_gaps(&mut array, gaps);
+            assert_sorted!(&array);
+        }
+    }
+
+    #[test]
+    fn gaps_without_final_one() {
+        let mut array = shuffled(200);
+        ShellSort::sort_with_gaps(&mut array, &[50, 7, 3]);
+        assert_sorted!(&array);
+
+        let mut array = shuffled(200);
+        ShellSort::sort_with_gaps(&mut array, &[]);
+        assert_sorted!(&array);
+    }
 }
@@ -0,0 +1,145 @@
+/// Buckets holding at most this many values are finished with insertion sort.
+const INSERTION_THRESHOLD: usize = 32;
+
+/// The largest number of bits used to pick a bucket at one level, so that at most
+/// `2^MAX_BUCKET_BITS` buckets are counted at a time.
+const MAX_BUCKET_BITS: u32 = 11;
+
+/// Sorts integers with a spreadsort-style hybrid of MSD radix sort and insertion sort.
+///
+/// 1. The minimum and maximum of the values are found, and only the bits where they
+///    differ are used: the values are split into buckets by the top few of those bits,
+///    about `log2(n)` of them, so that every bucket gets a handful of values on average.
+/// 2. The values are moved into their buckets in place by following cycles, as in
+///    American flag sort.
+/// 3. Every bucket is sorted the same way, with its own minimum and maximum, until it
+///    is small enough for an insertion sort.
+///
+/// Looking at the actual range of every bucket skips the bits that do not help to tell
+/// the values apart, so clustered data or a few large outliers cost little. On typical
+/// data each level divides the buckets by about `n`, and the sort takes close to linear
+/// time. Each level consumes some bits of a 64-bit range, which bounds the depth.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_algorithms::sorting::spread_sort;
+///
+/// let mut array = [170, 45, 75, 90, 802, 24, 2, 66];
+/// spread_sort(&mut array);
+///
+/// assert_eq!(array, [2, 24, 45, 66, 75, 90, 170, 802]);
+/// ```
+pub fn spread_sort(array: &mut [u64]) {
+    if array.len() <= INSERTION_THRESHOLD {
+        insertion_sort(array);
+        return;
+    }
+
+    let min = *array.iter().min().unwrap();
+    let max = *array.iter().max().unwrap();
+    if min == max {
+        return;
+    }
+
+    // the number of bits where the values differ, and the top ones picking a bucket
+    let range_bits = u64::BITS - (max - min).leading_zeros();
+    let log_len = usize::BITS - array.len().leading_zeros();
+    let bucket_bits = log_len.clamp(1, MAX_BUCKET_BITS).min(range_bits);
+    let shift = range_bits - bucket_bits;
+    let bucket_of = |x: u64| ((x - min) >> shift) as usize;
+
+    let buckets = 1 << bucket_bits;
+    let mut start = vec![0; buckets + 1];
+    for &x in array.iter() {
+        start[bucket_of(x) + 1] += 1;
+    }
+    for b in 1..=buckets {
+        start[b] += start[b - 1];
+    }
+
+    // next[b] is the first slot of bucket b that does not hold one of its values yet
+    let mut next = start.clone();
+    for b in 0..buckets {
+        while next[b] < start[b + 1] {
+            let target = bucket_of(array[next[b]]);
+            if target == b {
+                next[b] += 1;
+            } else {
+                array.swap(next[b], next[target]);
+                next[target] += 1;
+            }
+        }
+    }
+
+    for b in 0..buckets {
+        spread_sort(&mut array[start[b]..start[b + 1]]);
+    }
+}
+
+fn insertion_sort(array: &mut [u64]) {
+    for i in 1..array.len() {
+        let mut j = i;
+        while j > 0 && array[j - 1] > array[j] {
+            array.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::PCG32;
+
+    fn check(mut array: Vec<u64>) {
+        let mut expected = array.clone();
+        expected.sort_unstable();
+        spread_sort(&mut array);
+        assert_eq!(array, expected);
+    }
+
+    #[test]
+    fn small_inputs() {
+        check(vec![]);
+        check(vec![7]);
+        check(vec![2, 1]);
+        check(vec![5, 5, 5, 5]);
+        check(vec![3, 0, u64::MAX, 1, u64::MAX - 1]);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = PCG32::new_default(5);
+        for &len in [33, 100, 1000, 10_000].iter() {
+            check((0..len).map(|_| rng.get_u64()).collect());
+            check((0..len).map(|_| rng.get_u64() % 1000).collect());
+        }
+    }
+
+    #[test]
+    fn sorted_and_reversed() {
+        check((0..5000).collect());
+        check((0..5000).rev().collect());
+        check((0..5000).map(|i| i / 10).collect());
+    }
+
+    #[test]
+    fn clustered() {
+        let mut rng = PCG32::new_default(7);
+        // tight clusters far apart, plus one outlier at each end of the range
+        let mut array: Vec<u64> = (0..10_000)
+            .map(|i| (i % 4) * (1 << 40) + rng.get_u64() % 64)
+            .collect();
+        array.push(0);
+        array.push(u64::MAX);
+        check(array);
+    }
+
+    #[test]
+    fn large_input() {
+        let mut rng = PCG32::new_default(42);
+        let array: Vec<u64> = (0..500_000).map(|_| rng.get_u64()).collect();
+        check(array);
+    }
+}
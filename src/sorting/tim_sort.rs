@@ -73,11 +73,11 @@ fn merge<T: Default + Clone + Eq + Ord + Copy>(
 }
 
 fn _tim_sort<T: Ord + Eq + Default + Clone + Copy>(arr: &mut [T], n: usize) {
-    let min_run = min_run_length(MIN_MERGE);
+    let min_run = min_run_length(n);
 
     let mut i = 0;
     while i < n {
-        insertion_sort(arr, i, cmp::min(i + MIN_MERGE - 1, n - 1));
+        insertion_sort(arr, i, cmp::min(i + min_run - 1, n - 1));
         i += min_run;
     }
 
@@ -102,6 +102,8 @@ fn tim_sort<T: Ord + Eq + Default + Clone + Copy>(arr: &mut [T]) {
     _tim_sort(arr, n);
 }
 
+/// TimSort is stable: runs are insertion sorted and merged so that equal
+/// elements keep their relative order.
 pub struct TimSort;
 
 impl<T> Sorter<T> for TimSort
@@ -120,4 +122,25 @@ mod tests {
 
     sorting_tests!(TimSort::sort, tim_sort);
     sorting_tests!(TimSort::sort_inplace, tim_sort, inplace);
+
+    stability_tests!(TimSort::sort, tim_sort);
+    stability_tests!(TimSort::sort_inplace, tim_sort, inplace);
+
+    #[test]
+    fn min_run_length() {
+        assert_eq!(super::min_run_length(5), 5);
+        assert_eq!(super::min_run_length(32), 16);
+        assert_eq!(super::min_run_length(40), 20);
+        assert_eq!(super::min_run_length(1000), 32);
+    }
+
+    #[test]
+    fn varied_sizes() {
+        for &n in &[5, 40, 1000] {
+            let mut array: Vec<i64> = (0..n).map(|i| (i * 7919 + 13) % 101 - 50).collect();
+            TimSort::sort_inplace(&mut array);
+            assert_sorted!(&array);
+            assert_eq!(array.len(), n as usize);
+        }
+    }
 }
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+// Groups the words that are anagrams of each other, that is, words made of the
+// same characters in a different order. Every word is keyed by its characters
+// sorted, which is the same for all the anagrams of a word.
+//
+// Groups come in the order their first word appears in the input, and words keep
+// their input order inside each group. Duplicated words end up in the same group.
+pub fn group_anagrams(words: &[&str]) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    let mut group_of_signature: HashMap<Vec<char>, usize> = HashMap::new();
+
+    for word in words {
+        let mut signature: Vec<char> = word.chars().collect();
+        signature.sort_unstable();
+
+        let index = *group_of_signature.entry(signature).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(word.to_string());
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_groups() {
+        let result = group_anagrams(&["eat", "tea", "tan", "ate", "nat", "bat"]);
+        assert_eq!(
+            result,
+            vec![
+                vec!["eat".to_string(), "tea".to_string(), "ate".to_string()],
+                vec!["tan".to_string(), "nat".to_string()],
+                vec!["bat".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn no_anagrams() {
+        let words = ["rust", "go", "zig", "java"];
+        let result = group_anagrams(&words);
+        assert_eq!(result.len(), words.len());
+        for (group, word) in result.iter().zip(words.iter()) {
+            assert_eq!(group, &vec![word.to_string()]);
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(group_anagrams(&[]).is_empty());
+    }
+
+    #[test]
+    fn repeated_letters_and_duplicates() {
+        // same letters, but not the same number of each
+        let result = group_anagrams(&["aab", "abb", "baa", "", "aab", ""]);
+        assert_eq!(
+            result,
+            vec![
+                vec!["aab".to_string(), "baa".to_string(), "aab".to_string()],
+                vec!["abb".to_string()],
+                vec!["".to_string(), "".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn case_and_unicode() {
+        let result = group_anagrams(&["Listen", "silent", "enlist", "émile", "limée"]);
+        assert_eq!(
+            result,
+            vec![
+                vec!["Listen".to_string()],
+                vec!["silent".to_string(), "enlist".to_string()],
+                vec!["émile".to_string(), "limée".to_string()],
+            ]
+        );
+    }
+}
@@ -6,7 +6,7 @@ fn precompute_table(pattern: &str) -> Vec<usize> {
 
     for q in 1..p.len() {
         while k > 0 && p[k] != p[q] {
-            k = pi[k];
+            k = pi[k - 1];
         }
 
         if p[k] == p[q] {
@@ -19,6 +19,44 @@ fn precompute_table(pattern: &str) -> Vec<usize> {
     pi
 }
 
+/// Returns the length of the longest proper prefix of `s` that is also a suffix of it.
+///
+/// This is the last value of the KMP prefix function. Strings are compared byte by byte.
+///
+/// # Example
+///
+/// ```rust
+/// use rust_algorithms::string::longest_prefix_suffix;
+///
+/// assert_eq!(longest_prefix_suffix("abcab"), 2);
+/// assert_eq!(longest_prefix_suffix("aaaa"), 3);
+/// assert_eq!(longest_prefix_suffix("abcd"), 0);
+/// ```
+pub fn longest_prefix_suffix(s: &str) -> usize {
+    precompute_table(s).last().copied().unwrap_or(0)
+}
+
+/// Returns the smallest period of `s`, the smallest `p > 0` such that every byte of `s`
+/// equals the byte `p` positions after it.
+///
+/// Shifting `s` by its period lines it up with itself, so the period is the length of `s`
+/// minus its longest proper prefix that is also a suffix. `s` is a repetition of its first
+/// `p` bytes exactly when `p` divides its length. A string with no shorter period has its
+/// own length as period, and the empty string has period 0.
+///
+/// # Example
+///
+/// ```rust
+/// use rust_algorithms::string::smallest_period;
+///
+/// assert_eq!(smallest_period("abcabc"), 3);
+/// assert_eq!(smallest_period("abcab"), 3);
+/// assert_eq!(smallest_period("abcd"), 4);
+/// ```
+pub fn smallest_period(s: &str) -> usize {
+    s.len() - longest_prefix_suffix(s)
+}
+
 pub fn knuth_morris_pratt(text: &str, pattern: &str) -> Vec<usize> {
     if text.is_empty() || pattern.is_empty() {
         return vec![];
@@ -51,7 +89,7 @@ pub fn knuth_morris_pratt(text: &str, pattern: &str) -> Vec<usize> {
 
 #[cfg(test)]
 mod test {
-    use super::{knuth_morris_pratt, precompute_table};
+    use super::{knuth_morris_pratt, longest_prefix_suffix, precompute_table, smallest_period};
 
     #[test]
     fn builds_pi_correctly() {
@@ -59,6 +97,15 @@ mod test {
         assert_eq!(pi, vec![0, 0, 1, 2, 3, 0, 1]);
     }
 
+    #[test]
+    fn falls_back_to_shorter_borders() {
+        let pi = precompute_table("aabaaab");
+        assert_eq!(pi, vec![0, 1, 0, 1, 2, 2, 3]);
+
+        let index = knuth_morris_pratt("aabaabaaab", "aabaaab");
+        assert_eq!(index, vec![3]);
+    }
+
     #[test]
     fn each_letter_matches() {
         let pi = precompute_table("aaa");
@@ -115,4 +162,40 @@ mod test {
         let index = knuth_morris_pratt("", "abcdef");
         assert_eq!(index, vec![]);
     }
+
+    #[test]
+    fn prefix_suffix_lengths() {
+        assert_eq!(longest_prefix_suffix(""), 0);
+        assert_eq!(longest_prefix_suffix("a"), 0);
+        assert_eq!(longest_prefix_suffix("abcabc"), 3);
+        assert_eq!(longest_prefix_suffix("aaaa"), 3);
+        assert_eq!(longest_prefix_suffix("abacaba"), 3);
+        assert_eq!(longest_prefix_suffix("abcd"), 0);
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(smallest_period("abcabc"), 3);
+        assert_eq!(smallest_period("aaaa"), 1);
+        assert_eq!(smallest_period("abababa"), 2);
+        assert_eq!(smallest_period("abacaba"), 4);
+        assert_eq!(smallest_period("abcd"), 4);
+        assert_eq!(smallest_period("x"), 1);
+        assert_eq!(smallest_period(""), 0);
+    }
+
+    #[test]
+    fn period_matches_brute_force() {
+        let mut rng = crate::math::PCG32::new_default(88);
+        for len in 1..40 {
+            let s: String = (0..len)
+                .map(|_| (b'a' + (rng.get_u32() % 2) as u8) as char)
+                .collect();
+            let bytes = s.as_bytes();
+            let brute = (1..=len)
+                .find(|&p| (p..len).all(|i| bytes[i] == bytes[i - p]))
+                .unwrap();
+            assert_eq!(smallest_period(&s), brute, "{}", s);
+        }
+    }
 }
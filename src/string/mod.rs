@@ -1,5 +1,6 @@
 //! This module provides string manipulation algorithms.
 mod aho_corasick;
+mod anagram;
 mod burrows_wheeler_transform;
 mod hamming_distance;
 mod knuth_morris_pratt;
@@ -7,15 +8,18 @@ mod manacher;
 mod naive;
 mod rabin_karp;
 mod reverse;
+mod word_wrap;
 mod z_algorithm;
 
 pub use self::aho_corasick::AhoCorasick;
+pub use self::anagram::group_anagrams;
 pub use self::burrows_wheeler_transform::burrows_wheeler_transform;
 pub use self::burrows_wheeler_transform::inv_burrows_wheeler_transform;
 pub use self::hamming_distance::hamming_distance;
-pub use self::knuth_morris_pratt::knuth_morris_pratt;
+pub use self::knuth_morris_pratt::{knuth_morris_pratt, longest_prefix_suffix, smallest_period};
 pub use self::manacher::manacher;
 pub use self::naive::naive;
 pub use self::rabin_karp::rabin_karp;
 pub use self::reverse::reverse;
+pub use self::word_wrap::word_wrap;
 pub use self::z_algorithm::{match_pattern, z_array};
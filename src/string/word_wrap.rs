@@ -0,0 +1,90 @@
+/// Breaks `words` into lines of at most `line_width` characters, minimizing raggedness.
+///
+/// Words on a line are separated by a single space. The cost of a line is the square of
+/// its trailing spaces, and the breaks are chosen with dynamic programming to minimize
+/// the total cost over all lines. Unlike greedily filling each line, this avoids leaving
+/// one very short line to keep the others full. The last line is free, since it is
+/// expected to be ragged, and a word longer than `line_width` is put on a line of its own.
+///
+/// Time complexity is `O(n * w)`, where `n` is the number of words and `w` the
+/// maximum number of words that fit on a line.
+pub fn word_wrap(words: &[&str], line_width: usize) -> Vec<String> {
+    let n = words.len();
+    // cost[i] is the minimum cost of laying out words[i..], and
+    // next_break[i] the index of the first word after the line starting at i.
+    let mut cost = vec![0u64; n + 1];
+    let mut next_break = vec![n; n + 1];
+
+    for i in (0..n).rev() {
+        cost[i] = u64::MAX;
+        let mut length = 0;
+        for j in (i + 1)..=n {
+            length += words[j - 1].chars().count() + if j > i + 1 { 1 } else { 0 };
+            if length > line_width && j > i + 1 {
+                break;
+            }
+
+            let line_cost = if j == n || length > line_width {
+                0
+            } else {
+                let trailing = (line_width - length) as u64;
+                trailing * trailing
+            };
+            if line_cost + cost[j] < cost[i] {
+                cost[i] = line_cost + cost[j];
+                next_break[i] = j;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut i = 0;
+    while i < n {
+        lines.push(words[i..next_break[i]].join(" "));
+        i = next_break[i];
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beats_greedy() {
+        // Greedy gives "aaa bb" / "cc" / "ddddd" with cost 0 + 16 = 16,
+        // the optimum is "aaa" / "bb cc" / "ddddd" with cost 9 + 1 = 10.
+        let words = ["aaa", "bb", "cc", "ddddd"];
+        assert_eq!(word_wrap(&words, 6), vec!["aaa", "bb cc", "ddddd"]);
+    }
+
+    #[test]
+    fn wider_line() {
+        let words = ["aaa", "bb", "cc", "ddddd"];
+        assert_eq!(word_wrap(&words, 11), vec!["aaa bb cc", "ddddd"]);
+        assert_eq!(word_wrap(&words, 15), vec!["aaa bb cc ddddd"]);
+    }
+
+    #[test]
+    fn sentence() {
+        let words: Vec<&str> = "the quick brown fox jumps over the lazy dog"
+            .split(' ')
+            .collect();
+        // Trailing spaces 1, 1, 0 and 2 give the optimal cost of 6.
+        assert_eq!(
+            word_wrap(&words, 10),
+            vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+    }
+
+    #[test]
+    fn word_longer_than_line() {
+        let words = ["a", "extraordinary", "b", "c"];
+        assert_eq!(word_wrap(&words, 5), vec!["a", "extraordinary", "b c"]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(word_wrap(&[], 10), Vec::<String>::new());
+    }
+}